@@ -141,7 +141,7 @@
 
 pub(crate) mod internal;
 
-use std::{convert::Into, marker::PhantomData, panic::UnwindSafe};
+use std::{convert::Into, fmt, marker::PhantomData, panic::UnwindSafe};
 
 pub use crate::types::buffer::lock::Lock;
 
@@ -157,20 +157,28 @@ use crate::{
     types::{
         boxed::{Finalize, JsBox},
         error::JsError,
-        extract::FromArgs,
+        extract::{FromArgs, TryFromJs},
         private::ValueInternal,
-        Deferred, JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsFunction, JsNull, JsNumber,
-        JsObject, JsPromise, JsString, JsUndefined, JsValue, StringResult, Value,
+        Coerce, Deferred, JsArray, JsArrayBuffer, JsBoolean, JsBuffer, JsFunction, JsNull,
+        JsNumber, JsObject, JsPromise, JsString, JsUndefined, JsValue, StringResult, Value,
     },
+    types_impl::json,
 };
 
 use self::internal::{ContextInternal, Env};
 
 #[cfg(feature = "napi-4")]
-use crate::event::Channel;
+use crate::event::{Channel, ProgressSender, ProgressTaskBuilder};
 
 #[cfg(feature = "napi-5")]
-use crate::types::date::{DateError, JsDate};
+use {
+    crate::event::CancellationToken,
+    crate::types::{
+        date::{DateError, JsDate},
+        extract::TryIntoJs,
+    },
+    std::cell::RefCell,
+};
 
 #[cfg(feature = "napi-6")]
 use crate::lifecycle::InstanceData;
@@ -273,10 +281,24 @@ pub trait Context<'a>: ContextInternal<'a> {
     /// Handles created in the new scope are kept alive only for the duration of the computation and cannot escape.
     ///
     /// This method can be useful for limiting the life of temporary values created during long-running computations, to prevent leaks.
-    fn execute_scoped<'b, T, F>(&mut self, f: F) -> T
+    ///
+    /// The closure is passed a new [`ExecuteContext`] rather than `&mut Self` so that
+    /// the lifetime of its handles is bound to the inner scope. Returning one of them
+    /// is a compile error:
+    ///
+    /// ```compile_fail
+    /// # use neon::prelude::*;
+    /// fn leak(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let n = cx.execute_scoped(|mut cx| cx.number(1));
+    ///
+    ///     Ok(n)
+    /// }
+    /// ```
+    ///
+    /// Use [`Context::compute_scoped`] to return a single handle from the inner scope.
+    fn execute_scoped<T, F>(&mut self, f: F) -> T
     where
-        'a: 'b,
-        F: FnOnce(ExecuteContext<'b>) -> T,
+        F: for<'b> FnOnce(ExecuteContext<'b>) -> T,
     {
         let env = self.env();
         let scope = unsafe { HandleScope::new(env.to_raw()) };
@@ -295,17 +317,55 @@ pub trait Context<'a>: ContextInternal<'a> {
     /// Handles created in the new scope are kept alive only for the duration of the computation and cannot escape, with the exception of the result value, which is rooted in the outer context.
     ///
     /// This method can be useful for limiting the life of temporary values created during long-running computations, to prevent leaks.
-    fn compute_scoped<'b, V, F>(&mut self, f: F) -> JsResult<'a, V>
+    ///
+    /// The result is promoted to the outer scope with a Node-API escapable handle scope,
+    /// which permits exactly one handle to escape. Other handles are bound to the inner
+    /// scope, so storing one in captured state is a compile error:
+    ///
+    /// ```compile_fail
+    /// # use neon::prelude::*;
+    /// fn leak(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let mut scratch = None;
+    ///
+    ///     cx.compute_scoped(|mut cx| {
+    ///         scratch = Some(cx.number(1));
+    ///
+    ///         Ok(cx.undefined())
+    ///     })?;
+    ///
+    ///     Ok(scratch.unwrap())
+    /// }
+    /// ```
+    ///
+    /// Returning the single result, or a handle from the outer scope, is permitted:
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn squares(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     cx.compute_scoped(|mut cx| {
+    ///         let array = cx.empty_array();
+    ///
+    ///         for i in 0..100 {
+    ///             // Scratch handles are freed when the scope closes
+    ///             let n = cx.number(i * i);
+    ///             array.set(&mut cx, i, n)?;
+    ///         }
+    ///
+    ///         Ok(array)
+    ///     })
+    /// }
+    /// ```
+    fn compute_scoped<V, F>(&mut self, f: F) -> JsResult<'a, V>
     where
-        'a: 'b,
         V: Value,
-        F: FnOnce(ComputeContext<'b>) -> JsResult<'b, V>,
+        F: for<'b> FnOnce(ComputeContext<'b, 'a>) -> JsResult<'b, V>,
     {
         let env = self.env();
         let scope = unsafe { EscapableHandleScope::new(env.to_raw()) };
         let cx = ComputeContext {
             env,
             phantom_inner: PhantomData,
+            phantom_outer: PhantomData,
         };
 
         let escapee = unsafe { scope.escape(f(cx)?.to_local()) };
@@ -315,6 +375,34 @@ pub trait Context<'a>: ContextInternal<'a> {
         }))
     }
 
+    /// Executes a computation, catching any JavaScript exception it throws.
+    ///
+    /// If the computation throws, the pending exception is cleared and returned as
+    /// `Err`, so the context may continue to be used normally. Thrown values are
+    /// returned unchanged and need not be errors. A caught value may be rethrown with
+    /// [`Context::throw`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Calls a callback up to three times until it does not throw
+    /// fn retry(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let f = cx.argument::<JsFunction>(0)?;
+    ///     let mut attempts = 1;
+    ///
+    ///     loop {
+    ///         match cx.try_catch(|cx| f.call_with(cx).apply(cx)) {
+    ///             Ok(v) => return Ok(v),
+    ///             Err(err) if attempts >= 3 => return cx.throw(err),
+    ///             Err(_) => attempts += 1,
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the computation returns `Err(Throw)` without an exception pending,
+    /// e.g., because an exception was already caught.
     fn try_catch<T, F>(&mut self, f: F) -> Result<T, Handle<'a, JsValue>>
     where
         F: FnOnce(&mut Self) -> NeonResult<T>,
@@ -365,11 +453,55 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsObject::new(self)
     }
 
+    /// Creates a new object with the given prototype, equivalent to the JavaScript
+    /// expression [`Object.create(proto)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/create).
+    ///
+    /// No constructor is called. Throws a `TypeError` if `proto` is neither an
+    /// object nor `null`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn create_instance(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let class = cx.argument::<JsFunction>(0)?;
+    ///     let proto = class.get_value(&mut cx, "prototype")?;
+    ///
+    ///     cx.object_with_prototype(proto)
+    /// }
+    /// ```
+    fn object_with_prototype<V: Value>(&mut self, proto: Handle<V>) -> JsResult<'a, JsObject> {
+        let create = self
+            .global::<JsFunction>("Object")?
+            .get::<JsFunction, _, _>(self, "create")?;
+
+        let this = self.undefined();
+
+        create
+            .call(self, this, [proto.upcast::<JsValue>()])?
+            .downcast_or_throw(self)
+    }
+
+    /// Creates a new object with a `null` prototype, equivalent to the JavaScript
+    /// expression `Object.create(null)`.
+    ///
+    /// Objects without a prototype do not inherit properties such as `toString` or
+    /// `__proto__`, making them useful as dictionaries of untrusted keys.
+    fn null_prototype_object(&mut self) -> JsResult<'a, JsObject> {
+        let null = self.null();
+
+        self.object_with_prototype(null)
+    }
+
     /// Convenience method for creating an empty `JsArray` value.
     fn empty_array(&mut self) -> Handle<'a, JsArray> {
         JsArray::new(self, 0)
     }
 
+    /// Convenience method for creating a `JsArray` value with `len` empty slots,
+    /// equivalent to the JavaScript expression `new Array(len)`.
+    fn array(&mut self, len: usize) -> Handle<'a, JsArray> {
+        JsArray::new(self, len)
+    }
+
     /// Convenience method for creating an empty `JsArrayBuffer` value.
     fn array_buffer(&mut self, size: usize) -> JsResult<'a, JsArrayBuffer> {
         JsArrayBuffer::new(self, size)
@@ -386,7 +518,8 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsDate::new(self, value)
     }
 
-    /// Convenience method for looking up a global property by name.
+    /// Convenience method for looking up a global property by name and downcasting it
+    /// to `T`. Throws a `TypeError` if the property is not a `T`.
     ///
     /// Equivalent to:
     ///
@@ -408,14 +541,162 @@ pub trait Context<'a>: ContextInternal<'a> {
         global.get(self, name)
     }
 
+    /// Looks up a global property by name, distinguishing a missing global from
+    /// one of the wrong type.
+    ///
+    /// Unlike [`Context::global`], which reports a missing global as a failed
+    /// downcast of `undefined`, this throws a `TypeError` stating that the global
+    /// is not defined.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn parse_url(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let input = cx.argument::<JsString>(0)?;
+    ///     let url = cx.require_global::<JsFunction>("URL")?;
+    ///
+    ///     url.construct_with(&cx).arg(input).apply(&mut cx)
+    /// }
+    /// ```
+    fn require_global<T: Value>(&mut self, name: &str) -> JsResult<'a, T> {
+        let global = self.global_object();
+
+        if !global.has(self, name)? {
+            return self.throw_type_error(format!("global `{name}` is not defined"));
+        }
+
+        let value = global.get_value(self, name)?;
+
+        match value.downcast::<T, _>(self) {
+            Ok(value) => Ok(value),
+            Err(err) => self.throw_type_error(format!("global `{name}`: {err}")),
+        }
+    }
+
     /// Produces a handle to the JavaScript global object.
+    ///
+    /// The global object is fetched from the current environment, so each module
+    /// instance (e.g., in a worker thread) observes its own global. Fetching it is a
+    /// single, inexpensive Node-API call; caching it in a [`Root`](crate::handle::Root)
+    /// would not be cheaper since dereferencing a `Root` is also a Node-API call. In
+    /// hot paths that look up several globals, prefer fetching the global object once
+    /// and reading properties from it directly.
     fn global_object(&mut self) -> Handle<'a, JsObject> {
         JsObject::build(|out| unsafe {
             sys::scope::get_global(self.env().to_raw(), out);
         })
     }
 
+    /// Parses a JSON string into a JavaScript value with
+    /// [`JSON.parse`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse).
+    ///
+    /// Throws a `SyntaxError` if the string is not valid JSON.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn default_config(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     cx.json_parse(r#"{ "timeout": 1000, "retries": 3 }"#)
+    /// }
+    /// ```
+    fn json_parse(&mut self, s: &str) -> JsResult<'a, JsValue> {
+        json::parse(self, s)
+    }
+
+    /// Serializes a JavaScript value to a JSON string with
+    /// [`JSON.stringify`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify).
+    ///
+    /// Exceptions thrown by `JSON.stringify` are propagated, e.g., the `TypeError`
+    /// thrown for circular structures. Throws a `TypeError` if the value cannot be
+    /// serialized, such as `undefined` or a function.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn config_len(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let config = cx.argument::<JsObject>(0)?;
+    ///     let json = cx.json_stringify(config)?.value(&mut cx);
+    ///
+    ///     Ok(cx.number(json.len() as f64))
+    /// }
+    /// ```
+    fn json_stringify<V: Value>(&mut self, v: Handle<V>) -> JsResult<'a, JsString> {
+        json::stringify(self, v.upcast())
+    }
+
+    /// Compiles and runs a string of JavaScript source, returning the completion value
+    /// of the script.
+    ///
+    /// The script is run as a classic script in the global scope, equivalent to an
+    /// indirect `eval`; it is not wrapped as a CommonJS or ES module, so `require`,
+    /// `import` and `module` are not available. Throws a `SyntaxError` if the source
+    /// cannot be parsed, and propagates any exception thrown by the script.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn evaluate(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let src = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     cx.run_script(src)
+    /// }
+    /// ```
+    fn run_script<S: AsRef<str>>(&mut self, src: S) -> JsResult<'a, JsValue> {
+        let src = self.string(src);
+
+        crate::reflect::eval(self, src)
+    }
+
+    /// Informs the garbage collector of a change in the amount of memory allocated
+    /// outside of the JavaScript heap, but kept alive by JavaScript objects. Returns
+    /// the adjusted total of external memory.
+    ///
+    /// V8 is unaware of Rust allocations owned by a [`JsBox`] and may collect it too
+    /// infrequently if it holds a large buffer. Reporting the size increases GC
+    /// pressure accordingly. Every increase should be balanced by an equal decrease
+    /// once the memory is freed, typically in [`Finalize::finalize`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// struct Image(Vec<u8>);
+    ///
+    /// impl Finalize for Image {
+    ///     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+    ///         cx.adjust_external_memory(-(self.0.len() as i64));
+    ///     }
+    /// }
+    ///
+    /// fn image_new(mut cx: FunctionContext) -> JsResult<JsBox<Image>> {
+    ///     let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    ///     let image = Image(vec![0; len]);
+    ///
+    ///     cx.adjust_external_memory(len as i64);
+    ///
+    ///     Ok(cx.boxed(image))
+    /// }
+    /// ```
+    fn adjust_external_memory(&mut self, change_in_bytes: i64) -> i64 {
+        unsafe { sys::mem::adjust_external_memory(self.env().to_raw(), change_in_bytes) }
+    }
+
     /// Throws a JS value.
+    ///
+    /// Any value may be thrown, not only errors. Rethrowing a value caught with
+    /// [`Context::try_catch`] preserves it unchanged, including the stack of an `Error`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Calls a callback, logging and rethrowing any exception
+    /// fn logged(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let f = cx.argument::<JsFunction>(0)?;
+    ///
+    ///     match cx.try_catch(|cx| f.call_with(cx).apply::<JsValue, _>(cx)) {
+    ///         Ok(v) => Ok(v),
+    ///         Err(err) => {
+    ///             let msg = err.to_string(&mut cx)?.value(&mut cx);
+    ///
+    ///             eprintln!("callback threw: {msg}");
+    ///             cx.throw(err)
+    ///         }
+    ///     }
+    /// }
+    /// ```
     fn throw<T: Value, U>(&mut self, v: Handle<T>) -> NeonResult<U> {
         unsafe {
             sys::error::throw(self.env().to_raw(), v.to_local());
@@ -424,6 +705,27 @@ pub trait Context<'a>: ContextInternal<'a> {
     }
 
     /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class.
+    ///
+    /// The error is only constructed, not thrown. Properties may be attached before
+    /// throwing it with [`Context::throw`], which returns a [`NeonResult`] of any type
+    /// and may be used in place of a value, e.g., in a closure passed to
+    /// [`Result::or_else`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn parse_port(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let port = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///     let port = port.parse::<u16>().or_else(|_| {
+    ///         let err = cx.error(format!("invalid port: {port}"))?;
+    ///         let code = cx.string("ERR_INVALID_PORT");
+    ///
+    ///         err.set(&mut cx, "code", code)?;
+    ///         cx.throw(err)
+    ///     })?;
+    ///
+    ///     Ok(cx.number(port))
+    /// }
+    /// ```
     fn error<S: AsRef<str>>(&mut self, msg: S) -> JsResult<'a, JsError> {
         JsError::error(self, msg)
     }
@@ -438,6 +740,15 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsError::range_error(self, msg)
     }
 
+    /// Creates a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error)
+    /// class from a Rust error, including its chain of sources as nested `cause`
+    /// properties.
+    ///
+    /// **See also:** [`JsError::from_rust_error`]
+    fn error_from_rust<E: std::error::Error>(&mut self, err: E) -> JsResult<'a, JsError> {
+        JsError::from_rust_error(self, err)
+    }
+
     /// Throws a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error) class.
     fn throw_error<S: AsRef<str>, T>(&mut self, msg: S) -> NeonResult<T> {
         let err = JsError::error(self, msg)?;
@@ -456,6 +767,25 @@ pub trait Context<'a>: ContextInternal<'a> {
         self.throw(err)
     }
 
+    /// Throws a direct instance of the [`Error`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error)
+    /// class created from a Rust error with [`Context::error_from_rust`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn read_config(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     match std::fs::read_to_string(path) {
+    ///         Ok(config) => Ok(cx.string(config)),
+    ///         Err(err) => cx.throw_rust_error(err),
+    ///     }
+    /// }
+    /// ```
+    fn throw_rust_error<E: std::error::Error, T>(&mut self, err: E) -> NeonResult<T> {
+        let err = JsError::from_rust_error(self, err)?;
+        self.throw(err)
+    }
+
     /// Convenience method for wrapping a value in a `JsBox`.
     ///
     /// # Example:
@@ -476,6 +806,61 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsBox::new(self, v)
     }
 
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    /// Convenience method for wrapping a Rust [`Iterator`] in a JavaScript
+    /// [iterator](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Iteration_protocols)
+    /// object.
+    ///
+    /// The Rust iterator is advanced lazily on each call to `next()`. The returned
+    /// object is also iterable, so it may be used directly in a `for...of` loop.
+    /// Once exhausted, `next()` continues to return `{ value: undefined, done: true }`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn range(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let end = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    ///
+    ///     cx.iterator_from((0..end as u32).map(f64::from))
+    /// }
+    /// ```
+    fn iterator_from<I>(&mut self, iter: I) -> JsResult<'a, JsObject>
+    where
+        I: Iterator + 'static,
+        for<'cx> I::Item: TryIntoJs<'cx>,
+    {
+        let iter = RefCell::new(iter.fuse());
+        let iterator = self.empty_object();
+
+        let next = JsFunction::with_name(self, "next", move |mut cx| {
+            // The borrow is released before converting in case conversion calls into JavaScript
+            let item = iter.borrow_mut().next();
+            let result = cx.empty_object();
+            let done = cx.boolean(item.is_none());
+            let value = match item {
+                Some(item) => item.try_into_js(&mut cx)?.upcast(),
+                None => cx.undefined().upcast::<JsValue>(),
+            };
+
+            result.set(&mut cx, "value", value)?;
+            result.set(&mut cx, "done", done)?;
+
+            Ok(result)
+        })?;
+
+        let symbol_iterator = self
+            .global::<JsFunction>("Symbol")?
+            .get::<JsValue, _, _>(self, "iterator")?;
+
+        let iter_self =
+            JsFunction::with_name(self, "[Symbol.iterator]", |mut cx| Ok(cx.this_value()))?;
+
+        iterator.set(self, "next", next)?;
+        iterator.set(self, symbol_iterator, iter_self)?;
+
+        Ok(iterator)
+    }
+
     #[cfg(feature = "napi-4")]
     #[deprecated(since = "0.9.0", note = "Please use the channel() method instead")]
     #[doc(hidden)]
@@ -499,6 +884,37 @@ pub trait Context<'a>: ContextInternal<'a> {
         channel
     }
 
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    /// Sets whether the queue used to drop [`Root`](crate::handle::Root) values on the
+    /// JavaScript thread keeps the event loop alive.
+    ///
+    /// Values such as `Root` may be dropped from any thread; they are sent to a shared
+    /// queue to be freed on the JavaScript thread of the module instance. The queue is
+    /// unreferenced by default so that it does not prevent the process from exiting,
+    /// which means drops that are still pending at exit are never processed.
+    ///
+    /// Passing `true` references the queue so that pending drops are processed
+    /// before the event loop exits, e.g., during a controlled shutdown. Since the queue
+    /// is never closed, the event loop **will not exit** while it is referenced; pass
+    /// `false` to unreference it again once the pending drops have been processed.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn begin_shutdown(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     cx.keep_drop_queue_alive(true);
+    ///     Ok(cx.undefined())
+    /// }
+    ///
+    /// fn end_shutdown(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     cx.keep_drop_queue_alive(false);
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn keep_drop_queue_alive(&mut self, keep: bool) {
+        InstanceData::keep_drop_queue_alive(self, keep);
+    }
+
     /// Creates a [`Deferred`] and [`JsPromise`] pair. The [`Deferred`] handle can be
     /// used to resolve or reject the [`JsPromise`].
     ///
@@ -517,6 +933,49 @@ pub trait Context<'a>: ContextInternal<'a> {
         JsPromise::new(self)
     }
 
+    /// Schedules a function to be called as a microtask with the global
+    /// [`queueMicrotask`](https://developer.mozilla.org/en-US/docs/Web/API/queueMicrotask).
+    ///
+    /// The callback runs once the current synchronous JavaScript has completed, in
+    /// order with promise reactions, and before any other task such as a timer or I/O
+    /// callback. Throws a `TypeError` if `queueMicrotask` is not defined.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn flush_later(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let flush = cx.argument::<JsFunction>(0)?;
+    ///
+    ///     cx.queue_microtask(flush)?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn queue_microtask(&mut self, cb: Handle<JsFunction>) -> NeonResult<()> {
+        let queue_microtask = self.require_global::<JsFunction>("queueMicrotask")?;
+        let this = self.undefined();
+
+        queue_microtask.exec(self, this, [cb.upcast()])
+    }
+
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    /// Creates a [`CancellationToken`] and a JavaScript function that cancels it.
+    ///
+    /// The function may be returned to JavaScript, e.g., to be called from the `abort`
+    /// event of an [`AbortSignal`](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal),
+    /// while the token is polled by long running work. See [`CancellationToken`] for an
+    /// example.
+    fn cancellation_token(&mut self) -> NeonResult<(CancellationToken, Handle<'a, JsFunction>)> {
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        let f = JsFunction::new(self, move |mut cx| {
+            cancel.cancel();
+            Ok(cx.undefined())
+        })?;
+
+        Ok((token, f))
+    }
+
     /// Creates a [`TaskBuilder`] which can be used to schedule the `execute`
     /// callback to asynchronously execute on the
     /// [Node worker pool](https://nodejs.org/en/docs/guides/dont-block-the-event-loop/).
@@ -542,6 +1001,95 @@ pub trait Context<'a>: ContextInternal<'a> {
         TaskBuilder::new(self, execute)
     }
 
+    #[cfg(feature = "napi-4")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+    /// Creates a [`ProgressTaskBuilder`] which can be used to schedule the `execute`
+    /// callback to asynchronously execute on the Node worker pool while reporting
+    /// progress to the `on_progress` callback on the JavaScript main thread.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn count(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let promise = cx
+    ///         .task_with_progress(
+    ///             |progress| {
+    ///                 for i in 0..10 {
+    ///                     progress.send(i);
+    ///                 }
+    ///             },
+    ///             |_cx, i| {
+    ///                 println!("Counted {}", i);
+    ///                 Ok(())
+    ///             },
+    ///         )
+    ///         .promise(|mut cx, ()| Ok(cx.undefined()));
+    ///
+    ///     Ok(promise)
+    /// }
+    /// ```
+    fn task_with_progress<'cx, O, P, E, G>(
+        &'cx mut self,
+        execute: E,
+        on_progress: G,
+    ) -> ProgressTaskBuilder<'cx, Self, E, P>
+    where
+        'a: 'cx,
+        O: Send + 'static,
+        P: Send + 'static,
+        E: FnOnce(ProgressSender<P>) -> O + Send + 'static,
+        G: Fn(TaskContext, P) -> NeonResult<()> + Send + Sync + 'static,
+    {
+        ProgressTaskBuilder::new(self, execute, on_progress)
+    }
+
+    #[cfg(feature = "napi-5")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-5")))]
+    /// Schedules a closure to execute on the JavaScript main thread after the
+    /// current callback has fully returned to JavaScript, with the global
+    /// [`setImmediate`](https://nodejs.org/api/timers.html#setimmediatecallback-args).
+    ///
+    /// Useful for follow-up work that must not re-enter JavaScript while the
+    /// current native frame is still on the stack. The closure runs after the current
+    /// synchronous JavaScript and all queued microtasks have completed, in the same
+    /// order as other `setImmediate` callbacks, including other deferred closures.
+    /// Unlike [`Context::task`], it does not occupy a thread of the libuv pool.
+    ///
+    /// An exception thrown by the closure is reported as an uncaught exception. The
+    /// closure is dropped without running if the JavaScript environment is torn down
+    /// before it can run. Throws a `TypeError` if `setImmediate` is not defined.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn notify_later(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///
+    ///     cx.defer(move |mut cx| {
+    ///         callback.into_inner(&mut cx).call_with(&cx).exec(&mut cx)
+    ///     })?;
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    fn defer<F>(&mut self, f: F) -> NeonResult<()>
+    where
+        F: FnOnce(TaskContext) -> NeonResult<()> + 'static,
+    {
+        // `setImmediate` calls the function once, but `JsFunction::new` requires `Fn`
+        let f = RefCell::new(Some(f));
+        let callback = JsFunction::new(self, move |mut cx| {
+            if let Some(f) = f.borrow_mut().take() {
+                TaskContext::with_context(cx.env(), f)?;
+            }
+
+            Ok(cx.undefined())
+        })?;
+
+        let set_immediate = self.require_global::<JsFunction>("setImmediate")?;
+        let this = self.undefined();
+
+        set_immediate.exec(self, this, [callback.upcast()])
+    }
+
     #[cfg(feature = "sys")]
     #[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
     /// Gets the raw `sys::Env` for usage with Node-API.
@@ -628,18 +1176,22 @@ impl<'a> ContextInternal<'a> for ExecuteContext<'a> {
 impl<'a> Context<'a> for ExecuteContext<'a> {}
 
 /// An execution context of a scope created by [`Context::compute_scoped()`](Context::compute_scoped).
-pub struct ComputeContext<'a> {
+///
+/// The `'outer` lifetime is the scope of the parent context, which always outlives
+/// the computation.
+pub struct ComputeContext<'a, 'outer: 'a> {
     env: Env,
     phantom_inner: PhantomData<&'a ()>,
+    phantom_outer: PhantomData<&'outer ()>,
 }
 
-impl<'a> ContextInternal<'a> for ComputeContext<'a> {
+impl<'a, 'outer> ContextInternal<'a> for ComputeContext<'a, 'outer> {
     fn env(&self) -> Env {
         self.env
     }
 }
 
-impl<'a> Context<'a> for ComputeContext<'a> {}
+impl<'a, 'outer> Context<'a> for ComputeContext<'a, 'outer> {}
 
 /// An execution context of a function call.
 ///
@@ -683,15 +1235,20 @@ impl<'a> FunctionContext<'a> {
 
     /// Produces the `i`th argument, or `None` if `i` is greater than or equal to `self.len()`.
     pub fn argument_opt(&mut self, i: usize) -> Option<Handle<'a, JsValue>> {
-        let argv = if let Some(argv) = self.arguments.as_ref() {
-            argv
-        } else {
-            let argv = self.info.argv(self);
-            self.arguments.insert(argv)
-        };
+        let env = self.env();
 
-        argv.get(i)
-            .map(|v| Handle::new_internal(unsafe { JsValue::from_local(self.env(), v) }))
+        self.arguments()
+            .get(i)
+            .map(|v| Handle::new_internal(unsafe { JsValue::from_local(env, v) }))
+    }
+
+    // Fetches the arguments on first use and caches them for later calls
+    fn arguments(&mut self) -> &sys::call::Arguments {
+        if self.arguments.is_none() {
+            self.arguments = Some(self.info.argv(self));
+        }
+
+        self.arguments.as_ref().unwrap()
     }
 
     /// Produces the `i`th argument and casts it to the type `V`, or throws an exception if `i` is greater than or equal to `self.len()` or cannot be cast to `V`.
@@ -702,10 +1259,63 @@ impl<'a> FunctionContext<'a> {
         }
     }
 
+    /// Produces the `i`th argument converted to the type `V` with JavaScript's built-in
+    /// type coercion, i.e., the abstract `ToString`, `ToNumber` or `ToBoolean` operation,
+    /// like `` `${value}` ``, `+value` or `!!value`. A missing argument is coerced from
+    /// `undefined`, as in JavaScript.
+    ///
+    /// Unlike [`FunctionContext::argument`], this only throws if the coercion itself
+    /// throws, e.g., when coercing a `Symbol` or `BigInt` to a number, a `Symbol` to a
+    /// string, or when an object's `toString` method throws.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Accepts `repeat("ab", "3")` like `"ab".repeat("3")`
+    /// fn repeat(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let s = cx.argument_coerced::<JsString>(0)?.value(&mut cx);
+    ///     let n = cx.argument_coerced::<JsNumber>(1)?.value(&mut cx);
+    ///
+    ///     if !(n >= 0.0 && n.is_finite()) {
+    ///         return cx.throw_range_error("Invalid count value");
+    ///     }
+    ///
+    ///     Ok(cx.string(s.repeat(n as usize)))
+    /// }
+    /// ```
+    pub fn argument_coerced<V: Coerce>(&mut self, i: usize) -> JsResult<'a, V> {
+        let v = match self.argument_opt(i) {
+            Some(v) => v,
+            None => self.undefined().upcast(),
+        };
+
+        crate::types_impl::coerce(self.env(), v.to_local())
+    }
+
     /// Produces a handle to the `this`-binding and attempts to downcast as a specific type.
     /// Equivalent to calling `cx.this_value().downcast_or_throw(&mut cx)`.
     ///
-    /// Throws an exception if the value is a different type.
+    /// Throws a `TypeError` if the value is a different type. This is useful for methods
+    /// that must be called on a particular receiver. Note that, as with non-strict
+    /// JavaScript functions, a function called without a receiver has the global object
+    /// as `this`, and primitive receivers are wrapped in objects.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::cell::RefCell;
+    /// struct Counter(RefCell<u32>);
+    ///
+    /// impl Finalize for Counter {}
+    ///
+    /// // Called as `increment.call(counter)` where `counter` is a `JsBox<Counter>`
+    /// fn increment(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let counter = cx.this::<JsBox<Counter>>()?;
+    ///     let mut count = counter.0.borrow_mut();
+    ///
+    ///     *count += 1;
+    ///
+    ///     Ok(cx.number(*count))
+    /// }
+    /// ```
     pub fn this<T: Value>(&mut self) -> JsResult<'a, T> {
         self.this_value().downcast_or_throw(self)
     }
@@ -760,11 +1370,127 @@ impl<'a> FunctionContext<'a> {
         T::from_args_opt(self)
     }
 
+    /// Extract every JavaScript argument as the same Rust type.
+    ///
+    /// Useful for variadic functions. Throws a `TypeError` naming the index of the
+    /// first argument that could not be extracted.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let nums = cx.args_as::<f64>()?;
+    ///
+    ///     Ok(cx.number(nums.into_iter().sum::<f64>()))
+    /// }
+    /// ```
+    pub fn args_as<T>(&mut self) -> NeonResult<Vec<T>>
+    where
+        T: TryFromJs<'a>,
+        T::Error: fmt::Display,
+    {
+        let len = self.len();
+        let mut values = Vec::with_capacity(len);
+
+        for i in 0..len {
+            // `i < len`, so the argument is always present
+            let v = self.argument_opt(i).unwrap();
+
+            match T::try_from_js(self, v)? {
+                Ok(v) => values.push(v),
+                Err(err) => return self.throw_type_error(format!("argument {i}: {err}")),
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// Downcast every JavaScript argument to the same type.
+    ///
+    /// Includes every argument that was passed, even explicit trailing `undefined`
+    /// values. Throws a `TypeError` naming the index of the first argument that
+    /// is not a `V`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn max(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let max = cx
+    ///         .args_vec::<JsNumber>()?
+    ///         .into_iter()
+    ///         .map(|n| n.value(&mut cx))
+    ///         .fold(f64::NEG_INFINITY, f64::max);
+    ///
+    ///     Ok(cx.number(max))
+    /// }
+    /// ```
+    pub fn args_vec<V: Value>(&mut self) -> NeonResult<Vec<Handle<'a, V>>> {
+        self.args_as::<Handle<'a, V>>()
+    }
+
+    /// Produces an iterator over every JavaScript argument, in order.
+    ///
+    /// The iterator has the same length as [`FunctionContext::len`], including
+    /// explicit trailing `undefined` values. A handle is only created for an
+    /// argument when the iterator reaches it. The iterator does not borrow the
+    /// context, so the context may be used while iterating.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn count_strings(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let mut count = 0;
+    ///
+    ///     for arg in cx.args_iter() {
+    ///         if arg.is_a::<JsString, _>(&mut cx) {
+    ///             count += 1;
+    ///         }
+    ///     }
+    ///
+    ///     Ok(cx.number(count))
+    /// }
+    /// ```
+    pub fn args_iter(&mut self) -> impl ExactSizeIterator<Item = Handle<'a, JsValue>> {
+        let len = self.len();
+
+        ArgsIter {
+            env: self.env(),
+            argv: self.arguments().clone(),
+            range: 0..len,
+            _lifetime: PhantomData,
+        }
+    }
+
     pub(crate) fn argv<const N: usize>(&mut self) -> [Handle<'a, JsValue>; N] {
         self.info.argv_exact(self)
     }
 }
 
+// Iterator over the arguments of a `FunctionContext`. Only the raw argument values
+// are copied from the context; handles are created as the iterator advances.
+struct ArgsIter<'a> {
+    env: Env,
+    argv: sys::call::Arguments,
+    range: std::ops::Range<usize>,
+    _lifetime: PhantomData<&'a ()>,
+}
+
+impl<'a> Iterator for ArgsIter<'a> {
+    type Item = Handle<'a, JsValue>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.range.next()?;
+        let v = self.argv.get(i)?;
+
+        Some(Handle::new_internal(unsafe {
+            JsValue::from_local(self.env, v)
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ArgsIter<'_> {}
+
 impl<'a> ContextInternal<'a> for FunctionContext<'a> {
     fn env(&self) -> Env {
         self.env
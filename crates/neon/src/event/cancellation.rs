@@ -0,0 +1,92 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use crate::{context::Context, object::Object, result::NeonResult};
+
+/// A flag for cooperatively cancelling long running native work.
+///
+/// A `CancellationToken` may be cloned and sent to other threads; all clones share
+/// the same flag. Long running work polls [`CancellationToken::is_cancelled`], which
+/// is a single atomic load, and returns early once the token has been cancelled.
+///
+/// [`Context::cancellation_token`] creates a token together with a JavaScript function
+/// that cancels it. Since JavaScript cannot run while a native function blocks the
+/// JavaScript thread, the work should either run off the JavaScript thread, e.g., in a
+/// [`Context::task`], or call back into JavaScript while polling the token.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn search_step(_: u64) -> Option<f64> { todo!() }
+/// // Returns `{ promise, cancel }`, where `cancel()` rejects `promise` with an
+/// // `AbortError` if the search has not completed
+/// fn search(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let (token, cancel) = cx.cancellation_token()?;
+///     let on_complete = token.clone();
+///
+///     let promise = cx
+///         .task(move || {
+///             for i in 0.. {
+///                 if token.is_cancelled() {
+///                     return None;
+///                 }
+///
+///                 if let Some(found) = search_step(i) {
+///                     return Some(found);
+///                 }
+///             }
+///
+///             None
+///         })
+///         .promise(move |mut cx, found| {
+///             on_complete.throw_if_cancelled(&mut cx)?;
+///
+///             Ok(cx.number(found.unwrap_or(f64::NAN)))
+///         });
+///
+///     let result = cx.empty_object();
+///
+///     result.set(&mut cx, "promise", promise)?;
+///     result.set(&mut cx, "cancel", cancel)?;
+///
+///     Ok(result)
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a new token that has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the token and all of its clones. _Idempotent_
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` if [`CancellationToken::cancel`] has been called on the token or
+    /// any of its clones
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    /// Throws an `Error` named `AbortError` if the token has been cancelled, matching
+    /// the error thrown by an aborted
+    /// [`AbortSignal`](https://developer.mozilla.org/en-US/docs/Web/API/AbortSignal)
+    pub fn throw_if_cancelled<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<()> {
+        if !self.is_cancelled() {
+            return Ok(());
+        }
+
+        let err = cx.error("This operation was aborted")?;
+        let name = cx.string("AbortError");
+
+        err.set(cx, "name", name)?;
+        cx.throw(err)
+    }
+}
@@ -2,8 +2,9 @@ use std::{
     error, fmt, mem,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc,
+        Arc, Condvar, Mutex,
     },
+    thread::{self, ThreadId},
 };
 
 use crate::{
@@ -90,6 +91,14 @@ type Callback = Box<dyn FnOnce(Env) + Send + 'static>;
 ///     Ok(cx.undefined())
 /// }
 /// ```
+///
+/// ## Settling promises
+///
+/// To resolve or reject a [`JsPromise`](crate::types::JsPromise) from another thread,
+/// prefer [`Deferred::settle_with`](crate::types::Deferred::settle_with) over sending a
+/// closure that settles the [`Deferred`](crate::types::Deferred) manually. The promise is
+/// resolved with the value returned by the closure or rejected with the exception it
+/// throws, and is also rejected if the closure panics.
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
 pub struct Channel {
     state: Arc<ChannelState>,
@@ -107,13 +116,65 @@ impl Channel {
     /// main thread
     pub fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
         Self {
-            state: Arc::new(ChannelState::new(cx)),
+            state: Arc::new(ChannelState::new(cx, None)),
+            has_ref: true,
+        }
+    }
+
+    /// Creates a bounded channel that allows at most `capacity` closures to be
+    /// pending or executing on the JavaScript main thread at a time
+    ///
+    /// A bounded channel provides backpressure for fast producers. When the channel
+    /// is full, [`Channel::try_send`] fails immediately and [`Channel::send_blocking`]
+    /// blocks the calling thread until a closure has finished executing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn bounded<'a, C: Context<'a>>(cx: &mut C, capacity: usize) -> Self {
+        assert!(capacity > 0, "Channel capacity must be greater than zero");
+
+        Self {
+            state: Arc::new(ChannelState::new(cx, Some(capacity))),
             has_ref: true,
         }
     }
 
     /// Allow the Node event loop to exit while this `Channel` exists.
     /// _Idempotent_
+    ///
+    /// A `Channel` is referenced when it is created and keeps the event loop alive,
+    /// preventing the process from exiting naturally, for as long as it exists. Once
+    /// all closures sent on it have finished executing, an unreferenced channel does
+    /// not keep the event loop alive, even if the `Channel` is still held in Rust
+    /// (e.g., by a background thread that only occasionally sends events).
+    ///
+    /// Clones share a queue, and the event loop is kept alive while any clone is
+    /// referenced. Closures that are still pending when the process exits are
+    /// dropped without being executed.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use std::{sync::Arc, thread, time::Duration};
+    ///
+    /// // Calls `callback` every second without preventing Node from exiting
+    /// fn heartbeat(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = Arc::new(cx.argument::<JsFunction>(0)?.root(&mut cx));
+    ///     let mut channel = cx.channel();
+    ///
+    ///     channel.unref(&mut cx);
+    ///
+    ///     thread::spawn(move || loop {
+    ///         thread::sleep(Duration::from_secs(1));
+    ///
+    ///         let callback = callback.clone();
+    ///
+    ///         channel.send(move |mut cx| callback.to_inner(&mut cx).call_with(&cx).exec(&mut cx));
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
     pub fn unref<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
         // Already unreferenced
         if !self.has_ref {
@@ -127,6 +188,9 @@ impl Channel {
 
     /// Prevent the Node event loop from exiting while this `Channel` exists. (Default)
     /// _Idempotent_
+    ///
+    /// Reverses [`Channel::unref`], e.g., while a long-running operation is in
+    /// progress and its result must be delivered before the process exits.
     pub fn reference<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
         // Already referenced
         if self.has_ref {
@@ -139,7 +203,11 @@ impl Channel {
     }
 
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
-    /// Panics if there is a libuv error
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is a libuv error or if a [bounded](Channel::bounded) channel is full.
+    /// Use [`Channel::try_send`] or [`Channel::send_blocking`] to handle a full channel.
     pub fn send<T, F>(&self, f: F) -> JoinHandle<T>
     where
         T: Send + 'static,
@@ -151,8 +219,141 @@ impl Channel {
     /// Schedules a closure to execute on the JavaScript thread that created this Channel
     /// Returns an `Error` if the task could not be scheduled.
     ///
-    /// See [`SendError`] for additional details on failure causes.
+    /// See [`SendError`] for additional details on failure causes. Use
+    /// [`Channel::try_send_or_return`] to recover the closure on failure.
     pub fn try_send<T, F>(&self, f: F) -> Result<JoinHandle<T>, SendError>
+    where
+        T: Send + 'static,
+        F: FnOnce(TaskContext) -> NeonResult<T> + Send + 'static,
+    {
+        let permit = self.state.try_acquire()?;
+
+        self.schedule(f, permit)
+    }
+
+    /// Schedules a closure to execute on the JavaScript thread that created this Channel
+    /// without blocking, returning the closure in a [`TrySendError`] if it could not be
+    /// scheduled.
+    ///
+    /// This is equivalent to [`Channel::try_send`], except that the closure may be
+    /// recovered with [`TrySendError::into_inner`] to retry later or to clean up any
+    /// resources it owns. Fails immediately if a [bounded](Channel::bounded) channel is
+    /// full or if the JavaScript environment has stopped.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::event::TrySendError;
+    /// # fn produce(channel: Channel) {
+    /// let mut send = move |mut cx: TaskContext| {
+    ///     let n = cx.number(42);
+    ///     cx.global_object().set(&mut cx, "answer", n)?;
+    ///     Ok(())
+    /// };
+    ///
+    /// loop {
+    ///     match channel.try_send_or_return(send) {
+    ///         Ok(_) => break,
+    ///         // Retry after the JavaScript thread catches up
+    ///         Err(err) if err.is_full() => {
+    ///             send = err.into_inner();
+    ///             std::thread::yield_now();
+    ///         }
+    ///         // The JavaScript environment has stopped; drop the closure
+    ///         Err(_) => break,
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn try_send_or_return<T, F>(&self, f: F) -> Result<JoinHandle<T>, TrySendError<F>>
+    where
+        T: Send + 'static,
+        F: FnOnce(TaskContext) -> NeonResult<T> + Send + 'static,
+    {
+        if self.state.tsfn.is_finalized() {
+            return Err(TrySendError::closed(f));
+        }
+
+        let permit = match self.state.try_acquire() {
+            Ok(permit) => permit,
+            Err(_) => return Err(TrySendError::full(f)),
+        };
+
+        // The closure is shared with the callback so that it can be recovered if
+        // the callback is dropped without executing
+        let slot = Arc::new(Mutex::new(Some(f)));
+        let callback = {
+            let slot = slot.clone();
+
+            move |cx: TaskContext| {
+                let f = slot.lock().unwrap().take();
+
+                // `Some` because the closure is only taken back if scheduling failed
+                f.expect("Channel closure was already taken")(cx)
+            }
+        };
+
+        self.schedule(callback, permit).map_err(|_| {
+            let f = slot.lock().unwrap().take();
+
+            // `Some` because a callback that could not be scheduled never executes
+            TrySendError::closed(f.expect("Channel closure was executed"))
+        })
+    }
+
+    /// Schedules a closure to execute on the JavaScript thread that created this Channel,
+    /// blocking the current thread while a bounded channel is full
+    ///
+    /// Returns an `Error` instead of blocking indefinitely if the JavaScript environment
+    /// stops while waiting. For unbounded channels, this is equivalent to
+    /// [`Channel::try_send`].
+    ///
+    /// # Errors
+    ///
+    /// The JavaScript thread that created the channel is the only thread that drains it,
+    /// so it would wait forever on a full channel. When called from that thread,
+    /// `send_blocking` does not block and instead fails like [`Channel::try_send`].
+    ///
+    /// Since it may block the calling thread, it should not be called within an
+    /// asynchronous execution context.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn ingest(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    ///     let callback = std::sync::Arc::new(callback);
+    ///     // At most 16 rows are queued for JavaScript at a time
+    ///     let channel = Channel::bounded(&mut cx, 16);
+    ///
+    ///     std::thread::spawn(move || {
+    ///         for row in 0..1_000_000 {
+    ///             let callback = callback.clone();
+    ///             let sent = channel.send_blocking(move |mut cx| {
+    ///                 let row = cx.number(row);
+    ///
+    ///                 callback.to_inner(&mut cx).call_with(&cx).arg(row).exec(&mut cx)
+    ///             });
+    ///
+    ///             // The JavaScript environment has stopped
+    ///             if sent.is_err() {
+    ///                 break;
+    ///             }
+    ///         }
+    ///     });
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn send_blocking<T, F>(&self, f: F) -> Result<JoinHandle<T>, SendError>
+    where
+        T: Send + 'static,
+        F: FnOnce(TaskContext) -> NeonResult<T> + Send + 'static,
+    {
+        let permit = self.state.acquire()?;
+
+        self.schedule(f, permit)
+    }
+
+    fn schedule<T, F>(&self, f: F, permit: Option<Permit>) -> Result<JoinHandle<T>, SendError>
     where
         T: Send + 'static,
         F: FnOnce(TaskContext) -> NeonResult<T> + Send + 'static,
@@ -167,6 +368,10 @@ impl Channel {
                 // Error can be ignored; it only means the user didn't join
                 let _ = tx.send(f(cx).map_err(Into::into));
             });
+
+            // Free the slot after the closure has executed. The permit is also
+            // freed if the callback is dropped without executing.
+            drop(permit);
         });
 
         self.state
@@ -182,11 +387,21 @@ impl Channel {
     pub fn has_ref(&self) -> bool {
         self.has_ref
     }
+
+    /// Returns `false` if the JavaScript environment backing this `Channel` is
+    /// known to be shutting down or already stopped.
+    ///
+    /// This is only a hint; the environment may stop at any time, so a
+    /// subsequent [`Channel::try_send`] may still fail even if this returned
+    /// `true`. Callers that must not panic should continue to use `try_send`.
+    pub fn is_alive(&self) -> bool {
+        !self.state.tsfn.is_finalized()
+    }
 }
 
 impl Clone for Channel {
     /// Returns a clone of the Channel instance that shares the internal
-    /// unbounded queue with the original channel. Scheduling callbacks on the
+    /// queue, including any capacity bound, with the original channel. Scheduling callbacks on the
     /// same queue is faster than using separate channels, but might lead to
     /// starvation if one of the threads posts significantly more callbacks on
     /// the channel than the other one.
@@ -240,17 +455,45 @@ impl Drop for Channel {
         // UV thread if strong reference count goes to 0.
         let state = Arc::clone(&self.state);
 
-        // `Channel::try_send` will only fail if the environment has shutdown.
-        // In that case, the teardown will perform clean-up.
-        let _ = self.try_send(move |mut cx| {
-            state.unref(&mut cx);
-            Ok(())
-        });
+        // `Channel::schedule` will only fail if the environment has shutdown.
+        // In that case, the teardown will perform clean-up. The capacity bound
+        // is bypassed so that the channel cannot be leaked by a full queue.
+        let _ = self.schedule(
+            move |mut cx| {
+                state.unref(&mut cx);
+                Ok(())
+            },
+            None,
+        );
     }
 }
 
 /// An owned permission to join on the result of a closure sent to the JavaScript main
 /// thread with [`Channel::send`].
+///
+/// With the `futures` feature enabled, `JoinHandle` implements
+/// [`Future`](std::future::Future) and may be awaited from an asynchronous runtime
+/// instead of blocking with [`JoinHandle::join`]. The task is woken when the closure
+/// finishes executing. If the closure is dropped without executing, e.g., because the
+/// JavaScript environment stopped, the future resolves to a [`JoinError`] instead of
+/// waiting forever.
+///
+/// ```
+/// # #[cfg(feature = "futures")]
+/// # {
+/// # use neon::prelude::*;
+/// async fn read_version(channel: Channel) -> Result<String, neon::event::JoinError> {
+///     channel
+///         .send(|mut cx| {
+///             let process = cx.global::<JsObject>("process")?;
+///             let version = process.get::<JsString, _, _>(&mut cx, "version")?;
+///
+///             Ok(version.value(&mut cx))
+///         })
+///         .await
+/// }
+/// # }
+/// ```
 pub struct JoinHandle<T> {
     // `Err` is always `Throw`, but `Throw` cannot be sent across threads
     rx: oneshot::Receiver<Result<T, SendThrow>>,
@@ -350,9 +593,10 @@ impl<T> ResultExt<T> for Result<T, JoinError> {
 /// The most likely cause of a failure is that Node is shutting down. This may occur if the
 /// process is forcefully exiting even if the channel is referenced. For example, by calling
 /// `process.exit()`.
-//
-// NOTE: These docs will need to be updated to include `QueueFull` if bounded queues are
-// implemented.
+///
+/// [`Channel::try_send`] also fails if a [bounded](Channel::bounded) channel is full, as
+/// does [`Channel::send_blocking`] when called from the JavaScript thread that owns the
+/// channel.
 #[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
 pub struct SendError;
 
@@ -370,20 +614,173 @@ impl fmt::Debug for SendError {
 
 impl error::Error for SendError {}
 
+/// Error returned by [`Channel::try_send_or_return`] when a closure could not be
+/// scheduled, containing the closure that was not sent.
+///
+/// Unlike [`SendError`], the closure may be recovered with [`TrySendError::into_inner`].
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-4")))]
+pub struct TrySendError<F> {
+    kind: TrySendErrorKind,
+    f: F,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TrySendErrorKind {
+    Full,
+    Closed,
+}
+
+impl<F> TrySendError<F> {
+    fn full(f: F) -> Self {
+        Self {
+            kind: TrySendErrorKind::Full,
+            f,
+        }
+    }
+
+    fn closed(f: F) -> Self {
+        Self {
+            kind: TrySendErrorKind::Closed,
+            f,
+        }
+    }
+
+    /// Returns `true` if the closure was not sent because a [bounded](Channel::bounded)
+    /// channel was full. Sending may succeed after pending closures have executed.
+    pub fn is_full(&self) -> bool {
+        self.kind == TrySendErrorKind::Full
+    }
+
+    /// Returns `true` if the closure was not sent because the JavaScript environment
+    /// is shutting down or already stopped. Subsequent sends will also fail.
+    pub fn is_closed(&self) -> bool {
+        self.kind == TrySendErrorKind::Closed
+    }
+
+    /// Returns the closure that could not be sent
+    pub fn into_inner(self) -> F {
+        self.f
+    }
+}
+
+impl<F> fmt::Display for TrySendError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            TrySendErrorKind::Full => f.write_str("Channel is full"),
+            TrySendErrorKind::Closed => f.write_str("Channel is closed"),
+        }
+    }
+}
+
+impl<F> fmt::Debug for TrySendError<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            TrySendErrorKind::Full => f.write_str("TrySendError::Full"),
+            TrySendErrorKind::Closed => f.write_str("TrySendError::Closed"),
+        }
+    }
+}
+
+impl<F> error::Error for TrySendError<F> {}
+
+impl<F> From<TrySendError<F>> for SendError {
+    fn from(_: TrySendError<F>) -> Self {
+        SendError
+    }
+}
+
 struct ChannelState {
     tsfn: ThreadsafeFunction<Callback>,
     ref_count: AtomicUsize,
+    bound: Option<Arc<Bound>>,
+}
+
+// Counts closures that are pending or executing on a bounded channel. The bound is
+// enforced here instead of with the threadsafe function queue size so that internal
+// callbacks (e.g., `unref` on drop) can bypass it.
+struct Bound {
+    capacity: usize,
+    // JavaScript thread that drains the channel and must never wait on it
+    owner: ThreadId,
+    in_flight: Mutex<usize>,
+    available: Condvar,
+}
+
+// A slot in a bounded channel, freed on drop
+struct Permit(Arc<Bound>);
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight) = self.0.in_flight.lock() {
+            *in_flight -= 1;
+            self.0.available.notify_one();
+        }
+    }
 }
 
 impl ChannelState {
-    fn new<'a, C: Context<'a>>(cx: &mut C) -> Self {
+    fn new<'a, C: Context<'a>>(cx: &mut C, capacity: Option<usize>) -> Self {
         let tsfn = unsafe { ThreadsafeFunction::new(cx.env().to_raw(), Self::callback) };
         Self {
             tsfn,
             ref_count: AtomicUsize::new(1),
+            bound: capacity.map(|capacity| {
+                Arc::new(Bound {
+                    capacity,
+                    owner: thread::current().id(),
+                    in_flight: Mutex::new(0),
+                    available: Condvar::new(),
+                })
+            }),
         }
     }
 
+    // Acquires a slot without blocking, failing if a bounded channel is full
+    fn try_acquire(&self) -> Result<Option<Permit>, SendError> {
+        let bound = match &self.bound {
+            Some(bound) => bound,
+            None => return Ok(None),
+        };
+
+        let mut in_flight = bound.in_flight.lock().unwrap();
+
+        if *in_flight >= bound.capacity {
+            return Err(SendError);
+        }
+
+        *in_flight += 1;
+
+        Ok(Some(Permit(bound.clone())))
+    }
+
+    // Acquires a slot, blocking while a bounded channel is full. When the environment
+    // stops, pending callbacks are dropped, freeing their slots and waking waiters.
+    // The owning JavaScript thread never blocks since it is the one draining the queue.
+    fn acquire(&self) -> Result<Option<Permit>, SendError> {
+        let bound = match &self.bound {
+            Some(bound) => bound,
+            None => return Ok(None),
+        };
+
+        if thread::current().id() == bound.owner {
+            return self.try_acquire();
+        }
+
+        let mut in_flight = bound.in_flight.lock().unwrap();
+
+        while *in_flight >= bound.capacity {
+            if self.tsfn.is_finalized() {
+                return Err(SendError);
+            }
+
+            in_flight = bound.available.wait(in_flight).unwrap();
+        }
+
+        *in_flight += 1;
+
+        Ok(Some(Permit(bound.clone())))
+    }
+
     fn reference<'a, C: Context<'a>>(&self, cx: &mut C) {
         // We can use relaxed ordering because `reference()` can only be called
         // on the Event-Loop thread.
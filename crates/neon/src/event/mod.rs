@@ -122,17 +122,31 @@
 //! [psd-crate]: https://crates.io/crates/psd
 //! [psd-file]: https://www.adobe.com/devnet-apps/photoshop/fileformatashtml/
 
+mod cancellation;
 #[cfg(feature = "napi-4")]
 mod channel;
 
+mod pool;
+#[cfg(feature = "napi-4")]
+mod progress;
 mod task;
+#[cfg(feature = "napi-6")]
+mod tsfn;
 
-pub use self::task::TaskBuilder;
+pub use self::{
+    cancellation::CancellationToken,
+    pool::TaskPool,
+    task::{TaskBuilder, TaskHandle},
+};
 
 #[cfg(all(feature = "napi-5", feature = "futures"))]
 pub(crate) use self::channel::SendThrow;
 #[cfg(feature = "napi-4")]
-pub use self::channel::{Channel, JoinError, JoinHandle, SendError};
+pub use self::channel::{Channel, JoinError, JoinHandle, SendError, TrySendError};
+#[cfg(feature = "napi-4")]
+pub use self::progress::{ProgressSender, ProgressTaskBuilder};
+#[cfg(feature = "napi-6")]
+pub use self::tsfn::{CallError, CallMode, ThreadsafeFunction};
 
 #[cfg(feature = "napi-4")]
 #[deprecated(since = "0.9.0", note = "Please use the Channel type instead")]
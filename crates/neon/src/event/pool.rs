@@ -0,0 +1,258 @@
+use std::{
+    collections::VecDeque,
+    panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
+    sync::{Arc, Mutex, MutexGuard},
+    thread,
+};
+
+use crate::{
+    context::{
+        internal::{ContextInternal, Env},
+        Context, TaskContext,
+    },
+    handle::{root::instance_id, Handle},
+    result::{JsResult, NeonResult},
+    types::{JsPromise, Value},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::lifecycle::InstanceId;
+
+#[cfg(not(feature = "napi-6"))]
+use crate::handle::root::InstanceId;
+
+use super::task::{schedule, schedule_promise};
+
+type Job = Box<dyn FnOnce(Env) + Send + 'static>;
+
+/// Bounded pool for scheduling tasks on the Node worker pool
+///
+/// Tasks scheduled with [`TaskBuilder`](super::TaskBuilder) are queued directly
+/// on the libuv thread pool. Since the libuv pool is small and shared with I/O,
+/// many long-running tasks can starve other work. A `TaskPool` limits the number
+/// of its tasks executing concurrently, queueing the excess and scheduling them as
+/// running tasks complete.
+///
+/// A `TaskPool` may be cloned and sent across threads; clones share the same limit
+/// and queue. Tasks may only be scheduled from the JavaScript thread of the
+/// module instance that created the pool. Consider storing a pool in a
+/// [`LocalKey`](crate::thread::LocalKey) to create one per instance.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::{event::TaskPool, thread::LocalKey};
+/// # fn fibonacci(_: f64) -> f64 { todo!() }
+/// // Allow at most two computations to run concurrently per module instance
+/// static POOL: LocalKey<TaskPool> = LocalKey::new();
+///
+/// fn async_fibonacci(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx);
+///     let pool = POOL
+///         .get_or_try_init(&mut cx, |cx| NeonResult::Ok(TaskPool::new(cx, 2)))?
+///         .clone();
+///
+///     let promise = pool.promise(
+///         &mut cx,
+///         move || fibonacci(n),
+///         |mut cx, n| Ok(cx.number(n)),
+///     );
+///
+///     Ok(promise)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TaskPool {
+    state: Arc<PoolState>,
+}
+
+struct PoolState {
+    limit: usize,
+    instance_id: InstanceId,
+    queue: Mutex<Queue>,
+}
+
+struct Queue {
+    running: usize,
+    pending: VecDeque<Job>,
+}
+
+impl std::fmt::Debug for TaskPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TaskPool")
+            .field("limit", &self.state.limit)
+            .finish()
+    }
+}
+
+impl TaskPool {
+    /// Creates a pool that executes at most `limit` tasks concurrently
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is zero.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, limit: usize) -> Self {
+        assert!(limit > 0, "TaskPool limit must be greater than zero");
+
+        Self {
+            state: Arc::new(PoolState {
+                limit,
+                instance_id: instance_id(cx),
+                queue: Mutex::new(Queue {
+                    running: 0,
+                    pending: VecDeque::new(),
+                }),
+            }),
+        }
+    }
+
+    /// Returns the maximum number of tasks that may execute concurrently
+    pub fn limit(&self) -> usize {
+        self.state.limit
+    }
+
+    /// Schedules a task to execute on the Node worker pool once a slot is
+    /// available, executing the `complete` callback on the JavaScript main thread
+    /// with the result of the `execute` callback
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a different module instance than the one that
+    /// created the pool.
+    pub fn and_then<'a, C, O, E, F>(&self, cx: &mut C, execute: E, complete: F)
+    where
+        C: Context<'a>,
+        O: Send + 'static,
+        E: FnOnce() -> O + Send + 'static,
+        F: FnOnce(TaskContext, O) -> NeonResult<()> + Send + 'static,
+    {
+        let state = self.state.clone();
+
+        self.submit(
+            cx,
+            Box::new(move |env| {
+                let slot = Slot(state);
+
+                schedule(env, catch_panic(execute), move |cx, output| {
+                    slot.release(cx.env());
+                    complete(cx, output.unwrap_or_else(|panic| resume_unwind(panic)))
+                });
+            }),
+        );
+    }
+
+    /// Schedules a task to execute on the Node worker pool once a slot is
+    /// available and returns a promise that is resolved with the value from
+    /// the `complete` callback
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a different module instance than the one that
+    /// created the pool.
+    pub fn promise<'a, C, O, E, F, V>(
+        &self,
+        cx: &mut C,
+        execute: E,
+        complete: F,
+    ) -> Handle<'a, JsPromise>
+    where
+        C: Context<'a>,
+        O: Send + 'static,
+        E: FnOnce() -> O + Send + 'static,
+        F: FnOnce(TaskContext, O) -> JsResult<V> + Send + 'static,
+        V: Value,
+    {
+        let (deferred, promise) = JsPromise::new(cx);
+        let state = self.state.clone();
+
+        self.submit(
+            cx,
+            Box::new(move |env| {
+                let slot = Slot(state);
+
+                schedule_promise(
+                    env,
+                    catch_panic(execute),
+                    move |cx, output| {
+                        slot.release(cx.env());
+                        complete(cx, output.unwrap_or_else(|panic| resume_unwind(panic)))
+                    },
+                    deferred,
+                );
+            }),
+        );
+
+        promise
+    }
+
+    fn submit<'a, C: Context<'a>>(&self, cx: &mut C, job: Job) {
+        if self.state.instance_id != instance_id(cx) {
+            panic!("Attempted to use a `neon::event::TaskPool` from the wrong module");
+        }
+
+        self.state.lock().pending.push_back(job);
+        self.state.start(cx.env());
+    }
+}
+
+impl PoolState {
+    // The queue is only ever left in a consistent state, so a panic while holding the
+    // lock must not poison the pool. Otherwise, `running` would never be decremented
+    // and the pool would stall.
+    fn lock(&self) -> MutexGuard<'_, Queue> {
+        self.queue.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    // Starts pending tasks until the pool is full or the queue is empty
+    fn start(&self, env: Env) {
+        loop {
+            let job = {
+                let mut queue = self.lock();
+
+                if queue.running >= self.limit {
+                    return;
+                }
+
+                match queue.pending.pop_front() {
+                    Some(job) => {
+                        queue.running += 1;
+                        job
+                    }
+                    None => return,
+                }
+            };
+
+            job(env);
+        }
+    }
+}
+
+// Catches a panic in `execute` so that the slot can be released on the JavaScript
+// thread before the panic is resumed. Otherwise, the slot would only be dropped
+// while unwinding and pending tasks would never start.
+fn catch_panic<O, E>(execute: E) -> impl FnOnce() -> thread::Result<O> + Send + 'static
+where
+    O: Send + 'static,
+    E: FnOnce() -> O + Send + 'static,
+{
+    move || catch_unwind(AssertUnwindSafe(execute))
+}
+
+// A running slot in a pool. Dropping a slot frees it without starting pending
+// tasks since it may be dropped after the environment has stopped (e.g., during
+// shutdown). Use `Slot::release` when the environment is live.
+struct Slot(Arc<PoolState>);
+
+impl Slot {
+    fn release(self, env: Env) {
+        let state = self.0.clone();
+
+        drop(self);
+        state.start(env);
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.lock().running -= 1;
+    }
+}
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use crate::{
+    context::{Context, TaskContext},
+    handle::Handle,
+    result::{JsResult, NeonResult},
+    sys::{
+        async_work::{self, ProgressSender as RawProgressSender},
+        raw,
+    },
+    types::{JsPromise, Value},
+};
+
+use super::task::{complete, complete_promise};
+
+type OnProgress<P> = dyn Fn(TaskContext, P) -> NeonResult<()> + Send + Sync + 'static;
+type Event = Box<dyn FnOnce(raw::Env) + Send + 'static>;
+
+/// Node asynchronous task builder for tasks that report progress
+///
+/// The `execute` callback is passed a [`ProgressSender`] which may be used to
+/// report progress from the Node worker pool. Each event is passed to the
+/// `on_progress` callback on the JavaScript main thread, in the order sent. All
+/// events sent before `execute` returns are delivered before the `complete`
+/// callback is called, and no events are delivered after it. An exception thrown
+/// or a panic in `on_progress` is reported as an `unhandledRejection`, as with
+/// [`Channel::send`](crate::event::Channel::send), and does not prevent delivery
+/// of later events.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn compress(_: &[u8]) -> Vec<u8> { todo!() }
+/// fn compress_chunks(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let chunks = vec![vec![0u8; 1024]; 16];
+///     let on_progress = cx.argument::<JsFunction>(0)?.root(&mut cx);
+///
+///     let promise = cx
+///         .task_with_progress(
+///             move |progress| {
+///                 let total = chunks.len();
+///                 let mut compressed = Vec::new();
+///
+///                 for (i, chunk) in chunks.iter().enumerate() {
+///                     compressed.extend(compress(chunk));
+///                     progress.send((i + 1) as f64 / total as f64);
+///                 }
+///
+///                 compressed.len()
+///             },
+///             move |mut cx, ratio: f64| {
+///                 on_progress
+///                     .to_inner(&mut cx)
+///                     .call_with(&cx)
+///                     .arg(cx.number(ratio))
+///                     .exec(&mut cx)
+///             },
+///         )
+///         .promise(|mut cx, len| Ok(cx.number(len as f64)));
+///
+///     Ok(promise)
+/// }
+/// ```
+pub struct ProgressTaskBuilder<'cx, C, E, P> {
+    cx: &'cx mut C,
+    execute: E,
+    on_progress: Arc<OnProgress<P>>,
+}
+
+impl<'a: 'cx, 'cx, C, O, E, P> ProgressTaskBuilder<'cx, C, E, P>
+where
+    C: Context<'a>,
+    O: Send + 'static,
+    E: FnOnce(ProgressSender<P>) -> O + Send + 'static,
+    P: Send + 'static,
+{
+    /// Construct a new task builder from an `execute` callback that can be
+    /// scheduled to execute on the Node worker pool and an `on_progress`
+    /// callback that is called on the JavaScript main thread with each event
+    pub fn new<G>(cx: &'cx mut C, execute: E, on_progress: G) -> Self
+    where
+        G: Fn(TaskContext, P) -> NeonResult<()> + Send + Sync + 'static,
+    {
+        Self {
+            cx,
+            execute,
+            on_progress: Arc::new(on_progress),
+        }
+    }
+
+    /// Schedules a task to execute on the Node worker pool, executing the
+    /// `complete` callback on the JavaScript main thread with the result
+    /// of the `execute` callback
+    pub fn and_then<F>(self, complete: F)
+    where
+        F: FnOnce(TaskContext, O) -> NeonResult<()> + 'static,
+    {
+        let env = self.cx.env().to_raw();
+
+        unsafe {
+            async_work::schedule_with_progress(
+                env,
+                (self.execute, self.on_progress),
+                execute::<E, P, O>,
+                deliver,
+                self::complete::<O, F>,
+                complete,
+            );
+        }
+    }
+
+    /// Schedules a task to execute on the Node worker pool and returns a
+    /// promise that is resolved with the value from the `complete` callback.
+    ///
+    /// The `complete` callback will execute on the JavaScript main thread and
+    /// is passed the return value from `execute`. If the `complete` callback
+    /// throws, the promise will be rejected with the exception. If either callback
+    /// panics, the promise will be rejected with an `Error` instead of aborting.
+    pub fn promise<V, F>(self, complete: F) -> Handle<'a, JsPromise>
+    where
+        V: Value,
+        F: FnOnce(TaskContext, O) -> JsResult<V> + 'static,
+    {
+        let env = self.cx.env().to_raw();
+        let (deferred, promise) = JsPromise::new(self.cx);
+
+        unsafe {
+            async_work::schedule_with_progress(
+                env,
+                (self.execute, self.on_progress),
+                execute::<E, P, O>,
+                deliver,
+                complete_promise::<O, F, V>,
+                (complete, deferred),
+            );
+        }
+
+        promise
+    }
+}
+
+/// Reports progress from a task created with [`Context::task_with_progress`]
+///
+/// A `ProgressSender` may be cloned and sent to other threads. Events sent after
+/// the task has completed are dropped.
+pub struct ProgressSender<P> {
+    sender: RawProgressSender<Event>,
+    on_progress: Arc<OnProgress<P>>,
+}
+
+impl<P> Clone for ProgressSender<P> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+            on_progress: self.on_progress.clone(),
+        }
+    }
+}
+
+impl<P> std::fmt::Debug for ProgressSender<P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ProgressSender")
+    }
+}
+
+impl<P: Send + 'static> ProgressSender<P> {
+    /// Sends a progress event to the JavaScript main thread. Returns `false` if
+    /// the task has already completed and the event was dropped.
+    pub fn send(&self, progress: P) -> bool {
+        let on_progress = self.on_progress.clone();
+
+        self.sender.send(Box::new(move |env| {
+            // A thrown exception is left pending and reported as an
+            // `unhandledRejection`, as with a closure passed to `Channel::send`
+            TaskContext::with_context(env.into(), move |cx| {
+                let _ = on_progress(cx, progress);
+            });
+        }))
+    }
+}
+
+fn execute<E, P, O>(
+    (execute, on_progress): (E, Arc<OnProgress<P>>),
+    sender: RawProgressSender<Event>,
+) -> O
+where
+    E: FnOnce(ProgressSender<P>) -> O + Send + 'static,
+    P: Send + 'static,
+    O: Send + 'static,
+{
+    execute(ProgressSender {
+        sender,
+        on_progress,
+    })
+}
+
+fn deliver(env: raw::Env, event: Event) {
+    event(env)
+}
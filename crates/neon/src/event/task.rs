@@ -4,7 +4,10 @@ use crate::{
     context::{internal::Env, Context, TaskContext},
     handle::Handle,
     result::{JsResult, NeonResult},
-    sys::{async_work, raw},
+    sys::{
+        async_work::{self, AsyncWorkHandle},
+        raw,
+    },
     types::{Deferred, JsPromise, Value},
 };
 
@@ -22,6 +25,14 @@ use crate::{
 ///     Ok(promise)
 /// }
 /// ```
+///
+/// # Panics
+///
+/// A panic in the `execute` callback is caught on the Node worker pool and resumed on
+/// the JavaScript main thread in place of the `complete` callback. For tasks scheduled
+/// with [`TaskBuilder::promise`], the promise is rejected with an `Error` and the
+/// panic payload is attached as its `panic` property. Otherwise, the panic is reported
+/// as an `uncaughtException`.
 pub struct TaskBuilder<'cx, C, E> {
     cx: &'cx mut C,
     execute: E,
@@ -52,12 +63,44 @@ where
         schedule(env, execute, complete);
     }
 
+    /// Schedules a task like [`TaskBuilder::and_then`], returning a [`TaskHandle`]
+    /// that may be used to cancel the task before it starts executing
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::buffer::TypedArray;
+    /// # fn compress(_: Vec<u8>) -> Vec<u8> { todo!() }
+    /// fn compress_or_abandon(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let data = cx.argument::<JsBuffer>(0)?.as_slice(&cx).to_vec();
+    ///     let task = cx
+    ///         .task(move || compress(data))
+    ///         .and_then_cancellable(|_cx, _compressed| Ok(()));
+    ///
+    ///     // The caller aborted; abandon the work if it has not started
+    ///     if !task.cancel(&mut cx) {
+    ///         // The task is already executing and `complete` will be called
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn and_then_cancellable<F>(self, complete: F) -> TaskHandle
+    where
+        F: FnOnce(TaskContext, O) -> NeonResult<()> + 'static,
+    {
+        let env = self.cx.env();
+        let execute = self.execute;
+
+        TaskHandle(schedule(env, execute, complete))
+    }
+
     /// Schedules a task to execute on the Node worker pool and returns a
     /// promise that is resolved with the value from the `complete` callback.
     ///
     /// The `complete` callback will execute on the JavaScript main thread and
     /// is passed the return value from `execute`. If the `complete` callback
-    /// throws, the promise will be rejected with the exception
+    /// throws, the promise will be rejected with the exception. If either callback
+    /// panics, the promise will be rejected with an `Error` instead of aborting.
     pub fn promise<V, F>(self, complete: F) -> Handle<'a, JsPromise>
     where
         V: Value,
@@ -73,16 +116,38 @@ where
     }
 }
 
+/// Handle to a task scheduled with [`TaskBuilder::and_then_cancellable`]
+///
+/// A `TaskHandle` may only be used on the JavaScript main thread that
+/// scheduled the task.
+pub struct TaskHandle(AsyncWorkHandle);
+
+impl std::fmt::Debug for TaskHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TaskHandle")
+    }
+}
+
+impl TaskHandle {
+    /// Attempts to cancel the task before it starts executing on the Node
+    /// worker pool
+    ///
+    /// Returns `true` if the task was cancelled; neither the `execute` nor the
+    /// `complete` callback will be called and both are dropped. Returns `false`
+    /// if the task is already executing, has completed, or was already cancelled.
+    pub fn cancel<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        unsafe { self.0.cancel(cx.env().to_raw()) }
+    }
+}
+
 // Schedule a task to execute on the Node worker pool
-fn schedule<I, O, D>(env: Env, input: I, data: D)
+pub(super) fn schedule<I, O, D>(env: Env, input: I, data: D) -> AsyncWorkHandle
 where
     I: FnOnce() -> O + Send + 'static,
     O: Send + 'static,
     D: FnOnce(TaskContext, O) -> NeonResult<()> + 'static,
 {
-    unsafe {
-        async_work::schedule(env.to_raw(), input, execute::<I, O>, complete::<O, D>, data);
-    }
+    unsafe { async_work::schedule(env.to_raw(), input, execute::<I, O>, complete::<O, D>, data) }
 }
 
 fn execute<I, O>(input: I) -> O
@@ -93,7 +158,7 @@ where
     input()
 }
 
-fn complete<O, D>(env: raw::Env, output: thread::Result<O>, callback: D)
+pub(super) fn complete<O, D>(env: raw::Env, output: thread::Result<O>, callback: D)
 where
     O: Send + 'static,
     D: FnOnce(TaskContext, O) -> NeonResult<()> + 'static,
@@ -110,7 +175,7 @@ where
 }
 
 // Schedule a task to execute on the Node worker pool and settle a `Promise` with the result
-fn schedule_promise<I, O, D, V>(env: Env, input: I, complete: D, deferred: Deferred)
+pub(super) fn schedule_promise<I, O, D, V>(env: Env, input: I, complete: D, deferred: Deferred)
 where
     I: FnOnce() -> O + Send + 'static,
     O: Send + 'static,
@@ -128,7 +193,7 @@ where
     }
 }
 
-fn complete_promise<O, D, V>(
+pub(super) fn complete_promise<O, D, V>(
     env: raw::Env,
     output: thread::Result<O>,
     (complete, deferred): (D, Deferred),
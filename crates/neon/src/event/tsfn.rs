@@ -0,0 +1,316 @@
+use std::{
+    error, fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::{
+    context::{Context, TaskContext},
+    event::Channel,
+    handle::{Handle, Root},
+    object::Object,
+    result::NeonResult,
+    types::{JsFunction, JsValue},
+};
+
+type Mapper<T> = dyn for<'cx> Fn(&mut TaskContext<'cx>, T) -> NeonResult<Vec<Handle<'cx, JsValue>>>
+    + Send
+    + Sync
+    + 'static;
+
+/// A JavaScript function that may be called from any thread.
+///
+/// Each call passes a payload of type `T` to the JavaScript main thread, where the
+/// `mapper` converts it into the arguments for the function. Unlike a [`Channel`],
+/// which schedules arbitrary closures, a `ThreadsafeFunction` is bound to a single
+/// function and payload type, and each call selects whether to block with
+/// [`CallMode`] when a bounded queue is full.
+///
+/// Cloning a `ThreadsafeFunction` creates a new handle that shares the queue,
+/// including any capacity bound, with the original.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::event::{CallMode, ThreadsafeFunction};
+///
+/// fn watch(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let on_event = cx.argument::<JsFunction>(0)?;
+///
+///     // At most 64 events are queued for JavaScript at a time
+///     let tsfn = ThreadsafeFunction::with_capacity(&mut cx, on_event, 64, |cx, (id, name)| {
+///         let id = cx.number(id).upcast();
+///         let name = cx.string(name).upcast();
+///
+///         Ok(vec![id, name])
+///     });
+///
+///     std::thread::spawn(move || {
+///         for id in 0..1000u32 {
+///             let event = (id, format!("event-{id}"));
+///
+///             // Drop events instead of waiting while JavaScript is busy
+///             if let Err(err) = tsfn.call(event, CallMode::NonBlocking) {
+///                 if err.is_closed() {
+///                     break;
+///                 }
+///             }
+///         }
+///     });
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub struct ThreadsafeFunction<T> {
+    channel: Channel,
+    state: Arc<State<T>>,
+}
+
+struct State<T> {
+    callback: Root<JsFunction>,
+    mapper: Box<Mapper<T>>,
+    is_aborted: AtomicBool,
+}
+
+impl<T> fmt::Debug for ThreadsafeFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ThreadsafeFunction")
+    }
+}
+
+impl<T: Send + 'static> ThreadsafeFunction<T> {
+    /// Creates a threadsafe function with an unbounded queue that calls `callback`
+    /// with the arguments returned by `mapper`.
+    pub fn new<'a, C, F>(cx: &mut C, callback: Handle<JsFunction>, mapper: F) -> Self
+    where
+        C: Context<'a>,
+        F: for<'cx> Fn(&mut TaskContext<'cx>, T) -> NeonResult<Vec<Handle<'cx, JsValue>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let channel = Channel::new(cx);
+
+        Self::with_channel(cx, channel, callback, mapper)
+    }
+
+    /// Creates a threadsafe function that allows at most `capacity` calls to be
+    /// pending or executing on the JavaScript main thread at a time.
+    ///
+    /// When the queue is full, a [`CallMode::NonBlocking`] call fails immediately and
+    /// a [`CallMode::Blocking`] call blocks the calling thread until a call has
+    /// finished executing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_capacity<'a, C, F>(
+        cx: &mut C,
+        callback: Handle<JsFunction>,
+        capacity: usize,
+        mapper: F,
+    ) -> Self
+    where
+        C: Context<'a>,
+        F: for<'cx> Fn(&mut TaskContext<'cx>, T) -> NeonResult<Vec<Handle<'cx, JsValue>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        let channel = Channel::bounded(cx, capacity);
+
+        Self::with_channel(cx, channel, callback, mapper)
+    }
+
+    fn with_channel<'a, C, F>(
+        cx: &mut C,
+        channel: Channel,
+        callback: Handle<JsFunction>,
+        mapper: F,
+    ) -> Self
+    where
+        C: Context<'a>,
+        F: for<'cx> Fn(&mut TaskContext<'cx>, T) -> NeonResult<Vec<Handle<'cx, JsValue>>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            channel,
+            state: Arc::new(State {
+                callback: callback.root(cx),
+                mapper: Box::new(mapper),
+                is_aborted: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Schedules a call to the JavaScript function with `payload`.
+    ///
+    /// The payload is converted into arguments by the `mapper` on the JavaScript main
+    /// thread. An exception thrown by the mapper or the function is treated the same
+    /// as an exception thrown by a [`Channel::send`] closure.
+    ///
+    /// # Errors
+    ///
+    /// The JavaScript main thread is the only thread that drains the queue, so it would
+    /// wait forever on a full bounded queue. When called from that thread, a
+    /// [`CallMode::Blocking`] call does not block and instead fails with a full
+    /// [`CallError`], like a [`CallMode::NonBlocking`] call.
+    pub fn call(&self, payload: T, mode: CallMode) -> Result<(), CallError> {
+        if self.is_aborted() {
+            return Err(CallError::closed());
+        }
+
+        let state = self.state.clone();
+        let f = move |mut cx: TaskContext| {
+            // Calls that were pending when the function was aborted are dropped
+            if state.is_aborted.load(Ordering::Acquire) {
+                return Ok(());
+            }
+
+            let callback = state.callback.to_inner(&mut cx);
+            let args = (state.mapper)(&mut cx, payload)?;
+            let this = cx.undefined();
+
+            callback.call(&mut cx, this, args)?;
+
+            Ok(())
+        };
+
+        match mode {
+            // `send_blocking` only fails on a live channel if it would have blocked
+            // the JavaScript main thread
+            CallMode::Blocking => self.channel.send_blocking(f).map(|_| ()).map_err(|_| {
+                if self.channel.is_alive() {
+                    CallError::full()
+                } else {
+                    CallError::closed()
+                }
+            }),
+            CallMode::NonBlocking => {
+                self.channel
+                    .try_send_or_return(f)
+                    .map(|_| ())
+                    .map_err(|err| {
+                        if err.is_full() {
+                            CallError::full()
+                        } else {
+                            CallError::closed()
+                        }
+                    })
+            }
+        }
+    }
+
+    /// Aborts the threadsafe function.
+    ///
+    /// Subsequent calls on this or any cloned handle fail with a closed [`CallError`],
+    /// and calls that are still pending are dropped without calling the JavaScript
+    /// function. A call that is already executing is not interrupted.
+    pub fn abort(&self) {
+        self.state.is_aborted.store(true, Ordering::Release);
+    }
+
+    /// Returns `true` if [`ThreadsafeFunction::abort`] has been called on this or any
+    /// cloned handle.
+    pub fn is_aborted(&self) -> bool {
+        self.state.is_aborted.load(Ordering::Acquire)
+    }
+
+    /// Allow the Node event loop to exit while this `ThreadsafeFunction` exists.
+    /// _Idempotent_
+    pub fn unref<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
+        self.channel.unref(cx);
+        self
+    }
+
+    /// Prevent the Node event loop from exiting while this `ThreadsafeFunction`
+    /// exists. (Default)
+    /// _Idempotent_
+    pub fn reference<'a, C: Context<'a>>(&mut self, cx: &mut C) -> &mut Self {
+        self.channel.reference(cx);
+        self
+    }
+}
+
+impl<T> Clone for ThreadsafeFunction<T> {
+    fn clone(&self) -> Self {
+        Self {
+            channel: self.channel.clone(),
+            state: self.state.clone(),
+        }
+    }
+}
+
+/// Selects the behavior of [`ThreadsafeFunction::call`] when the queue of a bounded
+/// threadsafe function is full
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallMode {
+    /// Block the calling thread until there is room in the queue
+    Blocking,
+    /// Fail immediately with a [`CallError`] if the queue is full
+    NonBlocking,
+}
+
+/// Error returned by [`ThreadsafeFunction::call`] when a call could not be scheduled
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub struct CallError {
+    kind: CallErrorKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CallErrorKind {
+    Full,
+    Closed,
+}
+
+impl CallError {
+    fn full() -> Self {
+        Self {
+            kind: CallErrorKind::Full,
+        }
+    }
+
+    fn closed() -> Self {
+        Self {
+            kind: CallErrorKind::Closed,
+        }
+    }
+
+    /// Returns `true` if the call was not scheduled because the queue was full
+    pub fn is_full(&self) -> bool {
+        self.kind == CallErrorKind::Full
+    }
+
+    /// Returns `true` if the call was not scheduled because the threadsafe function
+    /// was aborted or the JavaScript environment has stopped
+    pub fn is_closed(&self) -> bool {
+        self.kind == CallErrorKind::Closed
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            CallErrorKind::Full => f.write_str("ThreadsafeFunction is full"),
+            CallErrorKind::Closed => f.write_str("ThreadsafeFunction is closed"),
+        }
+    }
+}
+
+impl fmt::Debug for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            CallErrorKind::Full => f.write_str("CallError::Full"),
+            CallErrorKind::Closed => f.write_str("CallError::Closed"),
+        }
+    }
+}
+
+impl error::Error for CallError {}
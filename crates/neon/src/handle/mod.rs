@@ -4,7 +4,7 @@
 //! by the JavaScript engine's memory management system (the garbage collector).
 //!
 //! Neon APIs that accept and return JavaScript values never use raw pointer types
-//! ([`*T`](pointer)) or reference types ([`&T`](reference)). Instead they use the
+//! ([`*T`](pointer)) or reference types ([`&T`](prim@reference)). Instead they use the
 //! special Neon type [`Handle`], which encapsulates a JavaScript
 //! [`Value`] and ensures that Rust only maintains access to
 //! the value while it is guaranteed to be valid.
@@ -48,6 +48,7 @@
 
 pub(crate) mod internal;
 
+pub(crate) mod reference;
 pub(crate) mod root;
 
 use std::{
@@ -58,16 +59,19 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-pub use self::root::Root;
+pub use self::{reference::Reference, root::Root};
 
 use crate::{
     context::Context,
     handle::internal::{SuperType, TransparentNoCopyWrapper},
-    result::{JsResult, ResultExt},
+    result::{JsResult, NeonResult, ResultExt},
     sys,
-    types::Value,
+    types::{JsBoolean, JsFunction, JsValue, Value},
 };
 
+#[cfg(feature = "napi-6")]
+use crate::{object::Object, thread::LocalKey};
+
 /// A handle to a JavaScript value that is owned by the JavaScript engine.
 #[derive(Debug)]
 #[repr(transparent)]
@@ -96,10 +100,15 @@ impl<'a, V: Value + 'a> Handle<'a, V> {
 }
 
 /// An error representing a failed downcast.
+///
+/// Errors thrown by [`Handle::downcast_or_throw`] also include the
+/// [debug representation](Value::to_debug_string) of the value in their message.
+/// [`Handle::downcast`] omits it so that checking the type of a value stays cheap.
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub struct DowncastError<F: Value, T: Value> {
     phantom_from: PhantomData<F>,
     phantom_to: PhantomData<T>,
+    value: Option<String>,
 }
 
 impl<F: Value, T: Value> Debug for DowncastError<F, T> {
@@ -113,13 +122,27 @@ impl<F: Value, T: Value> DowncastError<F, T> {
         DowncastError {
             phantom_from: PhantomData,
             phantom_to: PhantomData,
+            value: None,
+        }
+    }
+
+    fn with_value(self, value: String) -> Self {
+        DowncastError {
+            value: Some(value),
+            ..self
         }
     }
 }
 
 impl<F: Value, T: Value> Display for DowncastError<F, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "failed to downcast {} to {}", F::name(), T::name())
+        write!(f, "failed to downcast {} to {}", F::name(), T::name())?;
+
+        if let Some(value) = &self.value {
+            write!(f, ": {value}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -175,12 +198,86 @@ impl<'a, T: Value> Handle<'a, T> {
     }
 
     /// Attempts to downcast a handle to another type, raising a JavaScript `TypeError`
-    /// exception on failure. This method is a convenient shorthand, equivalent to
-    /// `self.downcast::<U>().or_throw::<C>(cx)`.
+    /// exception on failure. This method is a convenient shorthand, similar to
+    /// `self.downcast::<U>().or_throw::<C>(cx)`, except that the error message also
+    /// includes the [debug representation](Value::to_debug_string) of the value.
     pub fn downcast_or_throw<'b, U: Value, C: Context<'b>>(&self, cx: &mut C) -> JsResult<'a, U> {
-        self.downcast(cx).or_throw(cx)
+        match self.downcast(cx) {
+            Ok(v) => Ok(v),
+            Err(err) => {
+                let err = err.with_value(self.to_debug_string(cx));
+
+                cx.throw_type_error(err.to_string())
+            }
+        }
+    }
+
+    /// Attempts to downcast a handle to another type, returning `default` on failure.
+    ///
+    /// Arguments passed to `default` are eagerly evaluated; if passing a newly
+    /// created value, consider [`Handle::downcast_or_else`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn greet(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let default = cx.string("world");
+    ///     let name = cx.argument::<JsValue>(0)?.downcast_or(&mut cx, default);
+    ///     let name = name.value(&mut cx);
+    ///
+    ///     Ok(cx.string(format!("hello, {name}!")))
+    /// }
+    /// ```
+    pub fn downcast_or<'b: 'a, U: Value, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        default: Handle<'a, U>,
+    ) -> Handle<'a, U> {
+        self.downcast(cx).unwrap_or(default)
+    }
+
+    /// Attempts to downcast a handle to another type, computing a fallback from a
+    /// closure on failure. This is useful for reading loosely typed options.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn timeout(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let options = cx.argument::<JsObject>(0)?;
+    ///     let timeout = options
+    ///         .get_value(&mut cx, "timeout")?
+    ///         .downcast_or_else(&mut cx, |cx| cx.number(1000));
+    ///
+    ///     Ok(timeout)
+    /// }
+    /// ```
+    pub fn downcast_or_else<'b: 'a, U, C, F>(&self, cx: &mut C, f: F) -> Handle<'a, U>
+    where
+        U: Value,
+        C: Context<'b>,
+        F: FnOnce(&mut C) -> Handle<'a, U>,
+    {
+        match self.downcast(cx) {
+            Ok(v) => v,
+            Err(_) => f(cx),
+        }
     }
 
+    /// Compares two values with JavaScript's
+    /// [strict equality](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Strict_equality)
+    /// operator (`===`).
+    ///
+    /// Objects are compared by identity. As in JavaScript, `NaN` is not equal to itself
+    /// and `+0` is equal to `-0`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn is_same(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let a = cx.argument::<JsValue>(0)?;
+    ///     let b = cx.argument::<JsValue>(1)?;
+    ///     let eq = a.strict_equals(&mut cx, b);
+    ///
+    ///     Ok(cx.boolean(eq))
+    /// }
+    /// ```
     pub fn strict_equals<'b, U: Value, C: Context<'b>>(
         &self,
         cx: &mut C,
@@ -188,6 +285,94 @@ impl<'a, T: Value> Handle<'a, T> {
     ) -> bool {
         unsafe { sys::mem::strict_equals(cx.env().to_raw(), self.to_local(), other.to_local()) }
     }
+
+    /// Compares two values with JavaScript's
+    /// [loose equality](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Equality)
+    /// operator (`==`), which converts operands of different types before comparing.
+    ///
+    /// Converting an object to a primitive may call user defined methods (e.g.,
+    /// `valueOf`), so this may throw. Prefer [`Handle::strict_equals`] when type
+    /// conversion is not needed.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn is_zero(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let v = cx.argument::<JsValue>(0)?;
+    ///     let zero = cx.number(0);
+    ///
+    ///     // `true` for `0`, `"0"`, `""`, `false` and `[]`
+    ///     let eq = v.loose_equals(&mut cx, zero)?;
+    ///
+    ///     Ok(cx.boolean(eq))
+    /// }
+    /// ```
+    pub fn loose_equals<'b, U: Value, C: Context<'b>>(
+        &self,
+        cx: &mut C,
+        other: Handle<'b, U>,
+    ) -> NeonResult<bool> {
+        let this = cx.undefined();
+        let lhs = self.upcast::<JsValue>();
+        let rhs = other.upcast::<JsValue>();
+
+        Ok(loose_equals(cx)?
+            .call(cx, this, [lhs, rhs])?
+            .downcast_or_throw::<JsBoolean, _>(cx)?
+            .value(cx))
+    }
+
+    /// Returns `true` if both handles are copies of the same handle, without calling
+    /// into the JavaScript engine.
+    ///
+    /// This compares handle identity, **not** value identity or equality. Each time a
+    /// value is read (e.g., from an argument or a property), Node-API may produce a new
+    /// handle for it, so `false` does not imply that the values are different. A `true`
+    /// result always implies that the values are the same. Use
+    /// [`Handle::strict_equals`] to determine whether two values are the same object.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn same_handle(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let a = cx.argument::<JsObject>(0)?;
+    ///     let b = a;
+    ///
+    ///     Ok(cx.boolean(a.ptr_eq(&b)))
+    /// }
+    /// ```
+    pub fn ptr_eq(&self, other: &Handle<T>) -> bool {
+        self.to_local() == other.to_local()
+    }
+}
+
+// Node-API does not provide the abstract equality algorithm; compile a function
+// that applies the operator instead of re-implementing the conversion rules
+fn compile_loose_equals<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    let script = cx.string("(function looseEquals(a, b) { return a == b; })");
+
+    crate::reflect::eval(cx, script)?.downcast_or_throw(cx)
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn loose_equals<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    compile_loose_equals(cx)
+}
+
+#[cfg(feature = "napi-6")]
+fn loose_equals<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    static LOOSE_EQUALS: LocalKey<Root<JsFunction>> = LocalKey::new();
+
+    LOOSE_EQUALS
+        .get_or_try_init(cx, |cx| compile_loose_equals(cx).map(|f| f.root(cx)))
+        .map(|f| f.to_inner(cx))
 }
 
 impl<'a, V: Value> Deref for Handle<'a, V> {
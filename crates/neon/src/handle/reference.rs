@@ -0,0 +1,207 @@
+use std::marker::PhantomData;
+
+use crate::{
+    context::Context,
+    handle::{
+        root::{instance_id, NapiRef},
+        Handle,
+    },
+    object::Object,
+    sys::reference,
+    types::boxed::Finalize,
+};
+
+#[cfg(feature = "napi-6")]
+use {
+    crate::{
+        lifecycle::{DropData, InstanceData, InstanceId},
+        sys::tsfn::ThreadsafeFunction,
+    },
+    std::sync::Arc,
+};
+
+#[cfg(not(feature = "napi-6"))]
+use crate::handle::root::InstanceId;
+
+/// A reference counted handle to a JavaScript object with manual control of
+/// the reference count.
+///
+/// A `Reference<T>` is a thin wrapper over a Node-API reference. While the
+/// count is greater than zero, the object is prevented from being garbage
+/// collected. When the count reaches zero, the reference becomes _weak_ and
+/// [`Reference::to_inner`] will return `None` after the object has been collected.
+///
+/// Unlike [`Root`](crate::handle::Root), a `Reference<T>` is not deleted when
+/// the count reaches zero; it is only deleted by [`Reference::delete`] or when
+/// dropped. Most users should prefer `Root`.
+///
+/// A `Reference<T>` may be sent across threads, but the referenced object may
+/// only be accessed on the JavaScript thread that created it.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::handle::Reference;
+/// fn weak_ref(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///     let reference = Reference::new(&mut cx, &*obj, 1);
+///
+///     // Allow the object to be garbage collected
+///     assert_eq!(reference.unref(&mut cx), 0);
+///
+///     // The object is still reachable from `obj`
+///     assert!(reference.to_inner(&mut cx).is_some());
+///
+///     reference.delete(&mut cx);
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+pub struct Reference<T> {
+    // `Option` is used to skip `Drop` when `Reference::delete` is used.
+    // It will *always* be `Some` when a user is interacting with `Reference`.
+    internal: Option<NapiRef>,
+    instance_id: InstanceId,
+    #[cfg(feature = "napi-6")]
+    drop_queue: Arc<ThreadsafeFunction<DropData>>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> std::fmt::Debug for Reference<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Reference<{}>", std::any::type_name::<T>())
+    }
+}
+
+// Safety: `Reference` contains a `NapiRef` which is `Send` and `Sync` and a
+// `PhantomData` that does not impact the safety.
+unsafe impl<T> Send for Reference<T> {}
+
+unsafe impl<T> Sync for Reference<T> {}
+
+impl<T: Object> Reference<T> {
+    /// Create a reference to a JavaScript object with an initial reference count.
+    /// An `initial_count` of `0` creates a weak reference.
+    ///
+    /// The caller _should_ ensure `Reference::delete` is called to dispose of the
+    /// `Reference<T>`. If the value is dropped without calling it:
+    /// * N-API < 6, Neon will `panic` to notify of the leak
+    /// * N-API >= 6, Neon will delete from a global queue at a runtime cost
+    pub fn new<'a, C: Context<'a>>(cx: &mut C, value: &T, initial_count: u32) -> Self {
+        let env = cx.env().to_raw();
+        let internal = unsafe { reference::with_count(env, value.to_local(), initial_count) };
+
+        Self {
+            internal: Some(NapiRef(internal as *mut _)),
+            instance_id: instance_id(cx),
+            #[cfg(feature = "napi-6")]
+            drop_queue: InstanceData::drop_queue(cx),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Increment the reference count, returning the new count.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the reference was created.
+    pub fn reference<'a, C: Context<'a>>(&self, cx: &mut C) -> u32 {
+        let env = cx.env().to_raw();
+        let internal = self.as_napi_ref(cx).0.cast();
+
+        unsafe { reference::reference(env, internal) as u32 }
+    }
+
+    /// Decrement the reference count, returning the new count. The reference
+    /// becomes weak when the count reaches zero, but it is not deleted.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the reference was created, or if the count is already zero.
+    pub fn unref<'a, C: Context<'a>>(&self, cx: &mut C) -> u32 {
+        let env = cx.env().to_raw();
+        let internal = self.as_napi_ref(cx).0.cast();
+
+        unsafe { reference::unref(env, internal) as u32 }
+    }
+
+    /// Access the referenced JavaScript object, or `None` if the reference is
+    /// weak and the object has been garbage collected.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the reference was created.
+    pub fn to_inner<'a, C: Context<'a>>(&self, cx: &mut C) -> Option<Handle<'a, T>> {
+        let env = cx.env();
+        let local = unsafe { reference::get(env.to_raw(), self.as_napi_ref(cx).0.cast()) };
+
+        if local.is_null() {
+            return None;
+        }
+
+        Some(Handle::new_internal(unsafe { T::from_local(env, local) }))
+    }
+
+    /// Delete the reference, regardless of the current count.
+    pub fn delete<'a, C: Context<'a>>(mut self, cx: &mut C) {
+        let env = cx.env().to_raw();
+        let internal = self.as_napi_ref(cx).clone();
+
+        self.internal = None;
+
+        unsafe {
+            internal.delete(env);
+        }
+    }
+
+    fn as_napi_ref<'a, C: Context<'a>>(&self, cx: &mut C) -> &NapiRef {
+        if self.instance_id != instance_id(cx) {
+            panic!("Attempted to dereference a `neon::handle::Reference` from the wrong module ");
+        }
+
+        self.internal
+            .as_ref()
+            // `unwrap` will not `panic` because `internal` will always be `Some`
+            // until the `Reference` is deleted.
+            .unwrap()
+    }
+}
+
+// Allows putting `Reference<T>` directly in a container that implements `Finalize`
+impl<T: Object> Finalize for Reference<T> {
+    fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
+        self.delete(cx);
+    }
+}
+
+impl<T> Drop for Reference<T> {
+    #[cfg(not(feature = "napi-6"))]
+    fn drop(&mut self) {
+        // If `None`, the `NapiRef` has already been manually deleted
+        if self.internal.is_none() {
+            return;
+        }
+
+        // Destructors are called during stack unwinding, prevent a double
+        // panic and instead prefer to leak.
+        if std::thread::panicking() {
+            eprintln!("Warning: neon::handle::Reference leaked during a panic");
+            return;
+        }
+
+        // Only panic if the event loop is still running
+        if let Ok(true) = crate::context::internal::IS_RUNNING.try_with(|v| *v.borrow()) {
+            panic!("Must call `delete` on `neon::handle::Reference`");
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn drop(&mut self) {
+        // If `None`, the `NapiRef` has already been manually deleted
+        if let Some(internal) = self.internal.take() {
+            let _ = self.drop_queue.call(DropData::Reference(internal), None);
+        }
+    }
+}
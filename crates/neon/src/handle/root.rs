@@ -2,7 +2,7 @@ use std::{ffi::c_void, marker::PhantomData};
 
 use crate::{
     context::Context,
-    handle::Handle,
+    handle::{Handle, Reference},
     object::Object,
     sys::{raw, reference},
     types::boxed::Finalize,
@@ -21,11 +21,11 @@ use {
 use std::thread::{self, ThreadId};
 
 #[cfg(not(feature = "napi-6"))]
-type InstanceId = ThreadId;
+pub(crate) type InstanceId = ThreadId;
 
 #[repr(transparent)]
 #[derive(Clone)]
-pub(crate) struct NapiRef(*mut c_void);
+pub(crate) struct NapiRef(pub(super) *mut c_void);
 
 impl NapiRef {
     /// # Safety
@@ -33,6 +33,12 @@ impl NapiRef {
     pub(crate) unsafe fn unref(self, env: raw::Env) {
         reference::unreference(env, self.0.cast());
     }
+
+    /// # Safety
+    /// Must only be used from the same module context that created the reference
+    pub(crate) unsafe fn delete(self, env: raw::Env) {
+        reference::delete(env, self.0.cast());
+    }
 }
 
 // # Safety
@@ -49,6 +55,30 @@ unsafe impl Sync for NapiRef {}
 ///
 /// A `Root<T>` may be sent across threads, but the referenced object may
 /// only be accessed on the JavaScript thread that created it.
+///
+/// Roots are commonly used to hold a JavaScript callback while work completes
+/// on another thread. With N-API 6 or later, a `Root<T>` dropped on any thread
+/// is released on the JavaScript thread by a global queue.
+///
+/// ```
+/// # use neon::prelude::*;
+/// fn call_later(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+///     let channel = cx.channel();
+///
+///     std::thread::spawn(move || {
+///         // Perform work off the JavaScript thread, then call back
+///         channel.send(move |mut cx| {
+///             callback
+///                 .into_inner(&mut cx)
+///                 .call_with(&cx)
+///                 .exec(&mut cx)
+///         });
+///     });
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
 pub struct Root<T> {
     // `Option` is used to skip `Drop` when `Root::drop` or `Root::into_inner` is used.
     // It will *always* be `Some` when a user is interacting with `Root`.
@@ -73,12 +103,12 @@ unsafe impl<T> Send for Root<T> {}
 unsafe impl<T> Sync for Root<T> {}
 
 #[cfg(feature = "napi-6")]
-fn instance_id<'a, C: Context<'a>>(cx: &mut C) -> InstanceId {
+pub(crate) fn instance_id<'a, C: Context<'a>>(cx: &mut C) -> InstanceId {
     InstanceData::id(cx)
 }
 
 #[cfg(not(feature = "napi-6"))]
-fn instance_id<'a, C: Context<'a>>(_: &mut C) -> InstanceId {
+pub(crate) fn instance_id<'a, C: Context<'a>>(_: &mut C) -> InstanceId {
     thread::current().id()
 }
 
@@ -177,6 +207,43 @@ impl<T: Object> Root<T> {
         Handle::new_internal(unsafe { T::from_local(env, local) })
     }
 
+    /// Creates a weak [`Reference`] to the same JavaScript object that does not prevent
+    /// it from being garbage collected.
+    ///
+    /// [`Reference::to_inner`] returns `None` once the object has been collected. This
+    /// is useful for caches and registries of objects that should not be kept alive
+    /// only by native code. Like a `Root`, the `Reference` can only be dereferenced in
+    /// the module instance that created it and is freed when dropped on Node-API 6 or
+    /// later. On earlier versions, [`Reference::delete`] must be called instead.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if it is called from a different JavaScript thread than the
+    /// one in which the handle was created.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn my_neon_function(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let root = cx.argument::<JsObject>(0)?.root(&mut cx);
+    /// let weak = root.downgrade(&mut cx);
+    ///
+    /// // Only the weak reference remains; the object may now be collected
+    /// root.drop(&mut cx);
+    ///
+    /// if let Some(obj) = weak.to_inner(&mut cx) {
+    ///     // The object is still alive
+    /// #   let _ = obj;
+    /// }
+    /// # weak.delete(&mut cx);
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn downgrade<'a, C: Context<'a>>(&self, cx: &mut C) -> Reference<T> {
+        let value = self.to_inner(cx);
+
+        Reference::new(cx, &*value, 0)
+    }
+
     fn as_napi_ref<'a, C: Context<'a>>(&self, cx: &mut C) -> &NapiRef {
         if self.instance_id != instance_id(cx) {
             panic!("Attempted to dereference a `neon::handle::Root` from the wrong module ");
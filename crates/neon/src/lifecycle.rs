@@ -213,6 +213,7 @@ impl<'cx, 'a, C: Context<'cx>> Drop for TryInitTransaction<'cx, 'a, C> {
 pub(crate) enum DropData {
     Deferred(NodeApiDeferred),
     Ref(NapiRef),
+    Reference(NapiRef),
 }
 
 impl DropData {
@@ -223,6 +224,7 @@ impl DropData {
                 match data {
                     DropData::Deferred(data) => data.leaked(env),
                     DropData::Ref(data) => data.unref(env),
+                    DropData::Reference(data) => data.delete(env),
                 }
             }
         }
@@ -273,6 +275,20 @@ impl InstanceData {
         Arc::clone(&InstanceData::get(cx).drop_queue)
     }
 
+    /// Toggles whether the `drop_queue` keeps the event loop alive
+    pub(crate) fn keep_drop_queue_alive<'cx, C: Context<'cx>>(cx: &mut C, keep: bool) {
+        let env = cx.env().to_raw();
+        let drop_queue = &InstanceData::get(cx).drop_queue;
+
+        unsafe {
+            if keep {
+                drop_queue.reference(env);
+            } else {
+                drop_queue.unref(env);
+            }
+        }
+    }
+
     /// Clones the shared channel and references it since new channels should start
     /// referenced, but the shared channel is unreferenced.
     pub(crate) fn channel<'cx, C: Context<'cx>>(cx: &mut C) -> Channel {
@@ -8,8 +8,8 @@
 //! ## Property Keys
 //!
 //! Object properties are accessed by a _property key_, which in JavaScript
-//! can be a string or [symbol][symbol]. (Neon does not yet have support for
-//! symbols.) For convenience, the [`PropertyKey`] trait allows
+//! can be a string or [symbol][symbol] (see [`JsSymbol`](crate::types::JsSymbol)).
+//! For convenience, the [`PropertyKey`] trait allows
 //! Neon programs to use various Rust string types, as well as numeric types,
 //! as keys when accessing object properties, converting the keys to strings
 //! as necessary:
@@ -29,19 +29,49 @@
 //! }
 //! ```
 //!
+//! Integer keys (`u32`) are accessed with the Node-API element functions
+//! ([`napi_get_element`][get-element] and [`napi_set_element`][set-element]), which
+//! work on any object, not only arrays. Setting an integer key on a plain object does
+//! not create or update a `length` property, so an object may be used as a compact,
+//! sparse integer-keyed map without converting each key to a string:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! // Builds `{ 2: "two", 1000: "thousand" }` and reads back key `1000`
+//! fn sparse(mut cx: FunctionContext) -> JsResult<JsString> {
+//!     let obj = cx.empty_object();
+//!     let two = cx.string("two");
+//!     let thousand = cx.string("thousand");
+//!
+//!     obj.set(&mut cx, 2, two)?;
+//!     obj.set(&mut cx, 1000, thousand)?;
+//!
+//!     obj.get(&mut cx, 1000)
+//! }
+//! ```
+//!
 //! [hierarchy]: crate::types#the-javascript-type-hierarchy
+//! [get-element]: https://nodejs.org/api/n-api.html#napi_get_element
+//! [set-element]: https://nodejs.org/api/n-api.html#napi_set_element
 //! [symbol]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol
 
 use crate::{
     context::Context,
     handle::{Handle, Root},
-    result::{NeonResult, Throw},
+    result::{JsResult, NeonResult, Throw},
     sys::{self, raw},
-    types::{build, function::CallOptions, utf8::Utf8, JsFunction, JsUndefined, JsValue, Value},
+    types::{
+        build, function::CallOptions, utf8::Utf8, JsBoolean, JsFunction, JsObject, JsUndefined,
+        JsValue, Value,
+    },
+    types_impl::builtin::Builtin,
 };
 
 #[cfg(feature = "napi-6")]
-use crate::{result::JsResult, types::JsArray};
+use crate::{
+    types::{JsArray, JsNumber, JsString, JsSymbol},
+    types_impl::private::ValueInternal,
+};
 
 /// A property key in a JavaScript object.
 pub trait PropertyKey {
@@ -59,6 +89,72 @@ pub trait PropertyKey {
         obj: raw::Local,
         val: raw::Local,
     ) -> bool;
+
+    /// Checks whether `obj` or its prototype chain has a property named by this key.
+    /// Returns `false` if the check failed.
+    ///
+    /// The default implementation throws a `TypeError`.
+    ///
+    /// # Safety
+    ///
+    /// `obj` must be a valid object handle in the current environment.
+    unsafe fn has_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        _out: &mut bool,
+        _obj: raw::Local,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        unsupported(cx, "has")
+    }
+
+    /// Checks whether `obj` has an own property named by this key.
+    /// Returns `false` if the check failed.
+    ///
+    /// The default implementation throws a `TypeError`.
+    ///
+    /// # Safety
+    ///
+    /// `obj` must be a valid object handle in the current environment.
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        _out: &mut bool,
+        _obj: raw::Local,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        unsupported(cx, "has_own_property")
+    }
+
+    /// Deletes the property of `obj` named by this key. Returns `false` if the
+    /// deletion failed.
+    ///
+    /// The default implementation throws a `TypeError`.
+    ///
+    /// # Safety
+    ///
+    /// `obj` must be a valid object handle in the current environment.
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        _out: &mut bool,
+        _obj: raw::Local,
+    ) -> bool
+    where
+        Self: Sized,
+    {
+        unsupported(cx, "delete")
+    }
+}
+
+fn unsupported<'c, C: Context<'c>>(cx: &mut C, operation: &str) -> bool {
+    let _ = cx.throw_type_error::<_, ()>(format!("property key does not support `{operation}`"));
+
+    false
 }
 
 impl PropertyKey for u32 {
@@ -80,6 +176,34 @@ impl PropertyKey for u32 {
     ) -> bool {
         sys::object::set_index(out, cx.env().to_raw(), obj, self, val)
     }
+
+    unsafe fn has_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        sys::object::has_index(out, cx.env().to_raw(), obj, self)
+    }
+
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        // Own property checks require a string or symbol key
+        self.to_string().as_str().has_own_from(cx, out, obj)
+    }
+
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        sys::object::delete_index(out, cx.env().to_raw(), obj, self)
+    }
 }
 
 impl<'a, K: Value> PropertyKey for Handle<'a, K> {
@@ -105,6 +229,49 @@ impl<'a, K: Value> PropertyKey for Handle<'a, K> {
 
         sys::object::set(out, env, obj, self.to_local(), val)
     }
+
+    unsafe fn has_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+
+        sys::object::has(out, env, obj, self.to_local())
+    }
+
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+        let mut key = self.to_local();
+
+        // Own property checks require a string or symbol key; coerce other
+        // values the same way as `Object.prototype.hasOwnProperty`.
+        if !sys::tag::is_string(env, key)
+            && !sys::tag::is_symbol(env, key)
+            && !sys::convert::to_string(&mut key, env, self.to_local())
+        {
+            return false;
+        }
+
+        sys::object::has_own(out, env, obj, key)
+    }
+
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+
+        sys::object::delete(out, env, obj, self.to_local())
+    }
 }
 
 impl<'a> PropertyKey for &'a str {
@@ -132,6 +299,196 @@ impl<'a> PropertyKey for &'a str {
 
         sys::object::set_string(env, out, obj, ptr, len, val)
     }
+
+    unsafe fn has_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+
+        match string_key(env, self) {
+            Some(key) => sys::object::has(out, env, obj, key),
+            None => false,
+        }
+    }
+
+    unsafe fn has_own_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+
+        match string_key(env, self) {
+            Some(key) => sys::object::has_own(out, env, obj, key),
+            None => false,
+        }
+    }
+
+    unsafe fn delete_from<'c, C: Context<'c>>(
+        self,
+        cx: &mut C,
+        out: &mut bool,
+        obj: raw::Local,
+    ) -> bool {
+        let env = cx.env().to_raw();
+
+        match string_key(env, self) {
+            Some(key) => sys::object::delete(out, env, obj, key),
+            None => false,
+        }
+    }
+}
+
+unsafe fn string_key(env: raw::Env, key: &str) -> Option<raw::Local> {
+    let (ptr, len) = Utf8::from(key).into_small_unwrap().lower();
+    let mut local: raw::Local = std::mem::zeroed();
+
+    if sys::string::new(&mut local, env, ptr, len) {
+        Some(local)
+    } else {
+        None
+    }
+}
+
+static OBJECT: Builtin = Builtin::new_static(
+    "Object",
+    &[
+        "isFrozen",
+        "isSealed",
+        "defineProperty",
+        "getPrototypeOf",
+        "setPrototypeOf",
+    ],
+);
+
+// Calls `Object.defineProperty(obj, key, descriptor)`
+fn define_property<'a, C: Context<'a>>(
+    cx: &mut C,
+    obj: raw::Local,
+    key: &str,
+    descriptor: Handle<JsObject>,
+) -> NeonResult<()> {
+    let obj = JsValue::new_internal(obj);
+    let key = cx.string(key);
+    let undefined = cx.undefined();
+
+    OBJECT.method(cx, "defineProperty")?.call(
+        cx,
+        undefined,
+        [obj, key.upcast(), descriptor.upcast()],
+    )?;
+
+    Ok(())
+}
+
+/// The attributes of a data property defined with [`Object::define_data_property`].
+///
+/// The default value has every attribute set to `false`, matching
+/// [`Object.defineProperty`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PropertyAttributes {
+    /// Whether the value may be changed with an assignment
+    pub writable: bool,
+    /// Whether the property is listed by `Object.keys` and `for...in` loops
+    pub enumerable: bool,
+    /// Whether the property may be deleted or redefined
+    pub configurable: bool,
+}
+
+// Calls a static predicate of the global `Object`, e.g., `Object.isFrozen(obj)`
+fn object_predicate<'a, C: Context<'a>>(
+    cx: &mut C,
+    name: &str,
+    obj: raw::Local,
+) -> NeonResult<bool> {
+    let obj = JsValue::new_internal(obj);
+    let undefined = cx.undefined();
+
+    OBJECT
+        .method(cx, name)?
+        .call(cx, undefined, [obj])?
+        .downcast_or_throw::<JsBoolean, _>(cx)
+        .map(|b| b.value(cx))
+}
+
+/// A builder for collecting the property keys of an object.
+///
+/// Created by [`Object::property_names_with`]. Each option narrows or widens the
+/// set of keys, matching the options of
+/// [`napi_get_all_property_names`](https://nodejs.org/api/n-api.html#napi_get_all_property_names).
+#[cfg(feature = "napi-6")]
+#[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+pub struct PropertyNamesOptions<'a> {
+    object: Handle<'a, JsValue>,
+    mode: sys::KeyCollectionMode,
+    filter: sys::KeyFilter,
+    conversion: sys::KeyConversion,
+}
+
+#[cfg(feature = "napi-6")]
+impl<'a> PropertyNamesOptions<'a> {
+    /// Include keys inherited from the object's prototype chain.
+    pub fn include_prototypes(&mut self) -> &mut Self {
+        self.mode = sys::KeyCollectionMode::IncludePrototypes;
+        self
+    }
+
+    /// Only include the keys of enumerable properties.
+    pub fn enumerable(&mut self) -> &mut Self {
+        self.filter |= sys::KeyFilter::ENUMERABLE;
+        self
+    }
+
+    /// Only include the keys of writable properties.
+    pub fn writable(&mut self) -> &mut Self {
+        self.filter |= sys::KeyFilter::WRITABLE;
+        self
+    }
+
+    /// Only include the keys of configurable properties.
+    pub fn configurable(&mut self) -> &mut Self {
+        self.filter |= sys::KeyFilter::CONFIGURABLE;
+        self
+    }
+
+    /// Exclude string keys.
+    pub fn skip_strings(&mut self) -> &mut Self {
+        self.filter |= sys::KeyFilter::SKIP_STRINGS;
+        self
+    }
+
+    /// Exclude symbol keys.
+    pub fn skip_symbols(&mut self) -> &mut Self {
+        self.filter |= sys::KeyFilter::SKIP_SYMBOLS;
+        self
+    }
+
+    /// Return integer-like keys (e.g., array indices) as numbers instead of
+    /// converting them to strings.
+    pub fn keep_numbers(&mut self) -> &mut Self {
+        self.conversion = sys::KeyConversion::KeepNumbers;
+        self
+    }
+
+    /// Collects the selected keys into a new array.
+    pub fn get<'b: 'a, C: Context<'b>>(&self, cx: &mut C) -> JsResult<'b, JsArray> {
+        let env = cx.env();
+
+        build(env, |out| unsafe {
+            sys::object::get_property_names(
+                out,
+                env.to_raw(),
+                self.object.to_local(),
+                self.mode,
+                self.filter,
+                self.conversion,
+            )
+        })
+    }
 }
 
 /// The trait of all object types.
@@ -152,6 +509,40 @@ pub trait Object: Value {
         v.downcast_or_throw(cx).map(Some)
     }
 
+    /// Gets an own property from a JavaScript object and attempts to downcast it,
+    /// or `None` if the object does not have the own property.
+    ///
+    /// Unlike [`Object::get_opt`], a property explicitly set to `undefined` is
+    /// distinguished from a missing property; it is downcast like any other value.
+    /// Properties inherited from the prototype chain are treated as missing.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn timeout(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let options = cx.argument::<JsObject>(0)?;
+    ///
+    ///     // `{}` uses the default, but `{ timeout: undefined }` disables the timeout
+    ///     let timeout = match options.get_own::<JsValue, _, _>(&mut cx, "timeout")? {
+    ///         None => 1000.0,
+    ///         Some(v) if v.is_a::<JsUndefined, _>(&mut cx) => f64::INFINITY,
+    ///         Some(v) => v.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx),
+    ///     };
+    ///
+    ///     Ok(cx.number(timeout))
+    /// }
+    /// ```
+    fn get_own<'a, V: Value, C: Context<'a>, K: PropertyKey + Copy>(
+        &self,
+        cx: &mut C,
+        key: K,
+    ) -> NeonResult<Option<Handle<'a, V>>> {
+        if !self.has_own_property(cx, key)? {
+            return Ok(None);
+        }
+
+        self.get(cx, key).map(Some)
+    }
+
     /// Gets a property from a JavaScript object as a [`JsValue`].
     ///
     /// If a [`getter`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/get)
@@ -178,6 +569,13 @@ pub trait Object: Value {
         self.get_value(cx, key)?.downcast_or_throw(cx)
     }
 
+    /// Gets the names of the object's own string-keyed properties, including
+    /// non-enumerable properties, equivalent to the JavaScript expression
+    /// [`Object.getOwnPropertyNames(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames).
+    ///
+    /// Symbol keys are not included. Integer keys are converted to strings.
+    ///
+    /// **See also:** [`Object::keys`]
     #[cfg(feature = "napi-6")]
     #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
     fn get_own_property_names<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsArray> {
@@ -188,7 +586,70 @@ pub trait Object: Value {
         })
     }
 
+    /// Gets the names of the object's own enumerable string-keyed properties,
+    /// equivalent to the JavaScript expression
+    /// [`Object.keys(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn count_keys(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let obj = cx.argument::<JsObject>(0)?;
+    ///     let keys = obj.keys(&mut cx)?;
+    ///     let len = keys.len(&mut cx);
+    ///
+    ///     Ok(cx.number(len))
+    /// }
+    /// ```
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    fn keys<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsArray> {
+        let env = cx.env();
+
+        build(cx.env(), |out| unsafe {
+            sys::object::get_own_enumerable_property_names(out, env.to_raw(), self.to_local())
+        })
+    }
+
+    /// Creates a builder for collecting the property keys of the object with control
+    /// over which keys are included, mirroring the options of the JavaScript
+    /// [`Object.getOwnPropertyNames`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getOwnPropertyNames)
+    /// family of functions.
+    ///
+    /// By default, the builder collects all of the object's own string and symbol keys,
+    /// equivalent to
+    /// [`Reflect.ownKeys(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Reflect/ownKeys).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Lists every string and symbol key, including inherited keys
+    /// fn manifest(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let obj = cx.argument::<JsObject>(0)?;
+    ///
+    ///     obj.property_names_with(&cx)
+    ///         .include_prototypes()
+    ///         .get(&mut cx)
+    /// }
+    /// ```
+    ///
+    /// **See also:** [`Object::keys`], [`Object::get_own_property_names`]
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    fn property_names_with<'a, C: Context<'a>>(&self, _cx: &C) -> PropertyNamesOptions<'a> {
+        PropertyNamesOptions {
+            object: JsValue::new_internal(self.to_local()),
+            mode: sys::KeyCollectionMode::OwnOnly,
+            filter: sys::KeyFilter::ALL_PROPERTIES,
+            conversion: sys::KeyConversion::NumbersToStrings,
+        }
+    }
+
+    /// Freezes the object, equivalent to the JavaScript expression
+    /// [`Object.freeze(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze).
+    ///
+    /// Properties of a frozen object cannot be added, removed or changed. Assignments
+    /// from JavaScript silently fail in sloppy mode and throw a `TypeError` in strict mode.
     #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
     fn freeze<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<&Self> {
         let env = cx.env().to_raw();
         let obj = self.to_local();
@@ -201,7 +662,13 @@ pub trait Object: Value {
         }
     }
 
+    /// Seals the object, equivalent to the JavaScript expression
+    /// [`Object.seal(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal).
+    ///
+    /// Properties cannot be added to or removed from a sealed object, but existing
+    /// writable properties may still be changed.
     #[cfg(feature = "napi-8")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-8")))]
     fn seal<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<&Self> {
         let env = cx.env().to_raw();
         let obj = self.to_local();
@@ -214,6 +681,164 @@ pub trait Object: Value {
         }
     }
 
+    /// Checks whether the object is frozen, equivalent to the JavaScript expression
+    /// [`Object.isFrozen(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen).
+    fn is_frozen<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<bool> {
+        object_predicate(cx, "isFrozen", self.to_local())
+    }
+
+    /// Checks whether the object is sealed, equivalent to the JavaScript expression
+    /// [`Object.isSealed(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed).
+    ///
+    /// Frozen objects are also sealed.
+    fn is_sealed<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<bool> {
+        object_predicate(cx, "isSealed", self.to_local())
+    }
+
+    /// Defines a data property with the given attributes, equivalent to the JavaScript
+    /// expression
+    /// [`Object.defineProperty(obj, key, { value, ...attributes })`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/defineProperty).
+    ///
+    /// Throws a `TypeError` if the property cannot be defined, e.g., if the object is
+    /// frozen or an existing property is not configurable.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::object::PropertyAttributes;
+    ///
+    /// fn create_config(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let config = cx.empty_object();
+    ///     let version = cx.number(1);
+    ///
+    ///     // A read-only, but visible, `version` property
+    ///     let attributes = PropertyAttributes {
+    ///         enumerable: true,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     config.define_data_property(&mut cx, "version", version, attributes)?;
+    ///
+    ///     Ok(config)
+    /// }
+    /// ```
+    fn define_data_property<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        key: &str,
+        value: Handle<V>,
+        attributes: PropertyAttributes,
+    ) -> NeonResult<()> {
+        let descriptor = cx.empty_object();
+        let writable = cx.boolean(attributes.writable);
+        let enumerable = cx.boolean(attributes.enumerable);
+        let configurable = cx.boolean(attributes.configurable);
+
+        descriptor.set(cx, "value", value)?;
+        descriptor.set(cx, "writable", writable)?;
+        descriptor.set(cx, "enumerable", enumerable)?;
+        descriptor.set(cx, "configurable", configurable)?;
+
+        define_property(cx, self.to_local(), key, descriptor)
+    }
+
+    /// Defines an accessor property that calls `getter` when read and `setter`, if
+    /// any, when assigned, equivalent to the JavaScript expression
+    /// `Object.defineProperty(obj, key, { get, set, enumerable: true, configurable: true })`.
+    ///
+    /// As in an object literal, the property is enumerable and configurable. Without a
+    /// `setter`, assignments are ignored in sloppy mode and throw a `TypeError` in
+    /// strict mode. Throws a `TypeError` if the property cannot be defined.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn checksum(_: &[u8]) -> f64 { todo!() }
+    /// // Computes the `checksum` of the file only when it is read
+    /// fn open_file(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let file = cx.empty_object();
+    ///     let getter = JsFunction::new(&mut cx, |mut cx| {
+    ///         let contents = std::fs::read("data.bin")
+    ///             .or_else(|err| cx.throw_error(err.to_string()))?;
+    ///
+    ///         Ok(cx.number(checksum(&contents)))
+    ///     })?;
+    ///
+    ///     file.define_accessor(&mut cx, "checksum", getter, None)?;
+    ///
+    ///     Ok(file)
+    /// }
+    /// ```
+    fn define_accessor<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        key: &str,
+        getter: Handle<JsFunction>,
+        setter: Option<Handle<JsFunction>>,
+    ) -> NeonResult<()> {
+        let descriptor = cx.empty_object();
+        let enabled = cx.boolean(true);
+
+        descriptor.set(cx, "get", getter)?;
+
+        if let Some(setter) = setter {
+            descriptor.set(cx, "set", setter)?;
+        }
+
+        descriptor.set(cx, "enumerable", enabled)?;
+        descriptor.set(cx, "configurable", enabled)?;
+
+        define_property(cx, self.to_local(), key, descriptor)
+    }
+
+    /// Returns the prototype of the object, equivalent to the JavaScript expression
+    /// [`Object.getPrototypeOf(obj)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/getPrototypeOf).
+    ///
+    /// The result is `null` for objects without a prototype. As in JavaScript, the
+    /// `getPrototypeOf` trap of a `Proxy` is called and may throw.
+    fn get_prototype<'a, C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsValue> {
+        let obj = JsValue::new_internal(self.to_local());
+        let undefined = cx.undefined();
+
+        OBJECT
+            .method(cx, "getPrototypeOf")?
+            .call(cx, undefined, [obj])
+    }
+
+    /// Sets the prototype of the object, equivalent to the JavaScript expression
+    /// [`Object.setPrototypeOf(obj, proto)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/setPrototypeOf).
+    ///
+    /// `proto` must be an object or `null`; a `null` prototype creates a dictionary
+    /// object that does not inherit properties such as `toString`. Throws a `TypeError`
+    /// if `proto` is neither, or if the object is not extensible.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Makes an existing object inherit the methods of `proto`
+    /// fn inherit(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let obj = cx.argument::<JsObject>(0)?;
+    ///     let proto = cx.argument::<JsObject>(1)?;
+    ///
+    ///     obj.set_prototype(&mut cx, proto)?;
+    ///
+    ///     Ok(obj)
+    /// }
+    /// ```
+    ///
+    /// **See also:** [`Context::object_with_prototype`]
+    fn set_prototype<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        proto: Handle<V>,
+    ) -> NeonResult<()> {
+        let obj = JsValue::new_internal(self.to_local());
+        let undefined = cx.undefined();
+
+        OBJECT
+            .method(cx, "setPrototypeOf")?
+            .call(cx, undefined, [obj, proto.upcast()])?;
+
+        Ok(())
+    }
+
     fn set<'a, C: Context<'a>, K: PropertyKey, W: Value>(
         &self,
         cx: &mut C,
@@ -230,10 +855,117 @@ pub trait Object: Value {
         }
     }
 
+    /// Checks whether the object or its prototype chain has a property, equivalent
+    /// to the JavaScript expression `key in obj`.
+    ///
+    /// **See also:** [`Object::has_own_property`]
+    fn has<'a, C: Context<'a>, K: PropertyKey>(&self, cx: &mut C, key: K) -> NeonResult<bool> {
+        let mut result = false;
+        unsafe {
+            if key.has_from(cx, &mut result, self.to_local()) {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Checks whether the object has an own property, ignoring its prototype chain,
+    /// equivalent to the JavaScript expression
+    /// [`Object.hasOwn(obj, key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn).
+    fn has_own_property<'a, C: Context<'a>, K: PropertyKey>(
+        &self,
+        cx: &mut C,
+        key: K,
+    ) -> NeonResult<bool> {
+        let mut result = false;
+        unsafe {
+            if key.has_own_from(cx, &mut result, self.to_local()) {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Deletes a property from the object, equivalent to the JavaScript expression
+    /// `delete obj[key]`.
+    ///
+    /// Returns `false` if the property could not be deleted, e.g., because it is
+    /// non-configurable. Deleting a property that does not exist returns `true`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn take(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let obj = cx.argument::<JsObject>(0)?;
+    ///     let key = cx.argument::<JsString>(1)?;
+    ///     let value = obj.get_value(&mut cx, key)?;
+    ///
+    ///     obj.delete(&mut cx, key)?;
+    ///
+    ///     Ok(value)
+    /// }
+    /// ```
+    fn delete<'a, C: Context<'a>, K: PropertyKey>(&self, cx: &mut C, key: K) -> NeonResult<bool> {
+        let mut result = false;
+        unsafe {
+            if key.delete_from(cx, &mut result, self.to_local()) {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
+    /// Copies the own enumerable string-keyed properties of `source` onto the object,
+    /// like the JavaScript expression
+    /// [`Object.assign(obj, source)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign).
+    ///
+    /// Properties are read with getters invoked and written with setters invoked, so
+    /// properties of `source` overwrite existing properties with the same key. Unlike
+    /// `Object.assign`, symbol-keyed properties are not copied.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Merges caller provided options over the defaults
+    /// fn options(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let overrides = cx.argument::<JsObject>(0)?;
+    ///     let options = cx.empty_object();
+    ///     let retries = cx.number(3);
+    ///
+    ///     options.set(&mut cx, "retries", retries)?;
+    ///     options.assign(&mut cx, overrides)?;
+    ///
+    ///     Ok(options)
+    /// }
+    /// ```
+    #[cfg(feature = "napi-6")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-6")))]
+    fn assign<'a, C: Context<'a>, S: Object>(
+        &self,
+        cx: &mut C,
+        source: Handle<S>,
+    ) -> NeonResult<()> {
+        let keys = source.keys(cx)?;
+
+        for i in 0..keys.len(cx) {
+            let key = keys.get_value(cx, i)?;
+            let value = source.get_value(cx, key)?;
+
+            self.set(cx, key, value)?;
+        }
+
+        Ok(())
+    }
+
     fn root<'a, C: Context<'a>>(&self, cx: &mut C) -> Root<Self> {
         Root::new(cx, self)
     }
 
+    /// Creates a [`CallOptions`] for calling the method named `method`, with the object
+    /// bound as `this`.
+    ///
+    /// **See also:** [`Object::call_method`]
     fn call_method_with<'a, C, K>(&self, cx: &mut C, method: K) -> NeonResult<CallOptions<'a>>
     where
         C: Context<'a>,
@@ -243,4 +975,99 @@ pub trait Object: Value {
         options.this(JsValue::new_internal(self.to_local()));
         Ok(options)
     }
+
+    /// Calls the method named `method` with the object bound as `this`, equivalent to
+    /// the JavaScript expression `obj[method](...args)`.
+    ///
+    /// Throws a `TypeError` naming the key if the property is not a function.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Calls `map.set(key, value)`
+    /// fn insert(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let map = cx.argument::<JsObject>(0)?;
+    ///     let key = cx.argument::<JsValue>(1)?;
+    ///     let value = cx.argument::<JsValue>(2)?;
+    ///
+    ///     map.call_method(&mut cx, "set", [key, value])
+    /// }
+    /// ```
+    fn call_method<'a, 'b, C, K, AS>(
+        &self,
+        cx: &mut C,
+        method: K,
+        args: AS,
+    ) -> JsResult<'a, JsValue>
+    where
+        C: Context<'a>,
+        K: PropertyKey + Copy,
+        AS: AsRef<[Handle<'b, JsValue>]>,
+    {
+        let f = self.get_value(cx, method)?;
+        let f = match f.downcast::<JsFunction, _>(cx) {
+            Ok(f) => f,
+            Err(_) => {
+                let method = describe_key(cx, method);
+
+                return cx.throw_type_error(format!("{method} is not a function"));
+            }
+        };
+        let this = JsValue::new_internal(self.to_local());
+
+        f.call(cx, this, args)
+    }
+}
+
+// Describes a property key in the error message of `Object::call_method`, e.g., `'name'`
+// for a string key. Since `PropertyKey` only exposes property operations, the key is
+// recovered by setting it on a temporary object and reading back its own keys.
+fn describe_key<'a, C: Context<'a>, K: PropertyKey>(cx: &mut C, key: K) -> String {
+    #[cfg(feature = "napi-6")]
+    unsafe {
+        let env = cx.env().to_raw();
+        let undefined = cx.undefined().to_local();
+        let mut obj: raw::Local = std::mem::zeroed();
+        let mut keys: raw::Local = std::mem::zeroed();
+        let mut local: raw::Local = std::mem::zeroed();
+        let mut ok = false;
+
+        sys::object::new(&mut obj, env);
+
+        if key.set_from(cx, &mut ok, obj, undefined)
+            && sys::object::get_property_names(
+                &mut keys,
+                env,
+                obj,
+                sys::KeyCollectionMode::OwnOnly,
+                sys::KeyFilter::ALL_PROPERTIES,
+                sys::KeyConversion::KeepNumbers,
+            )
+            && sys::object::get_index(&mut local, env, keys, 0)
+        {
+            return describe_key_value(cx, JsValue::new_internal(local));
+        }
+
+        // The key was already used to look up the method, so this is unexpected
+        sys::error::clear_exception(env);
+    }
+
+    #[cfg(not(feature = "napi-6"))]
+    let _ = (cx, key);
+
+    String::from("method")
+}
+
+#[cfg(feature = "napi-6")]
+fn describe_key_value<'a, C: Context<'a>>(cx: &mut C, key: Handle<JsValue>) -> String {
+    if let Ok(key) = key.downcast::<JsString, _>(cx) {
+        format!("'{}'", key.value(cx))
+    } else if let Ok(key) = key.downcast::<JsNumber, _>(cx) {
+        key.value(cx).to_string()
+    } else if let Ok(key) = key.downcast::<JsSymbol, _>(cx) {
+        let description = cx.try_catch(|cx| key.description(cx)).ok().flatten();
+
+        format!("Symbol({})", description.unwrap_or_default())
+    } else {
+        String::from("method")
+    }
 }
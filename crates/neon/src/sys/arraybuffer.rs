@@ -83,3 +83,24 @@ pub unsafe fn size(env: Env, buf: Local) -> usize {
 
     size
 }
+
+/// # Safety
+/// * Caller must ensure `env` and `buf` are valid
+#[cfg(feature = "napi-7")]
+pub unsafe fn detach(env: Env, buf: Local) -> napi::Status {
+    napi::detach_arraybuffer(env, buf)
+}
+
+/// # Safety
+/// * Caller must ensure `env` and `buf` are valid
+#[cfg(feature = "napi-7")]
+pub unsafe fn is_detached(env: Env, buf: Local) -> bool {
+    let mut result = false;
+
+    assert_eq!(
+        napi::is_detached_arraybuffer(env, buf, &mut result as *mut _),
+        napi::Status::Ok,
+    );
+
+    result
+}
@@ -8,25 +8,75 @@
 //! See: [Async operations in Node-API](https://nodejs.org/api/n-api.html#n_api_simple_asynchronous_operations)
 
 use std::{
+    cell::Cell,
     ffi::c_void,
     mem,
     panic::{catch_unwind, resume_unwind, AssertUnwindSafe},
-    ptr, thread,
+    ptr,
+    rc::Rc,
+    thread,
 };
 
 use super::{
     bindings as napi, debug_send_wrapper::DebugSendWrapper, no_panic::FailureBoundary, raw::Env,
 };
 
+#[cfg(feature = "napi-4")]
+use {
+    super::tsfn::ThreadsafeFunction,
+    std::{
+        collections::VecDeque,
+        sync::{Arc, Mutex},
+    },
+};
+
 const BOUNDARY: FailureBoundary = FailureBoundary {
     both: "A panic and exception occurred while executing a `neon::event::TaskBuilder` task",
     exception: "An exception occurred while executing a `neon::event::TaskBuilder` task",
     panic: "A panic occurred while executing a `neon::event::TaskBuilder` task",
 };
 
+#[cfg(feature = "napi-4")]
+const PROGRESS_BOUNDARY: FailureBoundary = FailureBoundary {
+    both: "A panic and exception occurred while reporting progress of a `neon::event::ProgressTaskBuilder` task",
+    exception: "An exception occurred while reporting progress of a `neon::event::ProgressTaskBuilder` task",
+    panic: "A panic occurred while reporting progress of a `neon::event::ProgressTaskBuilder` task",
+};
+
 type Execute<I, O> = fn(input: I) -> O;
 type Complete<O, D> = fn(env: Env, output: thread::Result<O>, data: D);
 
+/// Handle to scheduled work that may be used to cancel it before it executes
+///
+/// The handle may only be used on the JavaScript main thread that scheduled the work.
+pub struct AsyncWorkHandle(Rc<Cell<napi::AsyncWork>>);
+
+impl AsyncWorkHandle {
+    /// Attempts to cancel the work before it starts executing. Returns `true` if
+    /// the work was cancelled, in which case `complete` will not be called.
+    ///
+    /// # Safety
+    /// * `env` must be the `napi_env` that scheduled the work
+    pub unsafe fn cancel(&self, env: Env) -> bool {
+        let work = self.0.get();
+
+        // The work has already completed or been cancelled
+        if work.is_null() {
+            return false;
+        }
+
+        // Fails if the work is executing or has already executed
+        if napi::cancel_async_work(env, work) != napi::Status::Ok {
+            return false;
+        }
+
+        // `call_complete` will still be called with `Status::Cancelled` to free `Data`
+        self.0.set(ptr::null_mut());
+
+        true
+    }
+}
+
 /// Schedule work to execute on the libuv thread pool
 ///
 /// # Safety
@@ -39,11 +89,13 @@ pub unsafe fn schedule<I, O, D>(
     execute: Execute<I, O>,
     complete: Complete<O, D>,
     data: D,
-) where
+) -> AsyncWorkHandle
+where
     I: Send + 'static,
     O: Send + 'static,
     D: 'static,
 {
+    let handle = Rc::new(Cell::new(ptr::null_mut()));
     let mut data = Box::new(Data {
         state: State::Input(input),
         execute,
@@ -52,6 +104,7 @@ pub unsafe fn schedule<I, O, D>(
         // Work is initialized as a null pointer, but set by `create_async_work`
         // `data` must not be used until this value has been set.
         work: ptr::null_mut(),
+        handle: handle.clone(),
     });
 
     // Store a pointer to `work` before ownership is transferred to `Box::into_raw`
@@ -80,6 +133,10 @@ pub unsafe fn schedule<I, O, D>(
             assert_eq!(status, napi::Status::Ok);
         }
     }
+
+    handle.set(*work);
+
+    AsyncWorkHandle(handle)
 }
 
 /// A pointer to data is passed to the `execute` and `complete` callbacks
@@ -89,6 +146,8 @@ struct Data<I, O, D> {
     complete: Complete<O, D>,
     data: DebugSendWrapper<D>,
     work: napi::AsyncWork,
+    // Shared with `AsyncWorkHandle`; only accessed on the JavaScript main thread
+    handle: Rc<Cell<napi::AsyncWork>>,
 }
 
 /// State of the task that is transitioned by `execute` and `complete`
@@ -149,14 +208,22 @@ unsafe extern "C" fn call_complete<I, O, D>(env: Env, status: napi::Status, data
         complete,
         data,
         work,
+        handle,
         ..
     } = *Box::<Data<I, O, D>>::from_raw(data.cast());
 
+    // Prevent cancelling work that has been deleted
+    handle.set(ptr::null_mut());
     napi::delete_async_work(env, work);
 
     BOUNDARY.catch_failure(env, None, move |env| {
+        // Cancelled work never executed and does not have an output
+        if status == napi::Status::Cancelled {
+            return ptr::null_mut();
+        }
+
         // `unwrap` is okay because `call_complete` should be called exactly once
-        // if and only if `call_execute` has completed successfully
+        // after `call_execute` has completed successfully, unless cancelled
         let output = state.into_output().unwrap();
 
         // The event looped has stopped if we do not have an Env
@@ -171,12 +238,192 @@ unsafe extern "C" fn call_complete<I, O, D>(env: Env, status: napi::Status, data
             return ptr::null_mut();
         };
 
-        match status {
-            napi::Status::Ok => complete(env, output, data.take()),
-            napi::Status::Cancelled => {}
-            _ => assert_eq!(status, napi::Status::Ok),
-        }
+        assert_eq!(status, napi::Status::Ok);
+        complete(env, output, data.take());
 
         ptr::null_mut()
     });
 }
+
+#[cfg(feature = "napi-4")]
+type ExecuteWithProgress<I, P, O> = fn(input: I, progress: ProgressSender<P>) -> O;
+
+#[cfg(feature = "napi-4")]
+/// Sends progress events from the libuv thread pool to the JavaScript main thread
+///
+/// Events are queued and delivered in order. Events are never delivered after
+/// `complete` has been called; any events still queued when `execute` returns
+/// are flushed before `complete`.
+pub struct ProgressSender<P> {
+    progress: Arc<Progress<P>>,
+}
+
+#[cfg(feature = "napi-4")]
+impl<P> Clone for ProgressSender<P> {
+    fn clone(&self) -> Self {
+        Self {
+            progress: self.progress.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "napi-4")]
+impl<P: Send + 'static> ProgressSender<P> {
+    /// Queue a progress event. Returns `false` if the work has already completed
+    /// and the event was dropped.
+    pub fn send(&self, event: P) -> bool {
+        let is_empty = {
+            let mut pending = self.progress.pending.lock().unwrap();
+
+            if pending.is_closed {
+                return false;
+            }
+
+            pending.queue.push_back(event);
+            pending.queue.len() == 1
+        };
+
+        // Only wake the main thread if a flush is not already pending. A flush
+        // drains the queue until it is observed empty.
+        if is_empty {
+            let _ = self.progress.tsfn.call(self.progress.clone(), None);
+        }
+
+        true
+    }
+}
+
+#[cfg(feature = "napi-4")]
+struct Progress<P> {
+    pending: Mutex<Pending<P>>,
+    on_progress: fn(env: Env, event: P),
+    tsfn: ThreadsafeFunction<Arc<Progress<P>>>,
+}
+
+#[cfg(feature = "napi-4")]
+struct Pending<P> {
+    queue: VecDeque<P>,
+    is_closed: bool,
+}
+
+#[cfg(feature = "napi-4")]
+impl<P: Send + 'static> Progress<P> {
+    /// Deliver queued events on the JavaScript main thread until the queue is empty.
+    /// If `close` is set, the queue is closed under the same lock that observes it
+    /// empty so that no event can be queued after the final delivery.
+    fn flush(&self, env: Env, close: bool) {
+        loop {
+            // The lock must not be held while calling `on_progress` since it may
+            // send more events
+            let event = {
+                let mut pending = self.pending.lock().unwrap();
+                let event = pending.queue.pop_front();
+
+                if event.is_none() && close {
+                    pending.is_closed = true;
+                }
+
+                event
+            };
+
+            let event = match event {
+                Some(event) => event,
+                None => return,
+            };
+
+            // Each event is delivered within its own boundary so that an exception
+            // or panic is reported as an `unhandledRejection`, like a `Channel`
+            // callback, without preventing delivery of the remaining events
+            unsafe {
+                PROGRESS_BOUNDARY.catch_failure(env, None, |env| {
+                    if let Some(env) = env {
+                        (self.on_progress)(env, event);
+                    }
+
+                    ptr::null_mut()
+                });
+            }
+        }
+    }
+
+    /// Deliver remaining events and reject any further events
+    unsafe fn close(&self, env: Env) {
+        self.flush(env, true);
+
+        // Senders leaked past `execute` must not keep the event loop alive
+        self.tsfn.unref(env);
+    }
+
+    fn callback(env: Option<Env>, progress: Arc<Self>) {
+        // Events are never queued after the work is closed, so a wake-up that
+        // arrives after `complete` finds the queue empty
+        if let Some(env) = env {
+            progress.flush(env, false);
+        }
+    }
+}
+
+#[cfg(feature = "napi-4")]
+/// Schedule work to execute on the libuv thread pool, delivering progress events
+/// sent from `execute` to `on_progress` on the JavaScript main thread
+///
+/// All events sent before `execute` returns are delivered before `complete`.
+///
+/// # Safety
+/// * `env` must be a valid `napi_env` for the current thread
+/// * The `thread::Result::Err` must only be used for resuming unwind if
+///   `execute` is not unwind safe
+pub unsafe fn schedule_with_progress<I, P, O, D>(
+    env: Env,
+    input: I,
+    execute: ExecuteWithProgress<I, P, O>,
+    on_progress: fn(env: Env, event: P),
+    complete: Complete<O, D>,
+    data: D,
+) -> AsyncWorkHandle
+where
+    I: Send + 'static,
+    P: Send + 'static,
+    O: Send + 'static,
+    D: 'static,
+{
+    let progress = Arc::new(Progress {
+        pending: Mutex::new(Pending {
+            queue: VecDeque::new(),
+            is_closed: false,
+        }),
+        on_progress,
+        tsfn: ThreadsafeFunction::new(env, Progress::callback),
+    });
+
+    let sender = ProgressSender {
+        progress: progress.clone(),
+    };
+
+    schedule(
+        env,
+        (input, sender, execute),
+        call_execute_with_progress::<I, P, O>,
+        call_complete_with_progress::<P, O, D>,
+        (progress, complete, data),
+    )
+}
+
+#[cfg(feature = "napi-4")]
+fn call_execute_with_progress<I, P, O>(
+    (input, sender, execute): (I, ProgressSender<P>, ExecuteWithProgress<I, P, O>),
+) -> O {
+    execute(input, sender)
+}
+
+#[cfg(feature = "napi-4")]
+fn call_complete_with_progress<P, O, D>(
+    env: Env,
+    output: thread::Result<O>,
+    (progress, complete, data): (Arc<Progress<P>>, Complete<O, D>, D),
+) where
+    P: Send + 'static,
+{
+    unsafe { progress.close(env) };
+    complete(env, output, data);
+}
@@ -17,12 +17,20 @@ mod napi1 {
 
             fn create_double(env: Env, value: f64, result: *mut Value) -> Status;
 
+            fn create_symbol(env: Env, description: Value, result: *mut Value) -> Status;
+
             fn create_object(env: Env, result: *mut Value) -> Status;
 
             fn get_value_bool(env: Env, value: Value, result: *mut bool) -> Status;
 
             fn get_value_double(env: Env, value: Value, result: *mut f64) -> Status;
 
+            fn get_value_int32(env: Env, value: Value, result: *mut i32) -> Status;
+
+            fn get_value_uint32(env: Env, value: Value, result: *mut u32) -> Status;
+
+            fn get_value_int64(env: Env, value: Value, result: *mut i64) -> Status;
+
             fn create_array_with_length(env: Env, length: usize, result: *mut Value) -> Status;
 
             fn get_array_length(env: Env, value: Value, result: *mut u32) -> Status;
@@ -31,6 +39,10 @@ mod napi1 {
 
             fn coerce_to_string(env: Env, value: Value, result: *mut Value) -> Status;
 
+            fn coerce_to_number(env: Env, value: Value, result: *mut Value) -> Status;
+
+            fn coerce_to_bool(env: Env, value: Value, result: *mut Value) -> Status;
+
             fn throw(env: Env, error: Value) -> Status;
 
             fn create_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -53,6 +65,7 @@ mod napi1 {
 
             fn is_arraybuffer(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_typedarray(env: Env, value: Value, result: *mut bool) -> Status;
+            fn is_dataview(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_buffer(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_error(env: Env, value: Value, result: *mut bool) -> Status;
             fn is_array(env: Env, value: Value, result: *mut bool) -> Status;
@@ -77,6 +90,14 @@ mod napi1 {
                 result: *mut usize,
             ) -> Status;
 
+            fn get_value_string_latin1(
+                env: Env,
+                value: Value,
+                buf: *mut c_char,
+                bufsize: usize,
+                result: *mut usize,
+            ) -> Status;
+
             fn create_type_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
 
             fn create_range_error(env: Env, code: Value, msg: Value, result: *mut Value) -> Status;
@@ -88,6 +109,20 @@ mod napi1 {
                 result: *mut Value,
             ) -> Status;
 
+            fn create_string_utf16(
+                env: Env,
+                str: *const u16,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn create_string_latin1(
+                env: Env,
+                str: *const c_char,
+                length: usize,
+                result: *mut Value,
+            ) -> Status;
+
             fn create_arraybuffer(
                 env: Env,
                 byte_length: usize,
@@ -121,6 +156,23 @@ mod napi1 {
                 offset: *mut usize,
             ) -> Status;
 
+            fn create_dataview(
+                env: Env,
+                length: usize,
+                arraybuffer: Value,
+                byte_offset: usize,
+                result: *mut Value,
+            ) -> Status;
+
+            fn get_dataview_info(
+                env: Env,
+                dataview: Value,
+                byte_length: *mut usize,
+                data: *mut *mut c_void,
+                arraybuffer: *mut Value,
+                byte_offset: *mut usize,
+            ) -> Status;
+
             fn create_buffer(
                 env: Env,
                 length: usize,
@@ -186,6 +238,16 @@ mod napi1 {
 
             fn get_element(env: Env, object: Value, index: u32, result: *mut Value) -> Status;
 
+            fn has_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn has_own_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn delete_property(env: Env, object: Value, key: Value, result: *mut bool) -> Status;
+
+            fn has_element(env: Env, object: Value, index: u32, result: *mut bool) -> Status;
+
+            fn delete_element(env: Env, object: Value, index: u32, result: *mut bool) -> Status;
+
             fn escape_handle(
                 env: Env,
                 scope: EscapableHandleScope,
@@ -210,6 +272,9 @@ mod napi1 {
 
             fn strict_equals(env: Env, lhs: Value, rhs: Value, result: *mut bool) -> Status;
 
+            fn instanceof(env: Env, object: Value, constructor: Value, result: *mut bool)
+                -> Status;
+
             #[cfg(any(feature = "sys", feature = "external-buffers"))]
             fn create_external_arraybuffer(
                 env: Env,
@@ -232,6 +297,12 @@ mod napi1 {
 
             fn run_script(env: Env, script: Value, result: *mut Value) -> Status;
 
+            fn adjust_external_memory(
+                env: Env,
+                change_in_bytes: i64,
+                adjusted_value: *mut i64,
+            ) -> Status;
+
             fn create_async_work(
                 env: Env,
                 async_resource: Value,
@@ -244,6 +315,7 @@ mod napi1 {
 
             fn delete_async_work(env: Env, work: AsyncWork) -> Status;
             fn queue_async_work(env: Env, work: AsyncWork) -> Status;
+            fn cancel_async_work(env: Env, work: AsyncWork) -> Status;
             fn create_promise(env: Env, deferred: *mut Deferred, promise: *mut Value) -> Status;
             fn resolve_deferred(env: Env, deferred: Deferred, resolution: Value) -> Status;
             fn reject_deferred(env: Env, deferred: Deferred, rejection: Value) -> Status;
@@ -387,6 +459,19 @@ mod napi6 {
     );
 }
 
+#[cfg(feature = "napi-7")]
+mod napi7 {
+    use super::super::types::*;
+
+    generate!(
+        #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+        extern "C" {
+            fn detach_arraybuffer(env: Env, arraybuffer: Value) -> Status;
+            fn is_detached_arraybuffer(env: Env, value: Value, result: *mut bool) -> Status;
+        }
+    );
+}
+
 #[cfg(feature = "napi-8")]
 mod napi8 {
     use super::super::types::*;
@@ -414,6 +499,8 @@ pub use napi4::*;
 pub use napi5::*;
 #[cfg(feature = "napi-6")]
 pub use napi6::*;
+#[cfg(feature = "napi-7")]
+pub use napi7::*;
 #[cfg(feature = "napi-8")]
 pub use napi8::*;
 
@@ -465,6 +552,9 @@ pub(crate) unsafe fn load(env: Env) -> Result<(), libloading::Error> {
     #[cfg(feature = "napi-6")]
     napi6::load(&host);
 
+    #[cfg(feature = "napi-7")]
+    napi7::load(&host);
+
     #[cfg(feature = "napi-8")]
     napi8::load(&host);
 
@@ -218,6 +218,7 @@ pub struct KeyFilter(pub ::std::os::raw::c_uint);
 impl KeyFilter {
     pub const ALL_PROPERTIES: KeyFilter = KeyFilter(0);
     pub const WRITABLE: KeyFilter = KeyFilter(1);
+    pub const ENUMERABLE: KeyFilter = KeyFilter(2);
     pub const CONFIGURABLE: KeyFilter = KeyFilter(4);
     pub const SKIP_STRINGS: KeyFilter = KeyFilter(8);
     pub const SKIP_SYMBOLS: KeyFilter = KeyFilter(16);
@@ -20,6 +20,7 @@ const ARGV_SIZE: usize = 4;
 /// List of JavaScript arguments to a function
 // `Arguments` is intended to be a small abstraction to hide the usage of
 // `SmallVec` allowing changes to `ARGV_SIZE` in a single location
+#[derive(Clone)]
 pub struct Arguments(SmallVec<[Local; ARGV_SIZE]>);
 
 impl Arguments {
@@ -8,3 +8,15 @@ pub unsafe fn to_string(out: &mut Local, env: Env, value: Local) -> bool {
 
     status == napi::Status::Ok
 }
+
+pub unsafe fn to_number(out: &mut Local, env: Env, value: Local) -> bool {
+    let status = napi::coerce_to_number(env, value, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+pub unsafe fn to_bool(out: &mut Local, env: Env, value: Local) -> bool {
+    let status = napi::coerce_to_bool(env, value, out as *mut _);
+
+    status == napi::Status::Ok
+}
@@ -11,3 +11,22 @@ pub unsafe fn strict_equals(env: Env, lhs: Local, rhs: Local) -> bool {
     );
     result
 }
+
+/// Mutates `out` to indicate whether `object` is an instance of `constructor`, equivalent to the
+/// JavaScript `instanceof` operator. Returns `false` if the check threw.
+pub unsafe fn instance_of(out: &mut bool, env: Env, object: Local, constructor: Local) -> bool {
+    let status = napi::instanceof(env, object, constructor, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Adjusts the amount of externally allocated memory V8 attributes to objects kept alive
+/// by JavaScript, returning the new total.
+pub unsafe fn adjust_external_memory(env: Env, change_in_bytes: i64) -> i64 {
+    let mut result = 0;
+    assert_eq!(
+        napi::adjust_external_memory(env, change_in_bytes, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
@@ -77,6 +77,7 @@ pub(crate) mod async_work;
 pub(crate) mod buffer;
 pub(crate) mod call;
 pub(crate) mod convert;
+pub(crate) mod dataview;
 pub(crate) mod error;
 pub(crate) mod external;
 pub(crate) mod fun;
@@ -29,14 +29,52 @@ pub unsafe fn seal(env: Env, obj: Local) -> napi::Status {
 /// Mutates the `out` argument to refer to a `napi_value` containing the own property names of the
 /// `object` as a JavaScript Array.
 pub unsafe fn get_own_property_names(out: &mut Local, env: Env, object: Local) -> bool {
-    let mut property_names = MaybeUninit::uninit();
+    get_own_string_keys(out, env, object, napi::KeyFilter::ALL_PROPERTIES)
+}
 
-    if napi::get_all_property_names(
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the own enumerable property
+/// names of the `object` as a JavaScript Array.
+pub unsafe fn get_own_enumerable_property_names(out: &mut Local, env: Env, object: Local) -> bool {
+    get_own_string_keys(out, env, object, napi::KeyFilter::ENUMERABLE)
+}
+
+#[cfg(feature = "napi-6")]
+unsafe fn get_own_string_keys(
+    out: &mut Local,
+    env: Env,
+    object: Local,
+    filter: napi::KeyFilter,
+) -> bool {
+    get_property_names(
+        out,
         env,
         object,
         napi::KeyCollectionMode::OwnOnly,
-        napi::KeyFilter::ALL_PROPERTIES | napi::KeyFilter::SKIP_SYMBOLS,
+        filter | napi::KeyFilter::SKIP_SYMBOLS,
         napi::KeyConversion::NumbersToStrings,
+    )
+}
+
+#[cfg(feature = "napi-6")]
+/// Mutates the `out` argument to refer to a `napi_value` containing the property keys of the
+/// `object` selected by `mode` and `filter` as a JavaScript Array.
+pub unsafe fn get_property_names(
+    out: &mut Local,
+    env: Env,
+    object: Local,
+    mode: napi::KeyCollectionMode,
+    filter: napi::KeyFilter,
+    conversion: napi::KeyConversion,
+) -> bool {
+    let mut property_names = MaybeUninit::uninit();
+
+    if napi::get_all_property_names(
+        env,
+        object,
+        mode,
+        filter,
+        conversion,
         property_names.as_mut_ptr(),
     ) != napi::Status::Ok
     {
@@ -148,3 +186,43 @@ pub unsafe fn set(out: &mut bool, env: Env, object: Local, key: Local, val: Loca
 
     *out
 }
+
+/// Mutates `out` to indicate whether `object` or its prototype chain has a property named by the
+/// `key` value. Returns `false` if the check failed.
+pub unsafe fn has(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::has_property(env, object, key, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Mutates `out` to indicate whether `object` has an own property named by the `key` value. The
+/// `key` must be a string or symbol. Returns `false` if the check failed.
+pub unsafe fn has_own(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::has_own_property(env, object, key, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Mutates `out` to indicate whether `object` or its prototype chain has a property at `index`.
+/// Returns `false` if the check failed.
+pub unsafe fn has_index(out: &mut bool, env: Env, object: Local, index: u32) -> bool {
+    let status = napi::has_element(env, object, index, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Deletes the property of `object` named by the `key` value, mutating `out` to indicate whether
+/// the property was deleted. Returns `false` if the deletion threw.
+pub unsafe fn delete(out: &mut bool, env: Env, object: Local, key: Local) -> bool {
+    let status = napi::delete_property(env, object, key, out as *mut _);
+
+    status == napi::Status::Ok
+}
+
+/// Deletes the property of `object` at `index`, mutating `out` to indicate whether the property
+/// was deleted. Returns `false` if the deletion threw.
+pub unsafe fn delete_index(out: &mut bool, env: Env, object: Local, index: u32) -> bool {
+    let status = napi::delete_element(env, object, index, out as *mut _);
+
+    status == napi::Status::Ok
+}
@@ -45,3 +45,45 @@ pub unsafe fn number_value(env: Env, p: Local) -> f64 {
     );
     value
 }
+
+/// Gets the value of a `Local` containing a JavaScript number, converted with the
+/// engine's `ToInt32`. Panics if the given `Local` is not a number.
+pub unsafe fn number_value_i32(env: Env, p: Local) -> i32 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_int32(env, p, &mut value as *mut i32),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Gets the value of a `Local` containing a JavaScript number, converted with the
+/// engine's `ToUint32`. Panics if the given `Local` is not a number.
+pub unsafe fn number_value_u32(env: Env, p: Local) -> u32 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_uint32(env, p, &mut value as *mut u32),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Gets the value of a `Local` containing a JavaScript number, truncated to an `i64`.
+/// Panics if the given `Local` is not a number.
+pub unsafe fn number_value_i64(env: Env, p: Local) -> i64 {
+    let mut value = 0;
+    assert_eq!(
+        napi::get_value_int64(env, p, &mut value as *mut i64),
+        napi::Status::Ok
+    );
+    value
+}
+
+/// Mutates the `out` argument provided to refer to a newly created `Local` containing a
+/// JavaScript symbol. The `description` may be null or must be a JavaScript string.
+pub unsafe fn symbol(out: &mut Local, env: Env, description: Local) {
+    assert_eq!(
+        napi::create_symbol(env, description, out as *mut Local),
+        napi::Status::Ok
+    );
+}
@@ -6,10 +6,16 @@ use super::{
 };
 
 pub unsafe fn new(env: Env, value: Local) -> napi::Ref {
+    with_count(env, value, 1)
+}
+
+/// Create a reference with an explicit initial reference count. A count of `0`
+/// creates a weak reference.
+pub unsafe fn with_count(env: Env, value: Local, count: u32) -> napi::Ref {
     let mut result = MaybeUninit::uninit();
 
     assert_eq!(
-        napi::create_reference(env, value, 1, result.as_mut_ptr()),
+        napi::create_reference(env, value, count, result.as_mut_ptr()),
         napi::Status::Ok,
     );
 
@@ -32,6 +38,16 @@ pub unsafe fn reference(env: Env, value: napi::Ref) -> usize {
 /// # Safety
 /// Must only be used from the same module context that created the reference
 pub unsafe fn unreference(env: Env, value: napi::Ref) {
+    if unref(env, value) == 0 {
+        delete(env, value);
+    }
+}
+
+/// Decrement the reference count without deleting the reference, returning the new count
+///
+/// # Safety
+/// Must only be used from the same module context that created the reference
+pub unsafe fn unref(env: Env, value: napi::Ref) -> usize {
     let mut result = MaybeUninit::uninit();
 
     assert_eq!(
@@ -39,11 +55,17 @@ pub unsafe fn unreference(env: Env, value: napi::Ref) {
         napi::Status::Ok,
     );
 
-    if result.assume_init() == 0 {
-        assert_eq!(napi::delete_reference(env, value), napi::Status::Ok);
-    }
+    result.assume_init() as usize
+}
+
+/// # Safety
+/// Must only be used from the same module context that created the reference
+pub unsafe fn delete(env: Env, value: napi::Ref) {
+    assert_eq!(napi::delete_reference(env, value), napi::Status::Ok);
 }
 
+/// Returns a null `Local` if the reference is weak and the value has been collected
+///
 /// # Safety
 /// Must only be used from the same module context that created the reference
 pub unsafe fn get(env: Env, value: napi::Ref) -> Local {
@@ -56,13 +56,21 @@ impl EscapableHandleScope {
         }
     }
 
+    /// Promotes `value` to the parent scope. Node-API only allows a single handle
+    /// to escape from each scope.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a handle has already escaped from this scope.
     pub(crate) unsafe fn escape(&self, value: napi::Value) -> napi::Value {
         let mut escapee = MaybeUninit::uninit();
+        let status = napi::escape_handle(self.env, self.scope, value, escapee.as_mut_ptr());
 
-        assert_eq!(
-            napi::escape_handle(self.env, self.scope, value, escapee.as_mut_ptr()),
-            napi::Status::Ok,
-        );
+        if status == napi::Status::EscapeCalledTwice {
+            panic!("Attempted to escape more than one handle from an `EscapableHandleScope`");
+        }
+
+        assert_eq!(status, napi::Status::Ok);
 
         escapee.assume_init()
     }
@@ -11,6 +11,18 @@ pub unsafe fn new(out: &mut Local, env: Env, data: *const u8, len: i32) -> bool
     status == napi::Status::Ok
 }
 
+pub unsafe fn new_utf16(out: &mut Local, env: Env, data: *const u16, len: usize) -> bool {
+    let status = napi::create_string_utf16(env, data, len, out);
+
+    status == napi::Status::Ok
+}
+
+pub unsafe fn new_latin1(out: &mut Local, env: Env, data: *const u8, len: usize) -> bool {
+    let status = napi::create_string_latin1(env, data as *const _, len, out);
+
+    status == napi::Status::Ok
+}
+
 pub unsafe fn utf8_len(env: Env, value: Local) -> usize {
     let mut len = MaybeUninit::uninit();
     let status = napi::get_value_string_utf8(env, value, ptr::null_mut(), 0, len.as_mut_ptr());
@@ -47,6 +59,15 @@ pub unsafe fn data_utf16(env: Env, out: *mut u16, len: usize, value: Local) -> u
     read.assume_init()
 }
 
+pub unsafe fn data_latin1(env: Env, out: *mut u8, len: usize, value: Local) -> usize {
+    let mut read = MaybeUninit::uninit();
+    let status = napi::get_value_string_latin1(env, value, out as *mut _, len, read.as_mut_ptr());
+
+    assert_eq!(status, napi::Status::Ok);
+
+    read.assume_init()
+}
+
 pub unsafe fn run_script(out: &mut Local, env: Env, value: Local) -> bool {
     let status = napi::run_script(env, value, out as *mut _);
 
@@ -3,14 +3,19 @@ use super::{
     raw::{Env, Local},
 };
 
-/// Return true if an `napi_value` `val` has the expected value type.
-unsafe fn is_type(env: Env, val: Local, expect: napi::ValueType) -> bool {
+/// Return the value type of an `napi_value` `val`.
+pub unsafe fn type_of(env: Env, val: Local) -> napi::ValueType {
     let mut actual = napi::ValueType::Undefined;
     assert_eq!(
         napi::typeof_value(env, val, &mut actual as *mut _),
         napi::Status::Ok
     );
-    actual == expect
+    actual
+}
+
+/// Return true if an `napi_value` `val` has the expected value type.
+unsafe fn is_type(env: Env, val: Local, expect: napi::ValueType) -> bool {
+    type_of(env, val) == expect
 }
 
 pub unsafe fn is_undefined(env: Env, val: Local) -> bool {
@@ -36,6 +41,11 @@ pub unsafe fn is_string(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::String)
 }
 
+/// Is `val` a JavaScript symbol?
+pub unsafe fn is_symbol(env: Env, val: Local) -> bool {
+    is_type(env, val, napi::ValueType::Symbol)
+}
+
 pub unsafe fn is_object(env: Env, val: Local) -> bool {
     is_type(env, val, napi::ValueType::Object)
 }
@@ -92,6 +102,16 @@ pub unsafe fn is_typedarray(env: Env, val: Local) -> bool {
     result
 }
 
+/// Is `val` a DataView instance?
+pub unsafe fn is_dataview(env: Env, val: Local) -> bool {
+    let mut result = false;
+    assert_eq!(
+        napi::is_dataview(env, val, &mut result as *mut _),
+        napi::Status::Ok
+    );
+    result
+}
+
 #[cfg(feature = "napi-5")]
 pub unsafe fn is_date(env: Env, val: Local) -> bool {
     let mut result = false;
@@ -125,6 +125,12 @@ impl<T: Send + 'static> ThreadsafeFunction<T> {
         }
     }
 
+    /// Returns `true` if the threadsafe function has been finalized or has
+    /// observed a closing status and will reject further calls
+    pub fn is_finalized(&self) -> bool {
+        *self.is_finalized.lock().unwrap()
+    }
+
     /// References a threadsafe function to prevent exiting the event loop until it has been dropped. (Default)
     /// Safety: `Env` must be valid for the current thread
     pub unsafe fn reference(&self, env: Env) {
@@ -89,6 +89,41 @@
 //!   applications, you'll want to store static data in thread-local data in case the
 //!   addon ends up instantiated by multiple threads in some future application.
 //!
+//! ### Replacing Global Mutable State
+//!
+//! Module-global state, such as a configuration or a connection pool, is often stored
+//! in a `static` behind a [`Mutex`](std::sync::Mutex). Such a `static` is shared by
+//! every instance of the addon, so a worker thread would observe, and contend for, the
+//! state of the main thread. Wrapping the state in a `LocalKey` instead gives each
+//! instance its own lazily initialized value:
+//!
+//! ```
+//! # use neon::prelude::*;
+//! # use neon::thread::LocalKey;
+//! use std::sync::Mutex;
+//!
+//! #[derive(Default)]
+//! struct Config {
+//!     verbose: bool,
+//! }
+//!
+//! // Instead of a single `Mutex<Config>` shared by all instances
+//! static CONFIG: LocalKey<Mutex<Config>> = LocalKey::new();
+//!
+//! fn set_verbose(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+//!     let verbose = cx.argument::<JsBoolean>(0)?.value(&mut cx);
+//!
+//!     CONFIG.get_or_init_default(&mut cx).lock().unwrap().verbose = verbose;
+//!
+//!     Ok(cx.undefined())
+//! }
+//! ```
+//!
+//! Since the value is only accessed from the JavaScript thread of its instance, the
+//! `Mutex` is never contended; it only provides the interior mutability and `Sync`
+//! bound required by a `static`. State that is also used by other Rust threads can be
+//! stored as an [`Arc`](std::sync::Arc) and cloned before moving it to the thread.
+//!
 //! ### Why Not Use Standard TLS?
 //!
 //! Since the JavaScript engine may not tie JavaScript threads 1:1 to system threads,
@@ -222,6 +222,8 @@ impl JsBigInt {
     /// Reads an `i64` from a `BigInt`.
     ///
     /// Fails on overflow and underflow.
+    /// The truncated value is available from the error with
+    /// [`RangeError::into_inner`].
     ///
     /// # Example
     ///
@@ -250,6 +252,8 @@ impl JsBigInt {
     /// Reads a `u64` from a `BigInt`.
     ///
     /// Fails on overflow or a negative sign.
+    /// The truncated value is available from the error with
+    /// [`RangeError::into_inner`].
     pub fn to_u64<'cx, C>(&self, cx: &mut C) -> Result<u64, RangeError<u64>>
     where
         C: Context<'cx>,
@@ -1,12 +1,18 @@
 use std::{
     any::{self, Any},
+    cell::{Ref, RefCell, RefMut},
+    error::Error,
+    fmt,
     ops::Deref,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::RwLock,
 };
 
 use crate::{
     context::{internal::Env, Context, FinalizeContext},
     handle::{internal::TransparentNoCopyWrapper, Handle},
     object::Object,
+    result::NeonResult,
     sys::{external, raw},
     types::{boxed::private::JsBoxInner, private::ValueInternal, Value},
 };
@@ -64,6 +70,28 @@ mod private {
 /// # }
 /// ```
 ///
+/// ## Downcasting
+///
+/// A `JsBox<T>` may be recovered from any [`JsValue`](crate::types::JsValue), e.g., one
+/// read from an object property, with [`Handle::downcast`](crate::handle::Handle::downcast).
+/// The downcast checks the type of the boxed value at runtime and fails with a
+/// [`DowncastError`](crate::handle::DowncastError) if the value is not a `JsBox`, or
+/// is a `JsBox` containing a type other than `T`. With the `napi-8` feature, boxes
+/// created by other native modules are also rejected.
+///
+/// ```rust
+/// # use neon::prelude::*;
+/// # struct Plugin;
+/// # impl Finalize for Plugin {}
+/// fn get_plugin<'cx>(
+///     cx: &mut FunctionContext<'cx>,
+///     registry: Handle<JsObject>,
+///     name: &str,
+/// ) -> JsResult<'cx, JsBox<Plugin>> {
+///     registry.get_value(cx, name)?.downcast_or_throw(cx)
+/// }
+/// ```
+///
 /// ## Examples
 ///
 /// Passing some immutable data between Rust and JavaScript.
@@ -238,13 +266,22 @@ impl<T: Finalize + 'static> JsBox<T> {
     {
         // This function will execute immediately before the `JsBox` is garbage collected.
         // It unwraps the `napi_external`, downcasts the `BoxAny` and moves the type
-        // out of the `Box`. Lastly, it calls the trait method `Finalize::fianlize` of the
-        // contained value `T`.
+        // out of the `Box`. Lastly, it calls the trait method `Finalize::try_finalize` of
+        // the contained value `T`. Errors and panics are reported to the finalize error
+        // hook since unwinding across the FFI boundary would abort the process.
         fn finalizer<U: Finalize + 'static>(env: raw::Env, data: BoxAny) {
             let data = *data.downcast::<U>().unwrap();
             let env = unsafe { std::mem::transmute(env) };
 
-            FinalizeContext::with(env, move |mut cx| data.finalize(&mut cx));
+            let result = catch_unwind(AssertUnwindSafe(move || {
+                FinalizeContext::with(env, move |mut cx| data.try_finalize(&mut cx))
+            }));
+
+            match result {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => FinalizeError::report(FinalizeError::Error(err)),
+                Err(panic) => FinalizeError::report(FinalizeError::Panic(panic)),
+            }
         }
 
         let v = Box::new(value) as BoxAny;
@@ -256,6 +293,49 @@ impl<T: Finalize + 'static> JsBox<T> {
     }
 }
 
+impl<T: 'static> JsBox<RefCell<T>> {
+    /// Immutably borrows the contents of the `RefCell`, throwing an `Error` instead of
+    /// panicking if they are currently mutably borrowed.
+    ///
+    /// A `JsBox` may be accessed re-entrantly, e.g., when a native method calls a
+    /// JavaScript function that calls back into the same method. Converting the borrow
+    /// failure into an exception allows JavaScript to catch it.
+    pub fn borrow_or_throw<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Ref<'_, T>> {
+        match self.try_borrow() {
+            Ok(v) => Ok(v),
+            Err(err) => cx.throw_error(err.to_string()),
+        }
+    }
+
+    /// Mutably borrows the contents of the `RefCell`, throwing an `Error` instead of
+    /// panicking if they are currently borrowed.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::cell::RefCell;
+    /// type BoxedLog = JsBox<RefCell<Vec<String>>>;
+    ///
+    /// // Calls a callback for each entry, appending the results to the log. Throws
+    /// // if the callback re-entrantly calls `append` on the same log.
+    /// fn append(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let log = cx.argument::<BoxedLog>(0)?;
+    ///     let f = cx.argument::<JsFunction>(1)?;
+    ///     let mut entries = log.borrow_mut_or_throw(&mut cx)?;
+    ///     let entry = f.call_with(&cx).apply::<JsString, _>(&mut cx)?;
+    ///
+    ///     entries.push(entry.value(&mut cx));
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn borrow_mut_or_throw<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<RefMut<'_, T>> {
+        match self.try_borrow_mut() {
+            Ok(v) => Ok(v),
+            Err(err) => cx.throw_error(err.to_string()),
+        }
+    }
+}
+
 impl<T: 'static> Deref for JsBox<T> {
     type Target = T;
 
@@ -273,6 +353,23 @@ impl<T: 'static> Deref for JsBox<T> {
 ///
 /// Values contained by a `JsBox` must implement `Finalize`.
 ///
+/// ## Ordering
+///
+/// The order in which the garbage collector finalizes separate `JsBox` values is
+/// unspecified, even when one holds a [`Root`](crate::handle::Root) to another.
+/// Values nested within a single `JsBox` are finalized by the `finalize` method of
+/// the containing value; the provided implementations finalize tuples in field order
+/// and collections such as `Vec` in iteration order. Their `try_finalize` methods
+/// forward to `try_finalize` of the nested values, finalizing every value and
+/// returning the first error.
+///
+/// ## Errors and panics
+///
+/// A `JsBox` finalizes its value with [`Finalize::try_finalize`], which calls
+/// `finalize` unless overridden. Errors returned from `try_finalize` and panics
+/// are caught and passed to the hook registered with [`FinalizeError::set_hook`]
+/// instead of aborting the process.
+///
 /// ## Examples
 ///
 /// `Finalize` provides a default implementation that does not perform any finalization.
@@ -310,8 +407,98 @@ impl<T: 'static> Deref for JsBox<T> {
 /// ```
 pub trait Finalize: Sized {
     fn finalize<'a, C: Context<'a>>(self, _: &mut C) {}
+
+    /// Fallible variant of [`Finalize::finalize`] used when a `JsBox` is garbage
+    /// collected. Errors are reported to the hook registered with
+    /// [`FinalizeError::set_hook`].
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::{error::Error, fs::File};
+    /// struct Log(File);
+    ///
+    /// impl Finalize for Log {
+    ///     fn try_finalize<'a, C: Context<'a>>(
+    ///         self,
+    ///         _cx: &mut C,
+    ///     ) -> Result<(), Box<dyn Error + Send + Sync>> {
+    ///         self.0.sync_all()?;
+    ///         Ok(())
+    ///     }
+    /// }
+    /// ```
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.finalize(cx);
+        Ok(())
+    }
+}
+
+type FinalizeErrorHook = fn(FinalizeError);
+
+static FINALIZE_ERROR_HOOK: RwLock<Option<FinalizeErrorHook>> = RwLock::new(None);
+
+/// An error or panic that occurred while finalizing the value in a [`JsBox`].
+#[derive(Debug)]
+pub enum FinalizeError {
+    /// An error returned from [`Finalize::try_finalize`]
+    Error(Box<dyn Error + Send + Sync>),
+    /// A panic caught while finalizing
+    Panic(Box<dyn Any + Send>),
+}
+
+impl FinalizeError {
+    /// Registers a process-wide hook that is called on the main JavaScript thread
+    /// with each error or panic that occurs while finalizing a [`JsBox`], replacing
+    /// any previous hook.
+    ///
+    /// By default, errors are printed to stderr. The hook must not panic.
+    ///
+    /// ```
+    /// # use neon::types::FinalizeError;
+    /// FinalizeError::set_hook(|err| eprintln!("[my-module] {err}"));
+    /// ```
+    pub fn set_hook(hook: fn(FinalizeError)) {
+        *FINALIZE_ERROR_HOOK
+            .write()
+            .unwrap_or_else(|err| err.into_inner()) = Some(hook);
+    }
+
+    fn report(self) {
+        let hook = *FINALIZE_ERROR_HOOK
+            .read()
+            .unwrap_or_else(|err| err.into_inner());
+
+        match hook {
+            Some(hook) => hook(self),
+            None => eprintln!("{self}"),
+        }
+    }
+}
+
+impl fmt::Display for FinalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error(err) => write!(f, "Error while finalizing a `neon::types::JsBox`: {err}"),
+            Self::Panic(panic) => {
+                let msg = if let Some(msg) = panic.downcast_ref::<&str>() {
+                    msg
+                } else if let Some(msg) = panic.downcast_ref::<String>() {
+                    msg.as_str()
+                } else {
+                    "Box<dyn Any>"
+                };
+
+                write!(f, "Panic while finalizing a `neon::types::JsBox`: {msg}")
+            }
+        }
+    }
 }
 
+impl Error for FinalizeError {}
+
 // Primitives
 
 impl Finalize for bool {}
@@ -358,6 +545,17 @@ macro_rules! finalize_tuple_impls {
                 let ($($name,)+) = self;
                 ($($name.finalize(cx),)+);
             }
+
+            fn try_finalize<'a, C: Context<'a>>(
+                self,
+                cx: &mut C,
+            ) -> Result<(), Box<dyn Error + Send + Sync>> {
+                #![allow(non_snake_case)]
+                let ($($name,)+) = self;
+                let result = Ok(());
+                $(let result = result.and($name.try_finalize(cx));)+
+                result
+            }
         }
     };
 }
@@ -380,6 +578,24 @@ impl<T: Finalize> Finalize for Vec<T> {
             item.finalize(cx);
         }
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut result = Ok(());
+
+        // Every item is finalized, even after one fails
+        for item in self {
+            let item = item.try_finalize(cx);
+
+            if result.is_ok() {
+                result = item;
+            }
+        }
+
+        result
+    }
 }
 
 // Smart pointers and other wrappers
@@ -388,6 +604,13 @@ impl<T: Finalize> Finalize for std::boxed::Box<T> {
     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
         (*self).finalize(cx);
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        (*self).try_finalize(cx)
+    }
 }
 
 impl<T: Finalize> Finalize for Option<T> {
@@ -396,6 +619,16 @@ impl<T: Finalize> Finalize for Option<T> {
             v.finalize(cx);
         }
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match self {
+            Some(v) => v.try_finalize(cx),
+            None => Ok(()),
+        }
+    }
 }
 
 impl<T: Finalize> Finalize for std::rc::Rc<T> {
@@ -404,6 +637,16 @@ impl<T: Finalize> Finalize for std::rc::Rc<T> {
             v.finalize(cx);
         }
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match std::rc::Rc::try_unwrap(self) {
+            Ok(v) => v.try_finalize(cx),
+            Err(_) => Ok(()),
+        }
+    }
 }
 
 impl<T: Finalize> Finalize for std::sync::Arc<T> {
@@ -412,6 +655,16 @@ impl<T: Finalize> Finalize for std::sync::Arc<T> {
             v.finalize(cx);
         }
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match std::sync::Arc::try_unwrap(self) {
+            Ok(v) => v.try_finalize(cx),
+            Err(_) => Ok(()),
+        }
+    }
 }
 
 impl<T: Finalize> Finalize for std::sync::Mutex<T> {
@@ -420,6 +673,16 @@ impl<T: Finalize> Finalize for std::sync::Mutex<T> {
             v.finalize(cx);
         }
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match self.into_inner() {
+            Ok(v) => v.try_finalize(cx),
+            Err(_) => Ok(()),
+        }
+    }
 }
 
 impl<T: Finalize> Finalize for std::sync::RwLock<T> {
@@ -428,16 +691,40 @@ impl<T: Finalize> Finalize for std::sync::RwLock<T> {
             v.finalize(cx);
         }
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        match self.into_inner() {
+            Ok(v) => v.try_finalize(cx),
+            Err(_) => Ok(()),
+        }
+    }
 }
 
 impl<T: Finalize> Finalize for std::cell::Cell<T> {
     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
         self.into_inner().finalize(cx);
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.into_inner().try_finalize(cx)
+    }
 }
 
 impl<T: Finalize> Finalize for std::cell::RefCell<T> {
     fn finalize<'a, C: Context<'a>>(self, cx: &mut C) {
         self.into_inner().finalize(cx);
     }
+
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        cx: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.into_inner().try_finalize(cx)
+    }
 }
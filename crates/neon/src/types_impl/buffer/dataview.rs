@@ -0,0 +1,272 @@
+use crate::{
+    context::{internal::Env, Context},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult, Throw},
+    sys::{self, raw},
+    types_impl::{private::ValueInternal, JsArrayBuffer, Value},
+};
+
+/// The type of JavaScript
+/// [`DataView`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+/// objects.
+///
+/// A `DataView` is a view over a region of a [`JsArrayBuffer`] for reading and writing
+/// values of mixed types at arbitrary byte offsets with an explicit byte order, e.g.,
+/// when decoding a binary protocol. Multi-byte accessors take a `little_endian` flag; as
+/// in JavaScript, `false` selects big-endian ("network") byte order.
+///
+/// As in JavaScript, accessing a value that does not fit entirely within the view
+/// throws a `RangeError`.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsDataView;
+///
+/// // Reads the version and length from the header of a packet
+/// fn parse_header(mut cx: FunctionContext) -> JsResult<JsObject> {
+///     let buf = cx.argument::<JsArrayBuffer>(0)?;
+///     let view = JsDataView::new(&mut cx, buf, 0, 6)?;
+///     let version = view.get_uint16(&mut cx, 0, false)?;
+///     let length = view.get_uint32(&mut cx, 2, false)?;
+///     let header = cx.empty_object();
+///
+///     let version = cx.number(version);
+///     header.set(&mut cx, "version", version)?;
+///     let length = cx.number(length);
+///     header.set(&mut cx, "length", length)?;
+///
+///     Ok(header)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsDataView(raw::Local);
+
+impl JsDataView {
+    /// Constructs a new `DataView` over `byte_length` bytes of `buffer`, starting at
+    /// `byte_offset`.
+    ///
+    /// Throws a `RangeError` if the region does not fit within `buffer`.
+    pub fn new<'cx, C: Context<'cx>>(
+        cx: &mut C,
+        buffer: Handle<JsArrayBuffer>,
+        byte_offset: usize,
+        byte_length: usize,
+    ) -> JsResult<'cx, Self> {
+        let env = cx.env().to_raw();
+        let result =
+            unsafe { sys::dataview::new(env, buffer.to_local(), byte_offset, byte_length) };
+
+        match result {
+            Ok(view) => Ok(Handle::new_internal(Self(view))),
+            Err(_) => Err(unsafe { Throw::new() }),
+        }
+    }
+
+    /// Returns the [`JsArrayBuffer`] referenced by the `DataView`.
+    pub fn buffer<'cx, C: Context<'cx>>(&self, cx: &mut C) -> Handle<'cx, JsArrayBuffer> {
+        let info = unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()) };
+
+        Handle::new_internal(unsafe { JsArrayBuffer::from_local(cx.env(), info.buf) })
+    }
+
+    /// Returns the offset, in bytes, of the start of the `DataView` within its buffer.
+    pub fn byte_offset<'cx, C: Context<'cx>>(&self, cx: &mut C) -> usize {
+        unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()).offset }
+    }
+
+    /// Returns the length, in bytes, of the `DataView`.
+    pub fn byte_length<'cx, C: Context<'cx>>(&self, cx: &mut C) -> usize {
+        unsafe { sys::dataview::info(cx.env().to_raw(), self.to_local()).length }
+    }
+
+    fn read<'cx, C: Context<'cx>, const N: usize>(
+        &self,
+        cx: &mut C,
+        byte_offset: usize,
+    ) -> NeonResult<[u8; N]> {
+        let data = unsafe { sys::dataview::as_mut_slice(cx.env().to_raw(), self.to_local()) };
+
+        match byte_offset
+            .checked_add(N)
+            .and_then(|end| data.get(byte_offset..end))
+        {
+            Some(bytes) => {
+                let mut value = [0; N];
+
+                value.copy_from_slice(bytes);
+                Ok(value)
+            }
+            None => cx.throw_range_error("Offset is outside the bounds of the DataView"),
+        }
+    }
+
+    fn write<'cx, C: Context<'cx>, const N: usize>(
+        &self,
+        cx: &mut C,
+        byte_offset: usize,
+        value: [u8; N],
+    ) -> NeonResult<()> {
+        let data = unsafe { sys::dataview::as_mut_slice(cx.env().to_raw(), self.to_local()) };
+
+        match byte_offset
+            .checked_add(N)
+            .and_then(|end| data.get_mut(byte_offset..end))
+        {
+            Some(bytes) => {
+                bytes.copy_from_slice(&value);
+                Ok(())
+            }
+            None => cx.throw_range_error("Offset is outside the bounds of the DataView"),
+        }
+    }
+}
+
+macro_rules! impl_byte_accessors {
+    ($(($get:ident, $set:ident, $ty:ty, $js_get:literal, $js_set:literal)),* $(,)?) => {
+        impl JsDataView {
+            $(
+                #[doc = concat!(
+                    "Reads the `", stringify!($ty), "` at `byte_offset`, equivalent to ",
+                    "[`DataView.prototype.", $js_get, "`]",
+                    "(https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/",
+                    $js_get, ")."
+                )]
+                pub fn $get<'cx, C: Context<'cx>>(
+                    &self,
+                    cx: &mut C,
+                    byte_offset: usize,
+                ) -> NeonResult<$ty> {
+                    Ok(<$ty>::from_ne_bytes(self.read(cx, byte_offset)?))
+                }
+
+                #[doc = concat!(
+                    "Writes the `", stringify!($ty), "` at `byte_offset`, equivalent to ",
+                    "[`DataView.prototype.", $js_set, "`]",
+                    "(https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/",
+                    $js_set, ")."
+                )]
+                pub fn $set<'cx, C: Context<'cx>>(
+                    &self,
+                    cx: &mut C,
+                    byte_offset: usize,
+                    value: $ty,
+                ) -> NeonResult<()> {
+                    self.write(cx, byte_offset, value.to_ne_bytes())
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! impl_accessors {
+    ($(($get:ident, $set:ident, $ty:ty, $js_get:literal, $js_set:literal)),* $(,)?) => {
+        impl JsDataView {
+            $(
+                #[doc = concat!(
+                    "Reads the `", stringify!($ty), "` at `byte_offset` in the given byte order, ",
+                    "equivalent to [`DataView.prototype.", $js_get, "`]",
+                    "(https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/",
+                    $js_get, ")."
+                )]
+                pub fn $get<'cx, C: Context<'cx>>(
+                    &self,
+                    cx: &mut C,
+                    byte_offset: usize,
+                    little_endian: bool,
+                ) -> NeonResult<$ty> {
+                    let bytes = self.read(cx, byte_offset)?;
+
+                    Ok(if little_endian {
+                        <$ty>::from_le_bytes(bytes)
+                    } else {
+                        <$ty>::from_be_bytes(bytes)
+                    })
+                }
+
+                #[doc = concat!(
+                    "Writes the `", stringify!($ty), "` at `byte_offset` in the given byte order, ",
+                    "equivalent to [`DataView.prototype.", $js_set, "`]",
+                    "(https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/",
+                    $js_set, ")."
+                )]
+                pub fn $set<'cx, C: Context<'cx>>(
+                    &self,
+                    cx: &mut C,
+                    byte_offset: usize,
+                    value: $ty,
+                    little_endian: bool,
+                ) -> NeonResult<()> {
+                    let bytes = if little_endian {
+                        value.to_le_bytes()
+                    } else {
+                        value.to_be_bytes()
+                    };
+
+                    self.write(cx, byte_offset, bytes)
+                }
+            )*
+        }
+    };
+}
+
+impl_byte_accessors!(
+    (get_int8, set_int8, i8, "getInt8", "setInt8"),
+    (get_uint8, set_uint8, u8, "getUint8", "setUint8"),
+);
+
+impl_accessors!(
+    (get_int16, set_int16, i16, "getInt16", "setInt16"),
+    (get_uint16, set_uint16, u16, "getUint16", "setUint16"),
+    (get_int32, set_int32, i32, "getInt32", "setInt32"),
+    (get_uint32, set_uint32, u32, "getUint32", "setUint32"),
+    (get_float32, set_float32, f32, "getFloat32", "setFloat32"),
+    (get_float64, set_float64, f64, "getFloat64", "setFloat64"),
+    (
+        get_bigint64,
+        set_bigint64,
+        i64,
+        "getBigInt64",
+        "setBigInt64"
+    ),
+    (
+        get_biguint64,
+        set_biguint64,
+        u64,
+        "getBigUint64",
+        "setBigUint64"
+    ),
+);
+
+unsafe impl TransparentNoCopyWrapper for JsDataView {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsDataView {
+    fn name() -> &'static str {
+        "JsDataView"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_dataview(env.to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        Self(h)
+    }
+}
+
+impl Value for JsDataView {}
+
+impl Object for JsDataView {}
@@ -18,7 +18,9 @@ use crate::{
     },
 };
 
+pub(super) mod dataview;
 pub(crate) mod lock;
+pub(super) mod shared;
 pub(super) mod types;
 
 pub use types::Binary;
@@ -54,6 +56,39 @@ pub trait TypedArray: Value {
     ///
     /// This may not be used if a mutable borrow is in scope. For the dynamically
     /// checked variant see [`TypedArray::try_borrow`].
+    ///
+    /// A detached buffer, or a typed array viewing one, yields an empty slice.
+    ///
+    /// The slice borrows `cx`, so no JavaScript can run and no handles can be created
+    /// while it is alive. Code that might detach or collect the buffer is a compile
+    /// error:
+    ///
+    /// ```compile_fail
+    /// # use neon::prelude::*;
+    /// # use neon::types::buffer::TypedArray;
+    /// fn first(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let buf = cx.argument::<JsArrayBuffer>(0)?;
+    ///     let data = buf.as_slice(&cx);
+    ///     let n = cx.number(data[0]);
+    ///
+    ///     println!("{}", data.len());
+    ///
+    ///     Ok(n)
+    /// }
+    /// ```
+    ///
+    /// Copy out what is needed and let the slice go out of scope first:
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::buffer::TypedArray;
+    /// fn first(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let buf = cx.argument::<JsArrayBuffer>(0)?;
+    ///     let first = buf.as_slice(&cx)[0];
+    ///
+    ///     Ok(cx.number(first))
+    /// }
+    /// ```
     fn as_slice<'cx, 'a, C>(&self, cx: &'a C) -> &'a [Self::Item]
     where
         C: Context<'cx>;
@@ -62,6 +97,8 @@ pub trait TypedArray: Value {
     ///
     /// This may not be used if any other borrow is in scope. For the dynamically
     /// checked variant see [`TypedArray::try_borrow_mut`].
+    ///
+    /// A detached buffer, or a typed array viewing one, yields an empty slice.
     fn as_mut_slice<'cx, 'a, C>(&mut self, cx: &'a mut C) -> &'a mut [Self::Item]
     where
         C: Context<'cx>;
@@ -69,6 +106,9 @@ pub trait TypedArray: Value {
     /// Dynamically checked immutable borrow of binary data, returning an error if the
     /// the borrow would overlap with a mutable borrow.
     ///
+    /// With the `napi-7` feature, borrowing a detached buffer, or a typed array viewing
+    /// one, also returns an error. See [`BorrowError::is_detached`].
+    ///
     /// The borrow lasts until [`Ref`] exits scope.
     ///
     /// This is the dynamically checked version of [`TypedArray::as_slice`].
@@ -79,6 +119,9 @@ pub trait TypedArray: Value {
     /// Dynamically checked mutable borrow of binary data, returning an error if the
     /// the borrow would overlap with an active borrow.
     ///
+    /// With the `napi-7` feature, borrowing a detached buffer, or a typed array viewing
+    /// one, also returns an error. See [`BorrowError::is_detached`].
+    ///
     /// The borrow lasts until [`RefMut`] exits scope.
     ///
     /// This is the dynamically checked version of [`TypedArray::as_mut_slice`].
@@ -90,6 +133,31 @@ pub trait TypedArray: Value {
         C: Context<'cx>;
 
     /// Returns the size, in bytes, of the allocated binary data.
+    ///
+    /// Only the length is read; the data is not borrowed, so this may be used to cheaply
+    /// validate an input before borrowing its contents. For a
+    /// [`JsBuffer`](crate::types::JsBuffer) or
+    /// [`JsArrayBuffer`](crate::types::JsArrayBuffer), this is the byte length of the
+    /// buffer.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::buffer::TypedArray;
+    /// const MAX_LEN: usize = 1 << 20;
+    ///
+    /// fn checksum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let buf = cx.argument::<JsBuffer>(0)?;
+    ///
+    ///     // Reject oversized inputs before borrowing the data
+    ///     if buf.size(&mut cx) > MAX_LEN {
+    ///         return cx.throw_range_error("buffer is too large");
+    ///     }
+    ///
+    ///     let sum = buf.as_slice(&cx).iter().map(|&b| b as u32).sum::<u32>();
+    ///
+    ///     Ok(cx.number(sum))
+    /// }
+    /// ```
     fn size<'cx, C>(&self, cx: &mut C) -> usize
     where
         C: Context<'cx>;
@@ -158,16 +226,27 @@ impl<'a, T> Drop for RefMut<'a, T> {
 
 #[derive(Eq, PartialEq)]
 /// An error returned by [`TypedArray::try_borrow`] or [`TypedArray::try_borrow_mut`] indicating
-/// that a mutable borrow would overlap with another borrow.
+/// that a mutable borrow would overlap with another borrow, or that the buffer was detached.
 ///
 /// [`BorrowError`] may be converted to an exception with [`ResultExt::or_throw`].
 pub struct BorrowError {
-    _private: (),
+    detached: bool,
 }
 
 impl BorrowError {
     fn new() -> Self {
-        BorrowError { _private: () }
+        BorrowError { detached: false }
+    }
+
+    #[cfg(feature = "napi-7")]
+    fn detached() -> Self {
+        BorrowError { detached: true }
+    }
+
+    /// Indicates the borrow failed because the underlying `ArrayBuffer` was detached,
+    /// e.g., by being transferred to a worker thread.
+    pub fn is_detached(&self) -> bool {
+        self.detached
     }
 }
 
@@ -175,19 +254,31 @@ impl Error for BorrowError {}
 
 impl Display for BorrowError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Display::fmt("Borrow overlaps with an active mutable borrow", f)
+        if self.detached {
+            Display::fmt("Cannot borrow a detached ArrayBuffer", f)
+        } else {
+            Display::fmt("Borrow overlaps with an active mutable borrow", f)
+        }
     }
 }
 
 impl Debug for BorrowError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("BorrowError").finish()
+        f.debug_struct("BorrowError")
+            .field("detached", &self.detached)
+            .finish()
     }
 }
 
 impl<T> ResultExt<T> for Result<T, BorrowError> {
     fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
-        self.or_else(|_| cx.throw_error("BorrowError"))
+        self.or_else(|err| {
+            if err.detached {
+                cx.throw_type_error("Cannot borrow a detached ArrayBuffer")
+            } else {
+                cx.throw_error("BorrowError")
+            }
+        })
     }
 }
 
@@ -201,6 +292,12 @@ impl<T> ResultExt<T> for Result<T, BorrowError> {
 /// a typed array via [`to_typed_array()`](Region::to_typed_array) or
 /// [`JsTypedArray::from_region()`](crate::types::JsTypedArray::from_region).
 ///
+/// The resulting typed array is a _view_ that shares memory with the buffer; no
+/// data is copied and writes through either are visible to the other. This differs
+/// from [`ArrayBuffer.prototype.slice`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice),
+/// which copies, and makes regions useful for handing out windows into a single
+/// large allocation, e.g., a `Uint8Array` per chunk with `buf.region::<u8>(offset, len)`.
+///
 /// # Example
 ///
 /// ```
@@ -0,0 +1,163 @@
+use std::{
+    slice,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::{
+    context::{internal::Env, Context},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw, TypedArrayType},
+    types_impl::{builtin::Builtin, private::ValueInternal, Value},
+};
+
+static SHARED_ARRAY_BUFFER: Builtin = Builtin::new("SharedArrayBuffer", &[]);
+
+static SHARED_ARRAY_BUFFER_GETTERS: Builtin =
+    Builtin::new_getters("SharedArrayBuffer", &["byteLength"]);
+
+static UINT8_ARRAY: Builtin = Builtin::new("Uint8Array", &[]);
+
+/// The type of JavaScript
+/// [`SharedArrayBuffer`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SharedArrayBuffer)
+/// objects.
+///
+/// Unlike a [`JsArrayBuffer`](crate::types::JsArrayBuffer), the memory of a
+/// `SharedArrayBuffer` may be shared with worker threads and read or written by them at
+/// any time, even while Rust holds a reference to it. For this reason the contents are
+/// only exposed as a slice of [`AtomicU8`]; all reads and writes must go through atomic
+/// operations, which pair with the
+/// [`Atomics`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Atomics)
+/// operations used by JavaScript.
+///
+/// Node-API does not provide direct access to `SharedArrayBuffer` objects. A value is
+/// considered a `JsSharedArrayBuffer` if the `SharedArrayBuffer.prototype.byteLength`
+/// getter accepts it, which, unlike `instanceof`, cannot be forged by changing the
+/// prototype of an `ArrayBuffer`. Its contents are accessed through a `Uint8Array`
+/// view.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use std::sync::atomic::Ordering;
+/// use neon::types::JsSharedArrayBuffer;
+///
+/// // Atomically increments every counter in a buffer shared with worker threads
+/// fn increment_all(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+///     let buf = cx.argument::<JsSharedArrayBuffer>(0)?;
+///
+///     for counter in buf.as_atomic_slice(&mut cx)? {
+///         counter.fetch_add(1, Ordering::SeqCst);
+///     }
+///
+///     Ok(cx.undefined())
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSharedArrayBuffer(raw::Local);
+
+impl JsSharedArrayBuffer {
+    /// Constructs a new zero-filled `SharedArrayBuffer` of `len` bytes, equivalent to
+    /// the JavaScript expression `new SharedArrayBuffer(len)`.
+    ///
+    /// Throws a `RangeError` if the buffer cannot be allocated.
+    pub fn new<'cx, C: Context<'cx>>(cx: &mut C, len: usize) -> JsResult<'cx, Self> {
+        let len = cx.number(len as f64).upcast();
+        let buf = SHARED_ARRAY_BUFFER.constructor(cx)?.construct(cx, [len])?;
+
+        Ok(Handle::new_internal(Self(buf.to_local())))
+    }
+
+    /// Constructs a new `SharedArrayBuffer` by copying the contents of `slice`.
+    pub fn from_slice<'cx, C: Context<'cx>>(cx: &mut C, slice: &[u8]) -> JsResult<'cx, Self> {
+        let buf = Self::new(cx, slice.len())?;
+
+        for (dst, src) in buf.as_atomic_slice(cx)?.iter().zip(slice) {
+            dst.store(*src, Ordering::Relaxed);
+        }
+
+        Ok(buf)
+    }
+
+    /// Returns the size, in bytes, of the buffer.
+    pub fn size<'cx, C: Context<'cx>>(&self, cx: &mut C) -> NeonResult<usize> {
+        Ok(self.as_atomic_slice(cx)?.len())
+    }
+
+    /// Borrows the contents of the buffer as a slice of atomic bytes.
+    ///
+    /// The slice may be read and written concurrently by other threads, including
+    /// JavaScript workers, so it is never exposed as a plain `&[u8]` or `&mut [u8]`.
+    /// The memory of a `SharedArrayBuffer` cannot be detached, so the slice remains
+    /// valid as long as the current scope.
+    pub fn as_atomic_slice<'cx, C: Context<'cx>>(&self, cx: &mut C) -> NeonResult<&'cx [AtomicU8]> {
+        let this = Handle::<JsSharedArrayBuffer>::new_internal(Self(self.0));
+        let view = UINT8_ARRAY
+            .constructor(cx)?
+            .construct(cx, [this.upcast()])?
+            .to_local();
+        let env = cx.env().to_raw();
+
+        // The view must be backed by this buffer and the buffer must not be an
+        // `ArrayBuffer`, which could be detached while the slice is borrowed
+        let info = unsafe {
+            if !sys::tag::is_typedarray(env, view) {
+                return cx.throw_type_error("Uint8Array did not construct a typed array");
+            }
+
+            let info = sys::typedarray::info(env, view);
+
+            if info.typ != TypedArrayType::U8
+                || sys::tag::is_arraybuffer(env, info.buf)
+                || !sys::mem::strict_equals(env, info.buf, self.0)
+            {
+                return cx.throw_type_error("Uint8Array did not construct a view of the buffer");
+            }
+
+            info
+        };
+
+        if info.length == 0 {
+            return Ok(&[]);
+        }
+
+        // `AtomicU8` has the same in-memory representation as `u8`
+        Ok(unsafe { slice::from_raw_parts(info.data.cast(), info.length) })
+    }
+}
+
+impl Value for JsSharedArrayBuffer {}
+
+unsafe impl TransparentNoCopyWrapper for JsSharedArrayBuffer {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSharedArrayBuffer {
+    fn name() -> &'static str {
+        "SharedArrayBuffer"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        let value = other.to_local();
+
+        !unsafe { sys::tag::is_arraybuffer(env.to_raw(), value) }
+            && SHARED_ARRAY_BUFFER_GETTERS.has_brand(env, value, "byteLength")
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSharedArrayBuffer(h)
+    }
+}
+
+impl Object for JsSharedArrayBuffer {}
@@ -17,6 +17,9 @@ use crate::{
     },
 };
 
+#[cfg(feature = "napi-7")]
+use crate::result::NeonResult;
+
 #[cfg(feature = "doc-comment")]
 use doc_comment::doc_comment;
 
@@ -25,6 +28,26 @@ macro_rules! doc_comment {
     {$comment:expr, $decl:item} => { $decl };
 }
 
+/// Fails a dynamically checked borrow of a detached `ArrayBuffer`
+///
+/// # Safety
+/// `buf` must be a valid `ArrayBuffer` in `env`
+#[cfg(feature = "napi-7")]
+unsafe fn check_detached(env: raw::Env, buf: raw::Local) -> Result<(), BorrowError> {
+    if sys::arraybuffer::is_detached(env, buf) {
+        Err(BorrowError::detached())
+    } else {
+        Ok(())
+    }
+}
+
+// Without `napi_is_detached_arraybuffer`, a detached buffer is indistinguishable
+// from an empty one and is borrowed as an empty slice
+#[cfg(not(feature = "napi-7"))]
+unsafe fn check_detached(_env: raw::Env, _buf: raw::Local) -> Result<(), BorrowError> {
+    Ok(())
+}
+
 /// The type of Node
 /// [`Buffer`](https://nodejs.org/api/buffer.html)
 /// objects.
@@ -68,6 +91,9 @@ impl JsBuffer {
 
     /// Constructs a `JsBuffer` from a slice by copying its contents.
     ///
+    /// The slice may be freed as soon as this returns. An empty slice produces a
+    /// valid, zero-length `JsBuffer`.
+    ///
     /// This method is defined on `JsBuffer` as a convenience and delegates to
     /// [`TypedArray::from_slice`][TypedArray::from_slice].
     pub fn from_slice<'cx, C>(cx: &mut C, slice: &[u8]) -> JsResult<'cx, Self>
@@ -176,10 +202,15 @@ impl TypedArray for JsBuffer {
     where
         C: Context<'cx>,
     {
-        // The borrowed data must be guarded by `Ledger` before returning
-        Ledger::try_borrow(&lock.ledger, unsafe {
-            sys::buffer::as_mut_slice(lock.cx.env().to_raw(), self.to_local())
-        })
+        unsafe {
+            let env = lock.cx.env().to_raw();
+            let value = self.to_local();
+
+            check_detached(env, sys::typedarray::info(env, value).buf)?;
+
+            // The borrowed data must be guarded by `Ledger` before returning
+            Ledger::try_borrow(&lock.ledger, sys::buffer::as_mut_slice(env, value))
+        }
     }
 
     fn try_borrow_mut<'cx, 'a, C>(
@@ -189,10 +220,15 @@ impl TypedArray for JsBuffer {
     where
         C: Context<'cx>,
     {
-        // The borrowed data must be guarded by `Ledger` before returning
-        Ledger::try_borrow_mut(&lock.ledger, unsafe {
-            sys::buffer::as_mut_slice(lock.cx.env().to_raw(), self.to_local())
-        })
+        unsafe {
+            let env = lock.cx.env().to_raw();
+            let value = self.to_local();
+
+            check_detached(env, sys::typedarray::info(env, value).buf)?;
+
+            // The borrowed data must be guarded by `Ledger` before returning
+            Ledger::try_borrow_mut(&lock.ledger, sys::buffer::as_mut_slice(env, value))
+        }
     }
 
     fn size<'cx, C: Context<'cx>>(&self, cx: &mut C) -> usize {
@@ -253,6 +289,9 @@ impl JsArrayBuffer {
 
     /// Constructs a `JsArrayBuffer` from a slice by copying its contents.
     ///
+    /// The slice may be freed as soon as this returns. An empty slice produces a
+    /// valid, zero-length `JsArrayBuffer`.
+    ///
     /// This method is defined on `JsArrayBuffer` as a convenience and delegates to
     /// [`TypedArray::from_slice`][TypedArray::from_slice].
     pub fn from_slice<'cx, C>(cx: &mut C, slice: &[u8]) -> JsResult<'cx, Self>
@@ -288,6 +327,48 @@ impl JsArrayBuffer {
         Handle::new_internal(Self(value))
     }
 
+    /// Detaches the `ArrayBuffer`, equivalent to transferring it to another thread.
+    ///
+    /// A detached `ArrayBuffer` has a length of zero. Statically checked borrows of it, or
+    /// of any typed array viewing it, produce an empty slice, while
+    /// [`try_borrow`](TypedArray::try_borrow) and [`try_borrow_mut`](TypedArray::try_borrow_mut)
+    /// return a [`BorrowError`]. Throws a `TypeError` if the `ArrayBuffer`
+    /// cannot be detached, e.g., because it is the memory of a WebAssembly instance.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use neon::types::buffer::TypedArray;
+    /// fn take(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    ///     let buf = cx.argument::<JsArrayBuffer>(0)?;
+    ///     let data = buf.as_slice(&cx).to_vec();
+    ///     let copy = JsBuffer::from_slice(&mut cx, &data)?;
+    ///
+    ///     // Prevent further use of the original buffer from JavaScript
+    ///     buf.detach(&mut cx)?;
+    ///     assert!(buf.is_detached(&mut cx));
+    ///     assert!(buf.as_slice(&cx).is_empty());
+    ///
+    ///     Ok(copy)
+    /// }
+    /// ```
+    #[cfg(feature = "napi-7")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+    pub fn detach<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<()> {
+        match unsafe { sys::arraybuffer::detach(cx.env().to_raw(), self.to_local()) } {
+            sys::Status::Ok => Ok(()),
+            sys::Status::PendingException => Err(unsafe { Throw::new() }),
+            _ => cx.throw_type_error("ArrayBuffer cannot be detached"),
+        }
+    }
+
+    /// Checks whether the `ArrayBuffer` has been detached, e.g., by being transferred
+    /// to a worker thread.
+    #[cfg(feature = "napi-7")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "napi-7")))]
+    pub fn is_detached<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        unsafe { sys::arraybuffer::is_detached(cx.env().to_raw(), self.to_local()) }
+    }
+
     /// Returns a region of this buffer.
     ///
     /// See also: [`Handle<JsArrayBuffer>::region()`](Handle::region) for a more
@@ -385,10 +466,15 @@ impl TypedArray for JsArrayBuffer {
     where
         C: Context<'cx>,
     {
-        // The borrowed data must be guarded by `Ledger` before returning
-        Ledger::try_borrow(&lock.ledger, unsafe {
-            sys::arraybuffer::as_mut_slice(lock.cx.env().to_raw(), self.to_local())
-        })
+        unsafe {
+            let env = lock.cx.env().to_raw();
+            let value = self.to_local();
+
+            check_detached(env, value)?;
+
+            // The borrowed data must be guarded by `Ledger` before returning
+            Ledger::try_borrow(&lock.ledger, sys::arraybuffer::as_mut_slice(env, value))
+        }
     }
 
     fn try_borrow_mut<'cx, 'a, C>(
@@ -398,10 +484,15 @@ impl TypedArray for JsArrayBuffer {
     where
         C: Context<'cx>,
     {
-        // The borrowed data must be guarded by `Ledger` before returning
-        Ledger::try_borrow_mut(&lock.ledger, unsafe {
-            sys::arraybuffer::as_mut_slice(lock.cx.env().to_raw(), self.to_local())
-        })
+        unsafe {
+            let env = lock.cx.env().to_raw();
+            let value = self.to_local();
+
+            check_detached(env, value)?;
+
+            // The borrowed data must be guarded by `Ledger` before returning
+            Ledger::try_borrow_mut(&lock.ledger, sys::arraybuffer::as_mut_slice(env, value))
+        }
     }
 
     fn size<'cx, C: Context<'cx>>(&self, cx: &mut C) -> usize {
@@ -549,6 +640,11 @@ where
             let value = self.to_local();
             let info = sys::typedarray::info(env, value);
 
+            // A detached typed array has a null data pointer
+            if info.length == 0 {
+                return &[];
+            }
+
             slice::from_raw_parts(info.data.cast(), info.length)
         }
     }
@@ -562,6 +658,10 @@ where
             let value = self.to_local();
             let info = sys::typedarray::info(env, value);
 
+            if info.length == 0 {
+                return &mut [];
+            }
+
             slice::from_raw_parts_mut(info.data.cast(), info.length)
         }
     }
@@ -578,11 +678,16 @@ where
             let value = self.to_local();
             let info = sys::typedarray::info(env, value);
 
+            check_detached(env, info.buf)?;
+
+            let data = if info.length == 0 {
+                &[]
+            } else {
+                slice::from_raw_parts(info.data.cast(), info.length)
+            };
+
             // The borrowed data must be guarded by `Ledger` before returning
-            Ledger::try_borrow(
-                &lock.ledger,
-                slice::from_raw_parts(info.data.cast(), info.length),
-            )
+            Ledger::try_borrow(&lock.ledger, data)
         }
     }
 
@@ -598,11 +703,16 @@ where
             let value = self.to_local();
             let info = sys::typedarray::info(env, value);
 
+            check_detached(env, info.buf)?;
+
+            let data = if info.length == 0 {
+                &mut []
+            } else {
+                slice::from_raw_parts_mut(info.data.cast(), info.length)
+            };
+
             // The borrowed data must be guarded by `Ledger` before returning
-            Ledger::try_borrow_mut(
-                &lock.ledger,
-                slice::from_raw_parts_mut(info.data.cast(), info.length),
-            )
+            Ledger::try_borrow_mut(&lock.ledger, data)
         }
     }
 
@@ -0,0 +1,251 @@
+//! Cached access to built-in JavaScript classes that do not have a Node-API
+//! equivalent (e.g., `Map` and `Set`).
+
+use crate::{
+    context::{internal::Env, Context, TaskContext},
+    handle::Handle,
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::{self, raw},
+    types::{JsBoolean, JsFunction, JsObject, JsValue},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::{handle::Root, thread::LocalKey};
+
+/// A global class and either the prototype methods used to operate on its instances,
+/// its static functions or the getters of its prototype accessors
+pub(crate) struct Builtin {
+    class: &'static str,
+    methods: &'static [&'static str],
+    lookup: Lookup,
+    #[cfg(feature = "napi-6")]
+    cache: LocalKey<Cache>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Lookup {
+    Prototype,
+    Static,
+    Getter,
+}
+
+#[cfg(feature = "napi-6")]
+struct Cache {
+    constructor: Root<JsFunction>,
+    methods: Vec<Root<JsFunction>>,
+}
+
+impl Builtin {
+    /// Methods are looked up on the prototype of the class, e.g., `Map.prototype.get`
+    pub(crate) const fn new(class: &'static str, methods: &'static [&'static str]) -> Self {
+        Self {
+            class,
+            methods,
+            lookup: Lookup::Prototype,
+            #[cfg(feature = "napi-6")]
+            cache: LocalKey::new(),
+        }
+    }
+
+    /// Methods are looked up on the class itself, e.g., `Object.isFrozen`
+    pub(crate) const fn new_static(class: &'static str, methods: &'static [&'static str]) -> Self {
+        Self {
+            class,
+            methods,
+            lookup: Lookup::Static,
+            #[cfg(feature = "napi-6")]
+            cache: LocalKey::new(),
+        }
+    }
+
+    /// Methods are the `get` functions of accessors on the prototype of the class,
+    /// e.g., `SharedArrayBuffer.prototype.byteLength`
+    pub(crate) const fn new_getters(class: &'static str, methods: &'static [&'static str]) -> Self {
+        Self {
+            class,
+            methods,
+            lookup: Lookup::Getter,
+            #[cfg(feature = "napi-6")]
+            cache: LocalKey::new(),
+        }
+    }
+
+    fn global_constructor<'cx, C>(&self, cx: &mut C) -> JsResult<'cx, JsFunction>
+    where
+        C: Context<'cx>,
+    {
+        cx.global(self.class)
+    }
+
+    fn global_method<'cx, C>(&self, cx: &mut C, name: &str) -> JsResult<'cx, JsFunction>
+    where
+        C: Context<'cx>,
+    {
+        let constructor = self.global_constructor(cx)?;
+
+        if self.lookup == Lookup::Static {
+            return constructor.get(cx, name);
+        }
+
+        let prototype = constructor.get_value(cx, "prototype")?;
+
+        // `Function.prototype` is itself a function
+        let prototype: Handle<JsObject> = match prototype.downcast::<JsFunction, _>(cx) {
+            Ok(prototype) => prototype.upcast(),
+            Err(_) => prototype.downcast_or_throw(cx)?,
+        };
+
+        if self.lookup == Lookup::Prototype {
+            return prototype.get(cx, name);
+        }
+
+        // Reading the accessor through the prototype would invoke the getter
+        let name = cx.string(name);
+        let descriptor = cx
+            .global::<JsFunction>("Object")?
+            .call_method_with(cx, "getOwnPropertyDescriptor")?
+            .arg(prototype)
+            .arg(name)
+            .apply::<JsObject, _>(cx)?;
+
+        descriptor.get(cx, "get")
+    }
+
+    fn index(&self, name: &str) -> usize {
+        self.methods
+            .iter()
+            .position(|method| *method == name)
+            .expect("Method must be listed in the builtin")
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn cache<'cx, C>(&'static self, cx: &mut C) -> NeonResult<&'cx Cache>
+    where
+        C: Context<'cx>,
+    {
+        self.cache.get_or_try_init(cx, |cx| {
+            let constructor = self.global_constructor(cx)?.root(cx);
+            let methods = self
+                .methods
+                .iter()
+                .map(|name| self.global_method(cx, name).map(|f| f.root(cx)))
+                .collect::<NeonResult<_>>()?;
+
+            Ok(Cache {
+                constructor,
+                methods,
+            })
+        })
+    }
+
+    #[cfg(not(feature = "napi-6"))]
+    // N.B.: As with `JSON`, patching the global class could cause differences
+    // between calls on Node-API < 6 where the methods cannot be cached.
+    pub(crate) fn constructor<'cx, C>(&'static self, cx: &mut C) -> JsResult<'cx, JsFunction>
+    where
+        C: Context<'cx>,
+    {
+        self.global_constructor(cx)
+    }
+
+    #[cfg(feature = "napi-6")]
+    pub(crate) fn constructor<'cx, C>(&'static self, cx: &mut C) -> JsResult<'cx, JsFunction>
+    where
+        C: Context<'cx>,
+    {
+        Ok(self.cache(cx)?.constructor.to_inner(cx))
+    }
+
+    #[cfg(not(feature = "napi-6"))]
+    pub(crate) fn method<'cx, C>(&'static self, cx: &mut C, name: &str) -> JsResult<'cx, JsFunction>
+    where
+        C: Context<'cx>,
+    {
+        self.global_method(cx, self.methods[self.index(name)])
+    }
+
+    #[cfg(feature = "napi-6")]
+    pub(crate) fn method<'cx, C>(&'static self, cx: &mut C, name: &str) -> JsResult<'cx, JsFunction>
+    where
+        C: Context<'cx>,
+    {
+        let i = self.index(name);
+
+        Ok(self.cache(cx)?.methods[i].to_inner(cx))
+    }
+
+    /// Checks if `value` is an instance of the global class, equivalent to the
+    /// JavaScript expression `value instanceof Class`. Returns `false` if the class
+    /// is not a function or if the check throws, e.g., from a throwing global getter,
+    /// `Symbol.hasInstance` or proxy trap, in which case the exception is cleared.
+    pub(crate) fn is_instance(&self, env: Env, value: raw::Local) -> bool {
+        let env = env.to_raw();
+
+        unsafe {
+            if !sys::tag::is_object(env, value) || sys::error::is_throwing(env) {
+                return false;
+            }
+
+            let mut global: raw::Local = std::mem::zeroed();
+            let mut constructor: raw::Local = std::mem::zeroed();
+            let mut result = false;
+
+            sys::scope::get_global(env, &mut global);
+
+            let is_instance = sys::object::get_string(
+                env,
+                &mut constructor,
+                global,
+                self.class.as_ptr(),
+                self.class.len() as i32,
+            ) && sys::tag::is_function(env, constructor)
+                && sys::mem::instance_of(&mut result, env, value, constructor)
+                && result;
+
+            // A failed type check must not leave an exception pending
+            sys::error::clear_exception(env);
+
+            is_instance
+        }
+    }
+
+    /// Checks if `value` has the internal slots of the global class by calling the
+    /// getter `name` with `value` bound as `this`. Unlike `instanceof`, this cannot
+    /// be forged by changing the prototype of an object.
+    pub(crate) fn has_brand(&'static self, env: Env, value: raw::Local, name: &str) -> bool {
+        unsafe {
+            // Checking while an exception is pending would clear it
+            if !sys::tag::is_object(env.to_raw(), value) || sys::error::is_throwing(env.to_raw()) {
+                return false;
+            }
+        }
+
+        TaskContext::with_context(env, |mut cx| {
+            cx.execute_scoped(|mut cx| {
+                cx.try_catch(|cx| {
+                    let this = JsValue::new_internal(value);
+
+                    self.method(cx, name)?.call(cx, this, [])
+                })
+                .is_ok()
+            })
+        })
+    }
+}
+
+// Advances a JavaScript iterator, returning `None` when it is done
+pub(crate) fn iter_next<'a, C: Context<'a>>(
+    cx: &mut C,
+    iter: Handle<JsObject>,
+) -> NeonResult<Option<Handle<'a, JsValue>>> {
+    let result = iter
+        .call_method_with(cx, "next")?
+        .apply::<JsObject, _>(cx)?;
+
+    if result.get::<JsBoolean, _, _>(cx, "done")?.value(cx) {
+        return Ok(None);
+    }
+
+    result.get_value(cx, "value").map(Some)
+}
@@ -1,6 +1,7 @@
 use std::{
     error::Error,
     fmt::{self, Debug},
+    time::SystemTime,
 };
 
 use super::{private::ValueInternal, Value};
@@ -145,6 +146,33 @@ impl JsDate {
         Handle::new_internal(JsDate(local))
     }
 
+    /// Creates a new `Date` from a [`SystemTime`](std::time::SystemTime). Times before
+    /// the Unix epoch are represented by negative values. Sub-millisecond precision is
+    /// truncated toward the epoch.
+    ///
+    /// It errors when the time is outside the range of valid JavaScript `Date` values.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::JsDate;
+    /// use std::time::SystemTime;
+    ///
+    /// fn now(mut cx: FunctionContext) -> JsResult<JsDate> {
+    ///     JsDate::from_system_time(&mut cx, SystemTime::now()).or_throw(&mut cx)
+    /// }
+    /// ```
+    pub fn from_system_time<'a, C: Context<'a>>(
+        cx: &mut C,
+        time: SystemTime,
+    ) -> Result<Handle<'a, JsDate>, DateError> {
+        let millis = match time.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(duration) => duration.as_millis() as f64,
+            Err(err) => -(err.duration().as_millis() as f64),
+        };
+
+        JsDate::new(cx, millis)
+    }
+
     /// Gets the `Date`'s value. An invalid `Date` will return [`std::f64::NAN`].
     pub fn value<'a, C: Context<'a>>(&self, cx: &mut C) -> f64 {
         let env = cx.env().to_raw();
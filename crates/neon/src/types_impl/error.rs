@@ -8,7 +8,7 @@ use crate::{
     object::Object,
     result::{NeonResult, Throw},
     sys::{self, raw},
-    types::{build, private::ValueInternal, utf8::Utf8, Value},
+    types::{build, private::ValueInternal, utf8::Utf8, JsFunction, JsString, Value},
 };
 
 /// The type of JavaScript
@@ -78,10 +78,12 @@ impl JsError {
         msg: S,
     ) -> NeonResult<Handle<'a, JsError>> {
         let msg = cx.string(msg.as_ref());
-        build(cx.env(), |out| unsafe {
+        let err = build(cx.env(), |out| unsafe {
             sys::error::new_error(cx.env().to_raw(), out, msg.to_local());
             true
-        })
+        })?;
+
+        with_native_stack(cx, err)
     }
 
     /// Creates an instance of the [`TypeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/TypeError) class.
@@ -92,10 +94,12 @@ impl JsError {
         msg: S,
     ) -> NeonResult<Handle<'a, JsError>> {
         let msg = cx.string(msg.as_ref());
-        build(cx.env(), |out| unsafe {
+        let err = build(cx.env(), |out| unsafe {
             sys::error::new_type_error(cx.env().to_raw(), out, msg.to_local());
             true
-        })
+        })?;
+
+        with_native_stack(cx, err)
     }
 
     /// Creates an instance of the [`RangeError`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/RangeError) class.
@@ -106,11 +110,213 @@ impl JsError {
         msg: S,
     ) -> NeonResult<Handle<'a, JsError>> {
         let msg = cx.string(msg.as_ref());
-        build(cx.env(), |out| unsafe {
+        let err = build(cx.env(), |out| unsafe {
             sys::error::new_range_error(cx.env().to_raw(), out, msg.to_local());
             true
-        })
+        })?;
+
+        with_native_stack(cx, err)
+    }
+
+    /// Reads the error's `message` property as a string.
+    ///
+    /// Throws a `TypeError` if the property is not a string.
+    pub fn message<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<String> {
+        self.get::<JsString, _, _>(cx, "message")
+            .map(|message| message.value(cx))
+    }
+
+    /// Reads the error's `name` property as a string, e.g., `"TypeError"`.
+    ///
+    /// The `name` is usually inherited from the error's prototype. Throws a
+    /// `TypeError` if the property is not a string.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn is_range_error(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    ///     let err = cx.argument::<JsError>(0)?;
+    ///     let name = err.name(&mut cx)?;
+    ///
+    ///     Ok(cx.boolean(name == "RangeError"))
+    /// }
+    /// ```
+    pub fn name<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<String> {
+        self.get::<JsString, _, _>(cx, "name")
+            .map(|name| name.value(cx))
+    }
+
+    /// Reads the error's non-standard `stack` property, returning `None` if it is not a
+    /// string, e.g., if it has been deleted or overwritten.
+    ///
+    /// The format of the stack trace is engine specific. In V8, it begins with the
+    /// `name` and `message` of the error, followed by one line per stack frame.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let err = cx.argument::<JsError>(0)?;
+    ///
+    ///     match err.stack(&mut cx)? {
+    ///         Some(stack) => eprintln!("{stack}"),
+    ///         None => eprintln!("{}", err.message(&mut cx)?),
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn stack<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Option<String>> {
+        let stack = self.get_value(cx, "stack")?;
+
+        Ok(stack
+            .downcast::<JsString, _>(cx)
+            .ok()
+            .map(|stack| stack.value(cx)))
+    }
+
+    /// Creates a direct instance of the `Error` class, like [`JsError::error`], and
+    /// explicitly captures the current JavaScript stack trace in its `stack` property
+    /// with [`Error.captureStackTrace`](https://v8.dev/docs/stack-trace-api).
+    ///
+    /// The stack only includes JavaScript frames, i.e., the callers of the current
+    /// native function. If the engine does not provide `Error.captureStackTrace`, this
+    /// is equivalent to [`JsError::error`].
+    pub fn capture<'a, C: Context<'a>, S: AsRef<str>>(
+        cx: &mut C,
+        msg: S,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let err = JsError::error(cx, msg)?;
+        let capture_stack_trace = cx
+            .global::<JsFunction>("Error")?
+            .get_value(cx, "captureStackTrace")?;
+
+        if let Ok(capture_stack_trace) = capture_stack_trace.downcast::<JsFunction, _>(cx) {
+            capture_stack_trace.call_with(cx).arg(err).exec(cx)?;
+        }
+
+        Ok(err)
     }
+
+    /// Creates a direct instance of the `Error` class from a Rust error.
+    ///
+    /// The message of the error is the [`Display`](std::fmt::Display) output of `err`.
+    /// Each error in the chain of [`Error::source`](std::error::Error::source) is
+    /// converted the same way and attached as the
+    /// [`cause`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error/cause)
+    /// of the previous error.
+    pub fn from_rust_error<'a, C: Context<'a>, E: std::error::Error>(
+        cx: &mut C,
+        err: E,
+    ) -> NeonResult<Handle<'a, JsError>> {
+        let mut messages = vec![err.to_string()];
+        let mut source = err.source();
+
+        while let Some(err) = source {
+            messages.push(err.to_string());
+            source = err.source();
+        }
+
+        let mut cause: Option<Handle<'a, JsError>> = None;
+
+        for msg in messages.into_iter().rev() {
+            let err = JsError::error(cx, msg)?;
+
+            cause = Some(match cause {
+                Some(cause) => err.with_cause(cx, cause)?,
+                None => err,
+            });
+        }
+
+        // The chain always includes at least `err`
+        Ok(cause.unwrap())
+    }
+}
+
+impl<'a> Handle<'a, JsError> {
+    /// Sets the [`cause`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Error/cause)
+    /// of the error, returning the error.
+    ///
+    /// Like errors created with the `cause` option in JavaScript, the property
+    /// is non-enumerable.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn parse_config(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let cause = cx.argument::<JsValue>(0)?;
+    ///     let err = cx
+    ///         .type_error("invalid configuration")?
+    ///         .with_cause(&mut cx, cause)?;
+    ///
+    ///     cx.throw(err)
+    /// }
+    /// ```
+    pub fn with_cause<C: Context<'a>, V: Value>(
+        self,
+        cx: &mut C,
+        cause: Handle<V>,
+    ) -> NeonResult<Self> {
+        define_hidden_property(cx, self, "cause", cause)?;
+
+        Ok(self)
+    }
+}
+
+// Defines a writable, configurable and non-enumerable property, matching the
+// properties that `Error` defines on its instances
+fn define_hidden_property<'a, C: Context<'a>, V: Value>(
+    cx: &mut C,
+    err: Handle<'a, JsError>,
+    key: &str,
+    value: Handle<V>,
+) -> NeonResult<()> {
+    let define_property = cx
+        .global::<JsFunction>("Object")?
+        .get::<JsFunction, _, _>(cx, "defineProperty")?;
+
+    let key = cx.string(key);
+    let descriptor = cx.empty_object();
+    let writable = cx.boolean(true);
+
+    descriptor.set(cx, "value", value)?;
+    descriptor.set(cx, "writable", writable)?;
+    descriptor.set(cx, "configurable", writable)?;
+
+    define_property
+        .call_with(cx)
+        .arg(err)
+        .arg(key)
+        .arg(descriptor)
+        .exec(cx)
+}
+
+// Attaches a captured Rust backtrace as a non-enumerable `nativeStack` property.
+// Compiled out of release builds and skipped unless `RUST_BACKTRACE` is enabled.
+#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+fn with_native_stack<'a, C: Context<'a>>(
+    cx: &mut C,
+    err: Handle<'a, JsError>,
+) -> NeonResult<Handle<'a, JsError>> {
+    #[cfg(debug_assertions)]
+    {
+        use std::backtrace::{Backtrace, BacktraceStatus};
+
+        // Attaching the property calls into JavaScript, which is not possible
+        // while an exception is pending
+        if unsafe { sys::error::is_throwing(cx.env().to_raw()) } {
+            return Ok(err);
+        }
+
+        let backtrace = Backtrace::capture();
+
+        if backtrace.status() != BacktraceStatus::Captured {
+            return Ok(err);
+        }
+
+        let value = cx.string(backtrace.to_string());
+
+        define_hidden_property(cx, err, "nativeStack", value)?;
+    }
+
+    Ok(err)
 }
 
 pub(crate) fn convert_panics<T, F: UnwindSafe + FnOnce() -> NeonResult<T>>(
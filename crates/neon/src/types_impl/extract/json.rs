@@ -1,96 +1,27 @@
 use crate::{
     context::Context,
     handle::Handle,
-    object::Object,
     result::{JsResult, NeonResult},
     types::{
         extract::{private, TryFromJs, TryIntoJs},
-        JsFunction, JsObject, JsString, JsValue,
+        JsValue,
     },
+    types_impl::json,
 };
 
-#[cfg(feature = "napi-6")]
-use crate::{handle::Root, thread::LocalKey};
-
-fn global_json_stringify<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
-where
-    C: Context<'cx>,
-{
-    cx.global::<JsObject>("JSON")?.get(cx, "stringify")
-}
-
-#[cfg(not(feature = "napi-6"))]
-// N.B.: This is not semantically identical to Node-API >= 6. Patching the global
-// method could cause differences between calls. However, threading a `Root` through
-// would require a significant refactor and "don't do this or things will break" is
-// fairly common in JS.
-fn json_stringify<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
-where
-    C: Context<'cx>,
-{
-    global_json_stringify(cx)
-}
-
-#[cfg(feature = "napi-6")]
-fn json_stringify<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
-where
-    C: Context<'cx>,
-{
-    static STRINGIFY: LocalKey<Root<JsFunction>> = LocalKey::new();
-
-    STRINGIFY
-        .get_or_try_init(cx, |cx| global_json_stringify(cx).map(|f| f.root(cx)))
-        .map(|f| f.to_inner(cx))
-}
-
 fn stringify<'cx, C>(cx: &mut C, v: Handle<JsValue>) -> NeonResult<String>
 where
     C: Context<'cx>,
 {
-    json_stringify(cx)?
-        .call(cx, v, [v])?
-        .downcast_or_throw::<JsString, _>(cx)
-        .map(|s| s.value(cx))
-}
-
-fn global_json_parse<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
-where
-    C: Context<'cx>,
-{
-    cx.global::<JsObject>("JSON")?.get(cx, "parse")
-}
-
-#[cfg(not(feature = "napi-6"))]
-fn json_parse<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
-where
-    C: Context<'cx>,
-{
-    global_json_parse(cx)
-}
-
-#[cfg(feature = "napi-6")]
-fn json_parse<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
-where
-    C: Context<'cx>,
-{
-    static PARSE: LocalKey<Root<JsFunction>> = LocalKey::new();
-
-    PARSE
-        .get_or_try_init(cx, |cx| global_json_parse(cx).map(|f| f.root(cx)))
-        .map(|f| f.to_inner(cx))
-}
-
-fn parse<'cx, C>(cx: &mut C, s: &str) -> JsResult<'cx, JsValue>
-where
-    C: Context<'cx>,
-{
-    let s = cx.string(s).upcast();
-
-    json_parse(cx)?.call(cx, s, [s])
+    json::stringify(cx, v).map(|s| s.value(cx))
 }
 
 /// Wrapper for converting between `T` and [`JsValue`](crate::types::JsValue) by
 /// serializing with JSON.
+///
+/// Extraction uses `JSON.stringify`, so values with a `toJSON` method are
+/// serialized the same way as in JavaScript. For example, a `Date` is extracted
+/// as its ISO string.
 pub struct Json<T>(pub T);
 
 impl<'cx, T> TryFromJs<'cx> for Json<T>
@@ -126,7 +57,7 @@ where
     {
         let s = serde_json::to_string(&self.0).or_else(|err| cx.throw_error(err.to_string()))?;
 
-        parse(cx, &s)
+        json::parse(cx, &s)
     }
 }
 
@@ -47,6 +47,27 @@
 //! }
 //! ```
 //!
+//! ## Converting Rust Values
+//!
+//! The reverse conversion is provided by [`TryIntoJs`]. It is used for the return
+//! values of functions exported with [`#[neon::export]`](crate::export), but may also
+//! be called directly. As with extraction, [`None`] is converted to `undefined`.
+//!
+//! ```
+//! # use neon::{prelude::*, types::extract::*};
+//! fn checksum(mut cx: FunctionContext) -> JsResult<JsValue> {
+//!     let data: Vec<u8> = cx.args()?;
+//!     let sum = data.iter().map(|&b| u32::from(b)).reduce(u32::wrapping_add);
+//!
+//!     Ok(sum.try_into_js(&mut cx)?.upcast())
+//! }
+//! ```
+//!
+//! Failing to extract a value throws a `TypeError`. Conversions are only provided
+//! where they are lossless; in particular, 64-bit integers are not supported since
+//! a JavaScript `number` cannot represent all of their values exactly. Extract an
+//! [`f64`] and convert it explicitly, or use a [`JsBigInt`](super::JsBigInt).
+//!
 //! ## Additional Extractors
 //!
 //! In some cases, the expected JavaScript type is ambiguous. For example, when
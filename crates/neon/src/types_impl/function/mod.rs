@@ -8,6 +8,7 @@ use crate::{
     object::Object,
     result::{JsResult, NeonResult},
     types::{JsFunction, JsObject, JsValue, Value},
+    types_impl::builtin::Builtin,
 };
 
 pub(crate) mod private;
@@ -70,6 +71,68 @@ impl<'a> CallOptions<'a> {
     }
 }
 
+/// A builder for creating a bound function like `f.bind(obj, 1, 2)`.
+///
+/// The resulting function is created with
+/// [`Function.prototype.bind`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind)
+/// and has the same semantics: when called, the bound arguments are prepended to
+/// the arguments it is called with. When called as a constructor, the bound `this`
+/// is ignored.
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn foo(mut cx: FunctionContext) -> JsResult<JsFunction> {
+/// # let parse_int: Handle<JsFunction> = cx.global("parseInt")?;
+/// // Equivalent to `(radix) => parseInt("ff", radix)`
+/// let parse_hex = parse_int
+///     .bind(&cx)
+///     .arg(cx.string("ff"))
+///     .build(&mut cx)?;
+/// # Ok(parse_hex)
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct BindOptions<'a> {
+    pub(crate) callee: Handle<'a, JsFunction>,
+    pub(crate) this: Option<Handle<'a, JsValue>>,
+    pub(crate) args: private::ArgsVec<'a>,
+}
+
+impl<'a> BindOptions<'a> {
+    /// Set the value of `this` for calls to the bound function.
+    pub fn this<V: Value>(&mut self, this: Handle<'a, V>) -> &mut Self {
+        self.this = Some(this.upcast());
+        self
+    }
+
+    /// Add an argument to the bound arguments list.
+    pub fn arg<V: Value>(&mut self, arg: Handle<'a, V>) -> &mut Self {
+        self.args.push(arg.upcast());
+        self
+    }
+
+    /// Replaces the bound arguments list with the given arguments.
+    pub fn args<A: Arguments<'a>>(&mut self, args: A) -> &mut Self {
+        self.args = args.into_args_vec();
+        self
+    }
+
+    /// Create the bound function.
+    pub fn build<'b: 'a, C: Context<'b>>(&self, cx: &mut C) -> JsResult<'b, JsFunction> {
+        let this = self.this.unwrap_or_else(|| cx.undefined().upcast());
+        let mut args: private::ArgsVec = smallvec![this];
+
+        args.extend_from_slice(&self.args);
+
+        FUNCTION
+            .method(cx, "bind")?
+            .call(cx, self.callee, &args)?
+            .downcast_or_throw(cx)
+    }
+}
+
+static FUNCTION: Builtin = Builtin::new("Function", &["bind"]);
+
 /// A builder for making a JavaScript constructor call like `new Array(16)`.
 ///
 /// The builder methods make it convenient to assemble the call from parts:
@@ -116,6 +179,19 @@ impl<'a> ConstructOptions<'a> {
 ///
 /// **Note:** This trait is implemented for tuples of up to 32 JavaScript values,
 /// but for the sake of brevity, only tuples up to size 8 are shown in this documentation.
+///
+/// Argument lists with a dynamic length, or longer than 32 values, may be passed as a
+/// slice or `Vec` of handles of the same type:
+///
+/// ```
+/// # use neon::prelude::*;
+/// # fn foo(mut cx: FunctionContext) -> JsResult<JsNumber> {
+/// # let max: Handle<JsFunction> = cx.global::<JsObject>("Math")?.get(&mut cx, "max")?;
+/// let args = (0..64).map(|n| cx.number(n)).collect::<Vec<_>>();
+/// let n: Handle<JsNumber> = max.call_with(&cx).args(args).apply(&mut cx)?;
+/// # Ok(n)
+/// # }
+/// ```
 pub trait Arguments<'a>: private::ArgumentsInternal<'a> {}
 
 impl<'a> private::ArgumentsInternal<'a> for () {
@@ -126,6 +202,22 @@ impl<'a> private::ArgumentsInternal<'a> for () {
 
 impl<'a> Arguments<'a> for () {}
 
+impl<'a, V: Value> private::ArgumentsInternal<'a> for &[Handle<'a, V>] {
+    fn into_args_vec(self) -> private::ArgsVec<'a> {
+        self.iter().map(|v| v.upcast()).collect()
+    }
+}
+
+impl<'a, V: Value> Arguments<'a> for &[Handle<'a, V>] {}
+
+impl<'a, V: Value> private::ArgumentsInternal<'a> for Vec<Handle<'a, V>> {
+    fn into_args_vec(self) -> private::ArgsVec<'a> {
+        self.as_slice().into_args_vec()
+    }
+}
+
+impl<'a, V: Value> Arguments<'a> for Vec<Handle<'a, V>> {}
+
 macro_rules! impl_arguments {
     {
         [ $(($tprefix:ident, $vprefix:ident), )* ];
@@ -0,0 +1,88 @@
+//! Cached access to the global `JSON.parse` and `JSON.stringify` functions.
+
+use crate::{
+    context::Context,
+    handle::Handle,
+    object::Object,
+    result::JsResult,
+    types::{JsFunction, JsObject, JsString, JsValue},
+};
+
+#[cfg(feature = "napi-6")]
+use crate::{handle::Root, thread::LocalKey};
+
+fn global_json_stringify<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    cx.global::<JsObject>("JSON")?.get(cx, "stringify")
+}
+
+#[cfg(not(feature = "napi-6"))]
+// N.B.: This is not semantically identical to Node-API >= 6. Patching the global
+// method could cause differences between calls. However, threading a `Root` through
+// would require a significant refactor and "don't do this or things will break" is
+// fairly common in JS.
+fn json_stringify<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    global_json_stringify(cx)
+}
+
+#[cfg(feature = "napi-6")]
+fn json_stringify<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    static STRINGIFY: LocalKey<Root<JsFunction>> = LocalKey::new();
+
+    STRINGIFY
+        .get_or_try_init(cx, |cx| global_json_stringify(cx).map(|f| f.root(cx)))
+        .map(|f| f.to_inner(cx))
+}
+
+pub(crate) fn stringify<'cx, C>(cx: &mut C, v: Handle<JsValue>) -> JsResult<'cx, JsString>
+where
+    C: Context<'cx>,
+{
+    json_stringify(cx)?
+        .call(cx, v, [v])?
+        .downcast_or_throw::<JsString, _>(cx)
+}
+
+fn global_json_parse<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    cx.global::<JsObject>("JSON")?.get(cx, "parse")
+}
+
+#[cfg(not(feature = "napi-6"))]
+fn json_parse<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    global_json_parse(cx)
+}
+
+#[cfg(feature = "napi-6")]
+fn json_parse<'cx, C>(cx: &mut C) -> JsResult<'cx, JsFunction>
+where
+    C: Context<'cx>,
+{
+    static PARSE: LocalKey<Root<JsFunction>> = LocalKey::new();
+
+    PARSE
+        .get_or_try_init(cx, |cx| global_json_parse(cx).map(|f| f.root(cx)))
+        .map(|f| f.to_inner(cx))
+}
+
+pub(crate) fn parse<'cx, C>(cx: &mut C, s: &str) -> JsResult<'cx, JsValue>
+where
+    C: Context<'cx>,
+{
+    let s = cx.string(s).upcast();
+
+    json_parse(cx)?.call(cx, s, [s])
+}
@@ -0,0 +1,184 @@
+use super::{
+    builtin::{self, Builtin},
+    private::ValueInternal,
+    Value,
+};
+
+use crate::{
+    context::{internal::Env, Context},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::raw,
+    types::{JsBoolean, JsNumber, JsObject, JsValue},
+};
+
+static MAP: Builtin = Builtin::new("Map", &["get", "set", "has", "delete", "entries"]);
+
+/// The type of JavaScript
+/// [`Map`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+/// objects.
+///
+/// Unlike the properties of a [`JsObject`], the keys of a `Map` may be any JavaScript
+/// value and are compared with
+/// [SameValueZero](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Equality_comparisons_and_sameness#same-value-zero_equality)
+/// equality, i.e., objects are compared by reference identity. Entries are iterated in
+/// insertion order.
+///
+/// Node-API does not provide direct access to `Map` objects. Operations call the
+/// methods of `Map.prototype`, which are looked up once per module instance on
+/// Node-API 6 or later. A value is considered a `JsMap` if it is an `instanceof` the
+/// global `Map` class.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsMap;
+///
+/// // Counts the number of times each value appears in an array
+/// fn count(mut cx: FunctionContext) -> JsResult<JsMap> {
+///     let values = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+///     let counts = JsMap::new(&mut cx)?;
+///
+///     for value in values {
+///         let n = counts
+///             .get(&mut cx, value)?
+///             .downcast::<JsNumber, _>(&mut cx)
+///             .map(|n| n.value(&mut cx))
+///             .unwrap_or(0.0);
+///         let n = cx.number(n + 1.0);
+///
+///         counts.set(&mut cx, value, n)?;
+///     }
+///
+///     Ok(counts)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsMap(raw::Local);
+
+impl JsMap {
+    /// Constructs a new empty `Map`, equivalent to the JavaScript expression `new Map()`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsMap> {
+        let map = MAP.constructor(cx)?.construct(cx, [])?;
+
+        Ok(Handle::new_internal(JsMap(map.to_local())))
+    }
+
+    /// Returns the number of entries in the `Map`.
+    pub fn size<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<usize> {
+        let size = Object::get::<JsNumber, _, _>(self, cx, "size")?;
+
+        Ok(size.value(cx) as usize)
+    }
+
+    /// Returns the value associated with `key`, or `undefined` if the `Map` does not
+    /// contain `key`.
+    pub fn get<'a, C: Context<'a>, K: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> JsResult<'a, JsValue> {
+        self.call(cx, "get", &[key.upcast()])
+    }
+
+    /// Associates `value` with `key`, replacing any existing value.
+    pub fn set<'a, C: Context<'a>, K: Value, V: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+        value: Handle<V>,
+    ) -> NeonResult<()> {
+        self.call(cx, "set", &[key.upcast(), value.upcast()])?;
+
+        Ok(())
+    }
+
+    /// Checks whether the `Map` contains `key`.
+    pub fn has<'a, C: Context<'a>, K: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> NeonResult<bool> {
+        let has = self.call(cx, "has", &[key.upcast()])?;
+
+        Ok(has.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Removes the entry for `key`, returning `true` if the `Map` contained `key`.
+    pub fn delete<'a, C: Context<'a>, K: Value>(
+        &self,
+        cx: &mut C,
+        key: Handle<K>,
+    ) -> NeonResult<bool> {
+        let deleted = self.call(cx, "delete", &[key.upcast()])?;
+
+        Ok(deleted.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Copies the entries of the `Map` into a new [`Vec`] of key-value pairs, in
+    /// insertion order.
+    pub fn entries<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+    ) -> NeonResult<Vec<(Handle<'a, JsValue>, Handle<'a, JsValue>)>> {
+        let iter = self
+            .call(cx, "entries", &[])?
+            .downcast_or_throw::<JsObject, _>(cx)?;
+
+        let mut entries = Vec::new();
+
+        while let Some(entry) = builtin::iter_next(cx, iter)? {
+            let entry = entry.downcast_or_throw::<JsObject, _>(cx)?;
+            let key = entry.get_value(cx, 0)?;
+            let value = entry.get_value(cx, 1)?;
+
+            entries.push((key, value));
+        }
+
+        Ok(entries)
+    }
+
+    fn call<'a, 'b, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        method: &str,
+        args: &[Handle<'b, JsValue>],
+    ) -> JsResult<'a, JsValue> {
+        let this = JsValue::new_internal(self.0);
+
+        MAP.method(cx, method)?.call(cx, this, args)
+    }
+}
+
+impl Value for JsMap {}
+
+unsafe impl TransparentNoCopyWrapper for JsMap {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsMap {
+    fn name() -> &'static str {
+        "Map"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        MAP.is_instance(env, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsMap(h)
+    }
+}
+
+impl Object for JsMap {}
@@ -5,18 +5,24 @@
 pub mod bigint;
 pub(crate) mod boxed;
 pub mod buffer;
+pub(crate) mod builtin;
 #[cfg(feature = "napi-5")]
 pub(crate) mod date;
 pub(crate) mod error;
 pub mod extract;
 pub mod function;
+pub(crate) mod json;
+pub(crate) mod map;
 pub(crate) mod promise;
+pub(crate) mod regexp;
+pub(crate) mod set;
 
 pub(crate) mod private;
 pub(crate) mod utf8;
 
 use std::{
     any,
+    cmp::Ordering,
     fmt::{self, Debug},
     os::raw::c_void,
 };
@@ -29,25 +35,31 @@ use crate::{
         internal::{SuperType, TransparentNoCopyWrapper},
         Handle,
     },
-    object::Object,
+    object::{Object, PropertyKey},
     result::{JsResult, NeonResult, ResultExt, Throw},
     sys::{self, raw},
     types::{
-        function::{CallOptions, ConstructOptions},
+        function::{BindOptions, CallOptions, ConstructOptions},
         private::ValueInternal,
         utf8::Utf8,
     },
+    types_impl::builtin::Builtin,
 };
 
 pub use self::{
-    boxed::{Finalize, JsBox},
+    boxed::{Finalize, FinalizeError, JsBox},
+    buffer::dataview::JsDataView,
+    buffer::shared::JsSharedArrayBuffer,
     buffer::types::{
         JsArrayBuffer, JsBigInt64Array, JsBigUint64Array, JsBuffer, JsFloat32Array, JsFloat64Array,
         JsInt16Array, JsInt32Array, JsInt8Array, JsTypedArray, JsUint16Array, JsUint32Array,
         JsUint8Array,
     },
     error::JsError,
+    map::JsMap,
     promise::{Deferred, JsPromise},
+    regexp::JsRegExp,
+    set::JsSet,
 };
 
 #[cfg(feature = "napi-5")]
@@ -85,6 +97,119 @@ impl<T: Object> SuperType<T> for JsObject {
     }
 }
 
+/// A primitive type that any JavaScript value can be converted to with JavaScript's
+/// built-in type coercion.
+///
+/// The conversions are the abstract `ToString`, `ToNumber` and `ToBoolean` operations,
+/// like `` `${value}` ``, `+value` and `!!value`, for [`JsString`], [`JsNumber`] and
+/// [`JsBoolean`] respectively. Unlike `String(value)` and `Number(value)`, coercing a
+/// `Symbol` to a string or number, or a `BigInt` to a number, throws a `TypeError`.
+/// Coercing an object calls its `toString`, `valueOf` or `Symbol.toPrimitive` methods,
+/// which may throw.
+///
+/// This trait is sealed and cannot be implemented outside of Neon.
+pub trait Coerce: Value + private::CoerceInternal {}
+
+impl Coerce for JsString {}
+
+impl private::CoerceInternal for JsString {
+    unsafe fn coerce(out: &mut raw::Local, env: raw::Env, value: raw::Local) -> bool {
+        sys::convert::to_string(out, env, value)
+    }
+}
+
+impl Coerce for JsNumber {}
+
+impl private::CoerceInternal for JsNumber {
+    unsafe fn coerce(out: &mut raw::Local, env: raw::Env, value: raw::Local) -> bool {
+        sys::convert::to_number(out, env, value)
+    }
+}
+
+impl Coerce for JsBoolean {}
+
+impl private::CoerceInternal for JsBoolean {
+    unsafe fn coerce(out: &mut raw::Local, env: raw::Env, value: raw::Local) -> bool {
+        sys::convert::to_bool(out, env, value)
+    }
+}
+
+pub(crate) fn coerce<'cx, V: Coerce>(env: Env, value: raw::Local) -> JsResult<'cx, V> {
+    build(env, |out| unsafe { V::coerce(out, env.to_raw(), value) })
+}
+
+/// The type of a JavaScript value, as returned by [`Value::type_of`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ValueType {
+    /// `undefined`
+    Undefined,
+    /// `null`
+    Null,
+    /// A boolean primitive
+    Boolean,
+    /// A number primitive
+    Number,
+    /// A string primitive
+    String,
+    /// A symbol primitive
+    Symbol,
+    /// Any object that is not a function, including arrays
+    Object,
+    /// A function
+    Function,
+    /// A native value created with [`JsBox`]
+    External,
+    /// A `BigInt` primitive
+    BigInt,
+}
+
+// Formats a value with `util.inspect`, returning `None` if `util` is not available.
+// Custom inspection, getters and proxy traps are disabled so that formatting does
+// not run user code.
+fn inspect<'cx, C: Context<'cx>>(cx: &mut C, value: Handle<JsValue>) -> NeonResult<Option<String>> {
+    let process = match cx.global::<JsValue>("process")?.downcast::<JsObject, _>(cx) {
+        Ok(process) => process,
+        Err(_) => return Ok(None),
+    };
+
+    let get_builtin_module = match process
+        .get_value(cx, "getBuiltinModule")?
+        .downcast::<JsFunction, _>(cx)
+    {
+        Ok(f) => f,
+        Err(_) => return Ok(None),
+    };
+
+    let util = get_builtin_module
+        .call_with(cx)
+        .this(process)
+        .arg(cx.string("util"))
+        .apply::<JsObject, _>(cx)?;
+
+    let options = cx.empty_object();
+    let depth = cx.number(1);
+    let break_length = cx.number(f64::INFINITY);
+
+    options.set(cx, "depth", depth)?;
+    options.set(cx, "breakLength", break_length)?;
+
+    for (key, value) in [
+        ("customInspect", false),
+        ("showProxy", true),
+        ("getters", false),
+    ] {
+        let value = cx.boolean(value);
+
+        options.set(cx, key, value)?;
+    }
+
+    let s = util
+        .call_method(cx, "inspect", [value, options.upcast()])?
+        .downcast_or_throw::<JsString, _>(cx)?;
+
+    Ok(Some(s.value(cx)))
+}
+
 /// The trait shared by all JavaScript values.
 pub trait Value: ValueInternal {
     fn to_string<'cx, C: Context<'cx>>(&self, cx: &mut C) -> JsResult<'cx, JsString> {
@@ -94,10 +219,223 @@ pub trait Value: ValueInternal {
         })
     }
 
+    /// Converts the value to a string with the abstract `ToString` operation, like
+    /// `` `${value}` ``.
+    ///
+    /// This is the same as [`Value::to_string`]. Throws if the value is a `Symbol` or if
+    /// an object's conversion methods throw.
+    fn coerce_to_string<'cx, C: Context<'cx>>(&self, cx: &mut C) -> JsResult<'cx, JsString> {
+        coerce(cx.env(), self.to_local())
+    }
+
+    /// Converts the value to a number with the abstract `ToNumber` operation, like
+    /// `+value`.
+    ///
+    /// Values that cannot be converted produce `NaN`. Throws a `TypeError` if the value
+    /// is a `Symbol` or a `BigInt`, or propagates an exception thrown by an object's
+    /// conversion methods.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let values = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    ///     let mut sum = 0.0;
+    ///
+    ///     for value in values {
+    ///         sum += value.coerce_to_number(&mut cx)?.value(&mut cx);
+    ///     }
+    ///
+    ///     Ok(cx.number(sum))
+    /// }
+    /// ```
+    fn coerce_to_number<'cx, C: Context<'cx>>(&self, cx: &mut C) -> JsResult<'cx, JsNumber> {
+        coerce(cx.env(), self.to_local())
+    }
+
+    /// Converts the value to a boolean with JavaScript's built-in type coercion,
+    /// equivalent to `Boolean(value)`, i.e., its truthiness. This never throws.
+    fn coerce_to_bool<'cx, C: Context<'cx>>(&self, cx: &mut C) -> JsResult<'cx, JsBoolean> {
+        coerce(cx.env(), self.to_local())
+    }
+
+    /// Returns the type of the value, similar to the JavaScript `typeof` operator.
+    ///
+    /// Unlike `typeof`, `null` is reported as [`ValueType::Null`] rather than an object
+    /// and values created with [`JsBox`] are reported as [`ValueType::External`]. Like
+    /// `typeof`, all other objects, including arrays and dates, are reported as
+    /// [`ValueType::Object`]; use [`Handle::is_a`] to refine the type.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// use neon::types::ValueType;
+    ///
+    /// fn describe(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let value = cx.argument::<JsValue>(0)?;
+    ///     let description = match value.type_of(&mut cx) {
+    ///         ValueType::Undefined | ValueType::Null => "nothing",
+    ///         ValueType::Number | ValueType::BigInt => "a number",
+    ///         ValueType::String => "a string",
+    ///         ValueType::Function => "a function",
+    ///         _ => "something else",
+    ///     };
+    ///
+    ///     Ok(cx.string(description))
+    /// }
+    /// ```
+    fn type_of<'cx, C: Context<'cx>>(&self, cx: &mut C) -> ValueType {
+        let ty = unsafe { sys::tag::type_of(cx.env().to_raw(), self.to_local()) };
+
+        match ty {
+            sys::ValueType::Undefined => ValueType::Undefined,
+            sys::ValueType::Null => ValueType::Null,
+            sys::ValueType::Boolean => ValueType::Boolean,
+            sys::ValueType::Number => ValueType::Number,
+            sys::ValueType::String => ValueType::String,
+            sys::ValueType::Symbol => ValueType::Symbol,
+            sys::ValueType::Object => ValueType::Object,
+            sys::ValueType::Function => ValueType::Function,
+            sys::ValueType::External => ValueType::External,
+            sys::ValueType::BigInt => ValueType::BigInt,
+        }
+    }
+
     fn as_value<'cx, C: Context<'cx>>(&self, _: &mut C) -> Handle<'cx, JsValue> {
         JsValue::new_internal(self.to_local())
     }
 
+    /// Reads the name of the value's constructor, i.e., `value.constructor.name`.
+    ///
+    /// Returns `None` for primitive values or if the constructor or its name are
+    /// not available. Useful for producing diagnostics on type mismatches.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn expect_buffer(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    ///     let v = cx.argument::<JsValue>(0)?;
+    ///
+    ///     if let Ok(buf) = v.downcast::<JsBuffer, _>(&mut cx) {
+    ///         return Ok(buf);
+    ///     }
+    ///
+    ///     let name = v.constructor_name(&mut cx)?;
+    ///     let name = name.as_deref().unwrap_or("a primitive");
+    ///
+    ///     cx.throw_type_error(format!("expected a Buffer, got {name}"))
+    /// }
+    /// ```
+    fn constructor_name<'cx, C: Context<'cx>>(&self, cx: &mut C) -> NeonResult<Option<String>> {
+        let env = cx.env().to_raw();
+        let local = self.to_local();
+
+        if !unsafe { sys::tag::is_object(env, local) || sys::tag::is_function(env, local) } {
+            return Ok(None);
+        }
+
+        let obj: Handle<JsObject> =
+            Handle::new_internal(unsafe { JsObject::from_local(cx.env(), local) });
+        let constructor = match obj
+            .get_value(cx, "constructor")?
+            .downcast::<JsFunction, _>(cx)
+        {
+            Ok(constructor) => constructor,
+            Err(_) => return Ok(None),
+        };
+
+        let name = constructor
+            .get_value(cx, "name")?
+            .downcast::<JsString, _>(cx)
+            .ok()
+            .map(|name| name.value(cx))
+            .filter(|name| !name.is_empty());
+
+        Ok(name)
+    }
+
+    /// Produces a short, human readable representation of the value for logging and
+    /// error messages, e.g., `'hello'` or `{ a: 1 }`.
+    ///
+    /// The value is formatted on a single line with
+    /// [`util.inspect`](https://nodejs.org/api/util.html#utilinspectobject-options),
+    /// loaded with `process.getBuiltinModule`, with custom inspection functions,
+    /// getters and proxy traps disabled. If `util` is not available (Node.js
+    /// before 20.16), strings are quoted, other primitives are converted with
+    /// `String(value)` and objects are described by their type, e.g., `[Object]`.
+    /// Formatting does not call methods of the value and never throws.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn expect_positive(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let v = cx.argument::<JsValue>(0)?;
+    ///
+    ///     match v.downcast::<JsNumber, _>(&mut cx) {
+    ///         Ok(n) if n.value(&mut cx) > 0.0 => Ok(n),
+    ///         _ => {
+    ///             let v = v.to_debug_string(&mut cx);
+    ///
+    ///             cx.throw_range_error(format!("expected a positive number, got {v}"))
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    fn to_debug_string<'cx, C: Context<'cx>>(&self, cx: &mut C) -> String {
+        let value = JsValue::new_internal(self.to_local());
+
+        if let Ok(Some(s)) = cx.try_catch(|cx| inspect(cx, value)) {
+            return s;
+        }
+
+        if let Ok(s) = value.downcast::<JsString, _>(cx) {
+            return format!("'{}'", s.value(cx));
+        }
+
+        match self.type_of(cx) {
+            // Converting other primitives does not call user code
+            ty @ (ValueType::Undefined
+            | ValueType::Null
+            | ValueType::Boolean
+            | ValueType::Number
+            | ValueType::BigInt) => cx
+                .try_catch(|cx| Ok(value.to_string(cx)?.value(cx)))
+                .unwrap_or_else(|_| format!("[{ty:?}]")),
+            ty => format!("[{ty:?}]"),
+        }
+    }
+
+    /// Checks whether the value is an instance of `constructor`, equivalent to the
+    /// JavaScript expression `value instanceof constructor`.
+    ///
+    /// Throws a `TypeError` if `constructor` is not a function.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn expect_stream(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let stream = cx.argument::<JsObject>(0)?;
+    ///     let readable = cx.argument::<JsFunction>(1)?;
+    ///
+    ///     if !stream.instance_of(&mut cx, readable)? {
+    ///         return cx.throw_type_error("expected a readable stream");
+    ///     }
+    ///
+    ///     Ok(stream)
+    /// }
+    /// ```
+    fn instance_of<'cx, C: Context<'cx>, U: Value>(
+        &self,
+        cx: &mut C,
+        constructor: Handle<U>,
+    ) -> NeonResult<bool> {
+        let mut result = false;
+        let env = cx.env().to_raw();
+
+        unsafe {
+            if sys::mem::instance_of(&mut result, env, self.to_local(), constructor.to_local()) {
+                Ok(result)
+            } else {
+                Err(Throw::new())
+            }
+        }
+    }
+
     #[cfg(feature = "sys")]
     #[cfg_attr(docsrs, doc(cfg(feature = "sys")))]
     /// Get a raw reference to the wrapped Node-API value.
@@ -567,8 +905,10 @@ impl JsString {
 
     /// Convert this JavaScript string into a [`Vec<u16>`] encoded as UTF-16.
     ///
-    /// The returned vector is guaranteed to be valid UTF-16, so libraries that handle
-    /// UTF-16-encoded strings can assume the content to be valid.
+    /// The code units are copied exactly as they are stored by the JavaScript engine.
+    /// Unlike [`JsString::value`], unpaired surrogates (e.g., `"\uD800"`) are preserved
+    /// rather than replaced, so the string can be round-tripped with
+    /// [`JsString::from_utf16`].
     ///
     /// # Example
     ///
@@ -596,9 +936,9 @@ impl JsString {
     /// fn print_with_widestring(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     ///     let s = cx.argument::<JsString>(0)?.to_utf16(&mut cx);
     ///
-    ///     // The returned vector is guaranteed to be valid UTF-16, so we can
-    ///     // safely skip the validation step.
-    ///     let s = unsafe { Utf16String::from_vec_unchecked(s) };
+    ///     // The returned vector may contain unpaired surrogates, so it must
+    ///     // be validated.
+    ///     let s = Utf16String::from_vec(s).or_else(|err| cx.throw_error(err.to_string()))?;
     ///
     ///     println!("JavaScript string as UTF-16: {}", s);
     ///
@@ -617,6 +957,71 @@ impl JsString {
         }
     }
 
+    /// Convert this JavaScript string into a [`Vec<u8>`] encoded as
+    /// [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1) (ISO-8859-1).
+    ///
+    /// Each UTF-16 code unit is converted to a single byte, so the result always has
+    /// the same length as [`JsString::size_utf16`]. Characters outside of the Latin-1
+    /// range (greater than `U+00FF`) cannot be represented and are truncated to their
+    /// low byte; check the contents first if the string may contain them.
+    ///
+    /// This is the inverse of [`JsString::from_latin1`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use neon::prelude::*;
+    /// # fn string_to_latin1(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let str = cx.string("café");
+    /// assert_eq!(b"caf\xE9".to_vec(), str.to_latin1(&mut cx));
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn to_latin1<'a, C: Context<'a>>(&self, cx: &mut C) -> Vec<u8> {
+        let env = cx.env().to_raw();
+
+        unsafe {
+            let capacity = sys::string::utf16_len(env, self.to_local()) + 1;
+            let mut buffer: Vec<u8> = Vec::with_capacity(capacity);
+            let len = sys::string::data_latin1(env, buffer.as_mut_ptr(), capacity, self.to_local());
+            buffer.set_len(len);
+            buffer
+        }
+    }
+
+    /// Creates a [`JsStringReader`] for copying the UTF-8 contents of this string in
+    /// chunks into caller provided buffers, instead of allocating a single buffer for
+    /// the entire string as [`JsString::value`] does.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # use std::io::Write;
+    /// fn write_stdout(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let s = cx.argument::<JsString>(0)?;
+    ///     let mut reader = s.reader(&cx);
+    ///     let mut buf = [0; 64 * 1024];
+    ///     let mut stdout = std::io::stdout().lock();
+    ///
+    ///     loop {
+    ///         let n = reader.read(&mut cx, &mut buf)?;
+    ///
+    ///         if n == 0 {
+    ///             break;
+    ///         }
+    ///
+    ///         stdout.write_all(&buf[..n]).or_else(|err| cx.throw_error(err.to_string()))?;
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn reader<'a, C: Context<'a>>(&self, _cx: &C) -> JsStringReader<'a> {
+        JsStringReader {
+            string: Handle::new_internal(JsString(self.0)),
+            offset: 0,
+        }
+    }
+
     /// Creates a new `JsString` value from a Rust string by copying its contents.
     ///
     /// This method panics if the string is longer than the maximum string size allowed
@@ -668,6 +1073,142 @@ impl JsString {
         }
     }
 
+    /// Creates a new `JsString` value from a slice of UTF-16 code units by copying its
+    /// contents.
+    ///
+    /// The code units do not need to be valid UTF-16; unpaired surrogates are preserved.
+    /// This is the inverse of [`JsString::to_utf16`].
+    ///
+    /// This method panics if the string is longer than the maximum string size allowed
+    /// by the JavaScript engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn string_from_utf16(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// // A lone high surrogate
+    /// let str = JsString::from_utf16(&mut cx, &[0xD800]);
+    /// assert_eq!(vec![0xD800], str.to_utf16(&mut cx));
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> Handle<'a, JsString> {
+        JsString::try_from_utf16(cx, val).unwrap()
+    }
+
+    /// Tries to create a new `JsString` value from a slice of UTF-16 code units by
+    /// copying its contents.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    pub fn try_from_utf16<'a, C: Context<'a>>(cx: &mut C, val: &[u16]) -> StringResult<'a> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if sys::string::new_utf16(&mut local, cx.env().to_raw(), val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    /// Creates a new `JsString` value from a slice of
+    /// [Latin-1](https://en.wikipedia.org/wiki/ISO/IEC_8859-1) (ISO-8859-1) encoded bytes
+    /// by copying its contents.
+    ///
+    /// Each byte is converted to the character with the same code point, so every byte
+    /// sequence is valid and the string has exactly one character per byte. This is the
+    /// inverse of [`JsString::to_latin1`].
+    ///
+    /// This method panics if the string is longer than the maximum string size allowed
+    /// by the JavaScript engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// # fn string_from_latin1(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    /// let str = JsString::from_latin1(&mut cx, b"caf\xE9");
+    /// assert_eq!("café", str.value(&mut cx));
+    /// # Ok(cx.undefined())
+    /// # }
+    /// ```
+    pub fn from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> Handle<'a, JsString> {
+        JsString::try_from_latin1(cx, val).unwrap()
+    }
+
+    /// Tries to create a new `JsString` value from a slice of Latin-1 encoded bytes by
+    /// copying its contents.
+    ///
+    /// Returns `Err(StringOverflow)` if the string is longer than the maximum string size
+    /// allowed by the JavaScript engine.
+    pub fn try_from_latin1<'a, C: Context<'a>>(cx: &mut C, val: &[u8]) -> StringResult<'a> {
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            if sys::string::new_latin1(&mut local, cx.env().to_raw(), val.as_ptr(), val.len()) {
+                Ok(Handle::new_internal(JsString(local)))
+            } else {
+                Err(StringOverflow(val.len()))
+            }
+        }
+    }
+
+    /// Creates a new `JsString` by concatenating this string with `other`.
+    ///
+    /// The result is built from the UTF-16 contents of both strings, so unpaired
+    /// surrogates are preserved. Returns `Err(StringOverflow)` if the result is
+    /// longer than the maximum string size allowed by the JavaScript engine.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn greet(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let name = cx.argument::<JsString>(0)?;
+    ///     let hello = cx.string("Hello, ");
+    ///
+    ///     hello.concat(&mut cx, name).or_throw(&mut cx)
+    /// }
+    /// ```
+    pub fn concat<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        other: Handle<JsString>,
+    ) -> StringResult<'a> {
+        let mut units = self.to_utf16(cx);
+
+        units.extend(other.to_utf16(cx));
+        JsString::try_from_utf16(cx, &units)
+    }
+
+    /// Creates a new `JsString` by repeating this string `n` times.
+    ///
+    /// Returns `Err(StringOverflow)` if the result is longer than the maximum string
+    /// size allowed by the JavaScript engine. Oversized results are rejected before
+    /// anything is allocated.
+    pub fn repeat<'a, C: Context<'a>>(&self, cx: &mut C, n: usize) -> StringResult<'a> {
+        // Maximum length, in UTF-16 code units, of a V8 string (`v8::String::kMaxLength`)
+        #[cfg(target_pointer_width = "64")]
+        const MAX_LENGTH: usize = (1 << 29) - 24;
+        #[cfg(not(target_pointer_width = "64"))]
+        const MAX_LENGTH: usize = (1 << 28) - 16;
+
+        let len = self.size_utf16(cx);
+
+        let size = match len.checked_mul(n) {
+            Some(size) if size <= MAX_LENGTH => size,
+            _ => return Err(StringOverflow(len.saturating_mul(n))),
+        };
+
+        let units = self.to_utf16(cx);
+        let mut repeated = Vec::with_capacity(size);
+
+        for _ in 0..n {
+            repeated.extend_from_slice(&units);
+        }
+
+        JsString::try_from_utf16(cx, &repeated)
+    }
+
     pub(crate) fn new_internal<'a>(env: Env, val: &str) -> Option<Handle<'a, JsString>> {
         let (ptr, len) = if let Some(small) = Utf8::from(val).into_small() {
             small.lower()
@@ -686,6 +1227,73 @@ impl JsString {
     }
 }
 
+static STRING: Builtin = Builtin::new("String", &["slice"]);
+
+/// A reader over the UTF-8 contents of a [`JsString`], created by [`JsString::reader`].
+#[derive(Debug)]
+pub struct JsStringReader<'a> {
+    string: Handle<'a, JsString>,
+    // Position of the next unread character, in UTF-16 code units
+    offset: usize,
+}
+
+impl<'a> JsStringReader<'a> {
+    // The longest UTF-8 encoded character, plus the null terminator written by Node-API
+    const MIN_BUFFER_LEN: usize = 5;
+
+    /// Copies the next chunk of the string into `buf` as UTF-8, returning the number
+    /// of bytes written, or `0` once the entire string has been read.
+    ///
+    /// A chunk always ends on a character boundary, so `&buf[..n]` is valid UTF-8 and
+    /// multi-byte sequences are never split across chunks. As with
+    /// [`JsString::value`], unpaired surrogates are replaced with `U+FFFD`. Since
+    /// Node-API reserves a byte for a null terminator, at most `buf.len() - 1` bytes
+    /// are written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than 5 bytes, which may not be enough to make
+    /// progress on a character encoded as 4 bytes.
+    pub fn read<C: Context<'a>>(&mut self, cx: &mut C, buf: &mut [u8]) -> NeonResult<usize> {
+        assert!(
+            buf.len() >= Self::MIN_BUFFER_LEN,
+            "buffer must be at least {} bytes",
+            Self::MIN_BUFFER_LEN,
+        );
+
+        if self.offset >= self.string.size_utf16(cx) {
+            return Ok(0);
+        }
+
+        let rest = self.rest(cx)?;
+        let env = cx.env().to_raw();
+        let len = unsafe { sys::string::data(env, buf.as_mut_ptr(), buf.len(), rest.to_local()) };
+        let chunk = match std::str::from_utf8(&buf[..len]) {
+            Ok(chunk) => chunk,
+            Err(err) => unsafe { std::str::from_utf8_unchecked(&buf[..err.valid_up_to()]) },
+        };
+
+        self.offset += chunk.chars().map(char::len_utf16).sum::<usize>();
+
+        Ok(chunk.len())
+    }
+
+    // Node-API can only copy a string from its start; slice off the part that has
+    // already been read
+    fn rest<C: Context<'a>>(&self, cx: &mut C) -> JsResult<'a, JsString> {
+        if self.offset == 0 {
+            return Ok(self.string);
+        }
+
+        let start = cx.number(self.offset as f64).upcast();
+
+        STRING
+            .method(cx, "slice")?
+            .call(cx, self.string, [start])?
+            .downcast_or_throw(cx)
+    }
+}
+
 /// The type of JavaScript
 /// [number](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#primitive_values)
 /// primitives.
@@ -732,29 +1340,145 @@ impl JsNumber {
         let env = cx.env().to_raw();
         unsafe { sys::primitive::number_value(env, self.to_local()) }
     }
-}
-
-impl Value for JsNumber {}
-
-unsafe impl TransparentNoCopyWrapper for JsNumber {
-    type Inner = raw::Local;
-
-    fn into_inner(self) -> Self::Inner {
-        self.0
-    }
-}
 
-impl ValueInternal for JsNumber {
-    fn name() -> &'static str {
-        "number"
+    /// Returns the value of this number converted to an `i32` with JavaScript's
+    /// [`ToInt32`](https://tc39.es/ecma262/#sec-toint32) operation, e.g. `n | 0`.
+    ///
+    /// Fractional parts are truncated, out of range values wrap, and non-finite
+    /// values produce `0`.
+    pub fn value_i32<'a, C: Context<'a>>(&self, cx: &mut C) -> i32 {
+        let env = cx.env().to_raw();
+        unsafe { sys::primitive::number_value_i32(env, self.to_local()) }
     }
 
-    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
-        unsafe { sys::tag::is_number(env.to_raw(), other.to_local()) }
+    /// Returns the value of this number converted to a `u32` with JavaScript's
+    /// [`ToUint32`](https://tc39.es/ecma262/#sec-touint32) operation, e.g. `n >>> 0`.
+    ///
+    /// Fractional parts are truncated, out of range values wrap, and non-finite
+    /// values produce `0`.
+    pub fn value_u32<'a, C: Context<'a>>(&self, cx: &mut C) -> u32 {
+        let env = cx.env().to_raw();
+        unsafe { sys::primitive::number_value_u32(env, self.to_local()) }
     }
 
-    fn to_local(&self) -> raw::Local {
-        self.0
+    /// Returns the value of this number truncated to an `i64`.
+    ///
+    /// Non-finite values produce `0` and values outside the range of `i64` saturate.
+    /// Integers outside of the safe integer range may have already lost precision;
+    /// prefer [`JsNumber::try_value_i64`] when truncation would be a bug.
+    pub fn value_i64<'a, C: Context<'a>>(&self, cx: &mut C) -> i64 {
+        let env = cx.env().to_raw();
+        unsafe { sys::primitive::number_value_i64(env, self.to_local()) }
+    }
+
+    /// Returns the value of this number as an `i64` if it is a
+    /// [safe integer](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger).
+    ///
+    /// Returns `Err(IntegerError)` if the number has a fractional part, is not
+    /// finite, or is outside the range `-(2^53 - 1)..=2^53 - 1`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn user_id(mut cx: FunctionContext) -> JsResult<JsString> {
+    ///     let id = cx.argument::<JsNumber>(0)?;
+    ///     let id = id.try_value_i64(&mut cx).or_throw(&mut cx)?;
+    ///
+    ///     Ok(cx.string(format!("user-{id}")))
+    /// }
+    /// ```
+    pub fn try_value_i64<'a, C: Context<'a>>(&self, cx: &mut C) -> Result<i64, IntegerError> {
+        let value = self.value(cx);
+
+        if is_safe_integer(value) {
+            Ok(value as i64)
+        } else {
+            Err(IntegerError(value))
+        }
+    }
+
+    /// Returns `true` if this number is an integer, equivalent to
+    /// [`Number.isInteger`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Number/isInteger).
+    ///
+    /// Integers too large to be represented exactly, e.g., `2 ** 64`, are included.
+    pub fn is_integer<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        let value = self.value(cx);
+
+        value.is_finite() && value.trunc() == value
+    }
+
+    /// Returns `true` if this number is an integer that can be represented exactly,
+    /// equivalent to
+    /// [`Number.isSafeInteger`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger).
+    ///
+    /// **See also:** [`JsNumber::try_value_i64`]
+    pub fn is_safe_integer<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        is_safe_integer(self.value(cx))
+    }
+
+    /// Returns `true` if this number is `NaN`.
+    pub fn is_nan<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        self.value(cx).is_nan()
+    }
+
+    /// Returns `true` if this number is neither infinite nor `NaN`, equivalent to
+    /// [`Number.isFinite`](https://developer.mozilla.org/docs/Web/JavaScript/Reference/Global_Objects/Number/isFinite).
+    pub fn is_finite<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
+        self.value(cx).is_finite()
+    }
+}
+
+fn is_safe_integer(value: f64) -> bool {
+    const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+    value.trunc() == value && value.abs() <= MAX_SAFE_INTEGER
+}
+
+/// An error produced when a number is not a safe integer.
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug)]
+pub struct IntegerError(f64);
+
+impl IntegerError {
+    /// Returns the number that could not be converted.
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+}
+
+impl fmt::Display for IntegerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "number is not a safe integer: {}", self.0)
+    }
+}
+
+impl std::error::Error for IntegerError {}
+
+impl<T> ResultExt<T> for Result<T, IntegerError> {
+    fn or_throw<'a, C: Context<'a>>(self, cx: &mut C) -> NeonResult<T> {
+        self.or_else(|err| cx.throw_range_error(err.to_string()))
+    }
+}
+
+impl Value for JsNumber {}
+
+unsafe impl TransparentNoCopyWrapper for JsNumber {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsNumber {
+    fn name() -> &'static str {
+        "number"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_number(env.to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
     }
 
     unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
@@ -762,6 +1486,110 @@ impl ValueInternal for JsNumber {
     }
 }
 
+/// The type of JavaScript
+/// [`Symbol`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol)
+/// primitives.
+///
+/// A handle to a symbol may be used as a [`PropertyKey`](crate::object::PropertyKey)
+/// to get and set properties of objects.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// # use neon::types::JsSymbol;
+/// // Get the iterator function of an iterable object, i.e., `obj[Symbol.iterator]`
+/// fn get_iterator(mut cx: FunctionContext) -> JsResult<JsFunction> {
+///     let obj = cx.argument::<JsObject>(0)?;
+///     let iterator = JsSymbol::well_known(&mut cx, "iterator")?;
+///
+///     obj.get(&mut cx, iterator)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSymbol(raw::Local);
+
+impl JsSymbol {
+    /// Creates a new unique symbol with an optional description, equivalent to the
+    /// JavaScript expression `Symbol(description)`.
+    pub fn new<'a, C: Context<'a>>(
+        cx: &mut C,
+        description: Option<Handle<JsString>>,
+    ) -> Handle<'a, JsSymbol> {
+        let description = description.map_or(std::ptr::null_mut(), |s| s.to_local());
+
+        unsafe {
+            let mut local: raw::Local = std::mem::zeroed();
+            sys::primitive::symbol(&mut local, cx.env().to_raw(), description);
+            Handle::new_internal(JsSymbol(local))
+        }
+    }
+
+    /// Gets the symbol registered for `key` in the global symbol registry, creating
+    /// it if it does not exist, equivalent to the JavaScript expression
+    /// [`Symbol.for(key)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for).
+    pub fn for_key<'a, C: Context<'a>>(cx: &mut C, key: &str) -> JsResult<'a, JsSymbol> {
+        let symbol = cx.global::<JsFunction>("Symbol")?;
+        let symbol_for = symbol.get::<JsFunction, _, _>(cx, "for")?;
+        let key = cx.string(key).upcast();
+
+        symbol_for.call(cx, symbol, [key])?.downcast_or_throw(cx)
+    }
+
+    /// Gets a [well-known symbol](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol#well-known_symbols)
+    /// by name, e.g., `"iterator"` for `Symbol.iterator`.
+    ///
+    /// Throws a `TypeError` if `name` is not a well-known symbol.
+    pub fn well_known<'a, C: Context<'a>>(cx: &mut C, name: &str) -> JsResult<'a, JsSymbol> {
+        cx.global::<JsFunction>("Symbol")?.get(cx, name)
+    }
+
+    /// Returns the description of this symbol, or `None` if it was created without
+    /// a description, equivalent to the JavaScript expression
+    /// [`symbol.description`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/description).
+    pub fn description<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Option<String>> {
+        let env = cx.env();
+        let (ptr, len) = Utf8::from("description").into_small_unwrap().lower();
+        let description: Handle<JsValue> = build(env, |out| unsafe {
+            sys::object::get_string(env.to_raw(), out, self.to_local(), ptr, len)
+        })?;
+
+        match description.downcast::<JsString, _>(cx) {
+            Ok(s) => Ok(Some(s.value(cx))),
+            Err(_) => Ok(None),
+        }
+    }
+}
+
+impl Value for JsSymbol {}
+
+unsafe impl TransparentNoCopyWrapper for JsSymbol {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSymbol {
+    fn name() -> &'static str {
+        "symbol"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        unsafe { sys::tag::is_symbol(env.to_raw(), other.to_local()) }
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSymbol(h)
+    }
+}
+
 /// The type of JavaScript
 /// [objects](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#objects),
 /// i.e., the root of all object types.
@@ -831,6 +1659,40 @@ impl JsObject {
         JsObject::new_internal(c.env())
     }
 
+    /// Creates a new object with the properties in `entries`, similar to the
+    /// JavaScript expression
+    /// [`Object.fromEntries(entries)`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries).
+    ///
+    /// Properties are set in order, so a later entry overwrites an earlier entry
+    /// with the same key.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn point(mut cx: FunctionContext) -> JsResult<JsObject> {
+    ///     let x = cx.number(1);
+    ///     let y = cx.number(2);
+    ///
+    ///     JsObject::from_entries(&mut cx, &[("x", x), ("y", y)])
+    /// }
+    /// ```
+    pub fn from_entries<'a, C, K, V>(
+        cx: &mut C,
+        entries: &[(K, Handle<V>)],
+    ) -> JsResult<'a, JsObject>
+    where
+        C: Context<'a>,
+        K: PropertyKey + Copy,
+        V: Value,
+    {
+        let obj = JsObject::new(cx);
+
+        for (key, value) in entries {
+            obj.set(cx, *key, *value)?;
+        }
+
+        Ok(obj)
+    }
+
     pub(crate) fn new_internal<'a>(env: Env) -> Handle<'a, JsObject> {
         JsObject::build(|out| unsafe { sys::object::new(out, env.to_raw()) })
     }
@@ -898,6 +1760,97 @@ impl JsArray {
         }
     }
 
+    /// Constructs a new array containing the elements of `values`, equivalent to the
+    /// JavaScript expression `[a, b, c]`.
+    ///
+    /// Throws a `RangeError` if `values` is longer than the maximum array length.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn pair(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let a = cx.string("hello");
+    ///     let b = cx.number(42).upcast::<JsValue>();
+    ///
+    ///     JsArray::from_slice(&mut cx, &[a.upcast(), b])
+    /// }
+    /// ```
+    pub fn from_slice<'a, C: Context<'a>, V: Value>(
+        cx: &mut C,
+        values: &[Handle<V>],
+    ) -> JsResult<'a, JsArray> {
+        if u32::try_from(values.len()).is_err() {
+            return cx.throw_range_error("Invalid array length");
+        }
+
+        let array = JsArray::new(cx, values.len());
+
+        array.set_all(cx.env(), 0, values)?;
+
+        Ok(array)
+    }
+
+    /// Copies `values` into the array starting at index `start`, extending the
+    /// array if necessary.
+    ///
+    /// Throws a `RangeError` if the last index would exceed the maximum array length.
+    /// The bounds are only checked once, making this faster than calling
+    /// [`Object::set`] for each element when filling large arrays.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn squares(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let n = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    ///     let squares = (0..n)
+    ///         .map(|i| cx.number((i * i) as f64))
+    ///         .collect::<Vec<_>>();
+    ///     let array = JsArray::new(&mut cx, n);
+    ///
+    ///     array.copy_from(&mut cx, 0, &squares)?;
+    ///
+    ///     Ok(array)
+    /// }
+    /// ```
+    pub fn copy_from<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        start: u32,
+        values: &[Handle<V>],
+    ) -> NeonResult<()> {
+        let end = u32::try_from(values.len())
+            .ok()
+            .and_then(|len| start.checked_add(len));
+
+        if end.is_none() {
+            return cx.throw_range_error("Invalid array length");
+        }
+
+        self.set_all(cx.env(), start, values)
+    }
+
+    // Sets each value at consecutive indices from `start`. Callers must ensure the
+    // indices do not overflow.
+    fn set_all<V: Value>(&self, env: Env, start: u32, values: &[Handle<V>]) -> NeonResult<()> {
+        let env = env.to_raw();
+
+        for (i, value) in values.iter().enumerate() {
+            unsafe {
+                let mut ok = false;
+
+                if !sys::object::set_index(
+                    &mut ok,
+                    env,
+                    self.to_local(),
+                    start + i as u32,
+                    value.to_local(),
+                ) {
+                    return Err(Throw::new());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Copies the array contents into a new [`Vec`] by iterating through all indices
     /// from 0 to `self.len()`.
     ///
@@ -917,6 +1870,197 @@ impl JsArray {
         }
     }
 
+    /// Copies the array contents into a new [`Vec`], downcasting each element to `V`.
+    ///
+    /// Throws a `TypeError` identifying the index of the first element that is not
+    /// of type `V`. As with [`JsArray::to_vec`], the length is re-checked on each
+    /// iteration in case the array is modified.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let nums = cx.argument::<JsArray>(0)?;
+    ///     let sum = nums
+    ///         .to_typed_vec::<JsNumber, _>(&mut cx)?
+    ///         .into_iter()
+    ///         .fold(0.0, |sum, n| sum + n.value(&mut cx));
+    ///
+    ///     Ok(cx.number(sum))
+    /// }
+    /// ```
+    pub fn to_typed_vec<'a, V: Value, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+    ) -> NeonResult<Vec<Handle<'a, V>>> {
+        let mut result = Vec::with_capacity(self.len_inner(cx.env()) as usize);
+        let mut i = 0;
+        loop {
+            // Since getting a property can trigger arbitrary code,
+            // we have to re-check the length on every iteration.
+            if i >= self.len_inner(cx.env()) {
+                return Ok(result);
+            }
+
+            let v: Handle<JsValue> = self.get(cx, i)?;
+
+            match v.downcast::<V, _>(cx) {
+                Ok(v) => result.push(v),
+                Err(err) => return cx.throw_type_error(format!("element {i}: {err}")),
+            }
+
+            i += 1;
+        }
+    }
+
+    /// Checks whether every element of the array is of type `T`, stopping at the
+    /// first element that is not.
+    ///
+    /// This is a cheaper guard than downcasting each element of [`JsArray::to_vec`]
+    /// since no intermediate [`Vec`] of handles is materialized. As with `to_vec`,
+    /// the length is re-checked on each iteration in case the array is modified.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    ///     let nums = cx.argument::<JsArray>(0)?;
+    ///
+    ///     if !nums.all_of::<JsNumber, _>(&mut cx)? {
+    ///         return cx.throw_type_error("expected an array of numbers");
+    ///     }
+    ///
+    ///     let mut sum = 0.0;
+    ///
+    ///     for n in nums.to_vec(&mut cx)? {
+    ///         sum += n.downcast_or_throw::<JsNumber, _>(&mut cx)?.value(&mut cx);
+    ///     }
+    ///
+    ///     Ok(cx.number(sum))
+    /// }
+    /// ```
+    pub fn all_of<'a, T: Value, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<bool> {
+        let env = cx.env();
+        let mut i = 0;
+
+        // Since getting a property can trigger arbitrary code,
+        // we have to re-check the length on every iteration.
+        while i < self.len_inner(env) {
+            let v = unsafe {
+                let mut local: raw::Local = std::mem::zeroed();
+
+                if !sys::object::get_index(&mut local, env.to_raw(), self.to_local(), i) {
+                    return Err(Throw::new());
+                }
+
+                JsValue::from_local(env, local)
+            };
+
+            if !T::is_typeof(env, &v) {
+                return Ok(false);
+            }
+
+            i += 1;
+        }
+
+        Ok(true)
+    }
+
+    /// Returns a new array containing the elements of this array followed by the
+    /// elements of each array in `others`, equivalent to the JavaScript
+    /// expression [`Array.prototype.concat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/concat).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn merge(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let a = cx.argument::<JsArray>(0)?;
+    ///     let b = cx.argument::<JsArray>(1)?;
+    ///
+    ///     a.concat(&mut cx, &[b])
+    /// }
+    /// ```
+    pub fn concat<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        others: &[Handle<JsArray>],
+    ) -> JsResult<'a, JsArray> {
+        let args = others
+            .iter()
+            .map(|other| other.upcast::<JsValue>())
+            .collect::<Vec<_>>();
+
+        self.call_prototype_method(cx, "concat", &args)
+    }
+
+    /// Returns a new array with sub-array elements flattened up to `depth` levels,
+    /// equivalent to the JavaScript expression
+    /// [`Array.prototype.flat`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/flat).
+    ///
+    /// Empty slots of sparse arrays are removed.
+    pub fn flat<'a, C: Context<'a>>(&self, cx: &mut C, depth: u32) -> JsResult<'a, JsArray> {
+        let depth = cx.number(depth).upcast();
+
+        self.call_prototype_method(cx, "flat", &[depth])
+    }
+
+    /// Sorts the array in place with a Rust comparator.
+    ///
+    /// The elements are first copied into a [`Vec`], as with [`JsArray::to_vec`], then
+    /// sorted and written back to the array. The comparator may call into JavaScript,
+    /// e.g., to read a property of each element; changes made to the array while sorting
+    /// do not affect the result and are overwritten when the elements are written back.
+    /// Holes in sparse arrays are read, and written back, as `undefined`.
+    ///
+    /// The sort is stable. If the comparator returns an error, sorting stops and the
+    /// array is left unmodified.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Sorts an array of objects by their numeric `priority` property
+    /// fn sort_by_priority(mut cx: FunctionContext) -> JsResult<JsArray> {
+    ///     let tasks = cx.argument::<JsArray>(0)?;
+    ///
+    ///     tasks.sort_by(&mut cx, |cx, a, b| {
+    ///         let a = a.downcast_or_throw::<JsObject, _>(cx)?;
+    ///         let a = a.get::<JsNumber, _, _>(cx, "priority")?.value(cx);
+    ///         let b = b.downcast_or_throw::<JsObject, _>(cx)?;
+    ///         let b = b.get::<JsNumber, _, _>(cx, "priority")?.value(cx);
+    ///
+    ///         Ok(a.total_cmp(&b))
+    ///     })?;
+    ///
+    ///     Ok(tasks)
+    /// }
+    /// ```
+    pub fn sort_by<'a, C, F>(&self, cx: &mut C, mut cmp: F) -> NeonResult<()>
+    where
+        C: Context<'a>,
+        F: FnMut(&mut C, Handle<'a, JsValue>, Handle<'a, JsValue>) -> NeonResult<Ordering>,
+    {
+        let mut values = self.to_vec(cx)?;
+        let mut buf = Vec::with_capacity(values.len());
+
+        merge_sort_by(&mut values, &mut buf, &mut |a, b| cmp(cx, a, b))?;
+
+        self.set_all(cx.env(), 0, &values)
+    }
+
+    // Calls a method from `Array.prototype` on this array. The method is read from
+    // the global `Array` instead of `self` so that it cannot be overridden.
+    fn call_prototype_method<'a, 'b, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        method: &str,
+        args: &[Handle<'b, JsValue>],
+    ) -> JsResult<'a, JsArray> {
+        let method = cx
+            .global::<JsFunction>("Array")?
+            .get::<JsObject, _, _>(cx, "prototype")?
+            .get::<JsFunction, _, _>(cx, method)?;
+
+        let this = self.as_value(cx);
+
+        method.call(cx, this, args)?.downcast_or_throw(cx)
+    }
+
     fn len_inner(&self, env: Env) -> u32 {
         unsafe { sys::array::len(env.to_raw(), self.to_local()) }
     }
@@ -933,6 +2077,106 @@ impl JsArray {
     pub fn is_empty<'a, C: Context<'a>>(&self, cx: &mut C) -> bool {
         self.len(cx) == 0
     }
+
+    /// Returns an iterator over the elements of the array that reads each element
+    /// lazily, without materializing a [`Vec`] like [`JsArray::to_vec`].
+    ///
+    /// Since reading an element requires a [`Context`], the iterator does not
+    /// implement [`Iterator`]. Instead, elements are read with
+    /// [`JsArrayIter::try_next`], leaving the context free to use between elements.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// // Finds the index of the first string in an array
+    /// fn find_string(mut cx: FunctionContext) -> JsResult<JsValue> {
+    ///     let array = cx.argument::<JsArray>(0)?;
+    ///     let mut iter = array.iter(&cx);
+    ///     let mut i = 0;
+    ///
+    ///     while let Some(v) = iter.try_next(&mut cx)? {
+    ///         if v.is_a::<JsString, _>(&mut cx) {
+    ///             return Ok(cx.number(i).upcast());
+    ///         }
+    ///
+    ///         i += 1;
+    ///     }
+    ///
+    ///     Ok(cx.undefined().upcast())
+    /// }
+    /// ```
+    pub fn iter<'a, C: Context<'a>>(&self, _cx: &C) -> JsArrayIter<'a> {
+        JsArrayIter {
+            array: Handle::new_internal(JsArray(self.0)),
+            index: 0,
+        }
+    }
+}
+
+// A stable merge sort that, unlike `slice::sort_by`, stops at the first error and
+// does not panic if the comparator is not a total order
+fn merge_sort_by<T, E, F>(items: &mut [T], buf: &mut Vec<T>, cmp: &mut F) -> Result<(), E>
+where
+    T: Copy,
+    F: FnMut(T, T) -> Result<Ordering, E>,
+{
+    if items.len() <= 1 {
+        return Ok(());
+    }
+
+    let mid = items.len() / 2;
+
+    merge_sort_by(&mut items[..mid], buf, cmp)?;
+    merge_sort_by(&mut items[mid..], buf, cmp)?;
+
+    let (mut i, mut j) = (0, mid);
+
+    buf.clear();
+
+    while i < mid && j < items.len() {
+        // Prefer the left element when equal to keep the sort stable
+        if cmp(items[j], items[i])? == Ordering::Less {
+            buf.push(items[j]);
+            j += 1;
+        } else {
+            buf.push(items[i]);
+            i += 1;
+        }
+    }
+
+    buf.extend_from_slice(&items[i..mid]);
+    buf.extend_from_slice(&items[j..]);
+    items.copy_from_slice(buf);
+
+    Ok(())
+}
+
+/// An iterator over the elements of a [`JsArray`], created by [`JsArray::iter`].
+#[derive(Debug)]
+pub struct JsArrayIter<'a> {
+    array: Handle<'a, JsArray>,
+    index: u32,
+}
+
+impl<'a> JsArrayIter<'a> {
+    /// Reads the next element of the array, or returns `None` after the last element.
+    ///
+    /// As with [`JsArray::to_vec`], the length of the array is re-checked on each
+    /// call in case the array is modified during iteration. Propagates exceptions
+    /// thrown by element getters.
+    pub fn try_next<C: Context<'a>>(
+        &mut self,
+        cx: &mut C,
+    ) -> NeonResult<Option<Handle<'a, JsValue>>> {
+        if self.index >= self.array.len_inner(cx.env()) {
+            return Ok(None);
+        }
+
+        let v = self.array.get(cx, self.index)?;
+
+        self.index += 1;
+
+        Ok(Some(v))
+    }
 }
 
 impl Value for JsArray {}
@@ -1080,6 +2324,24 @@ impl JsFunction {
 
     #[cfg(feature = "napi-5")]
     /// Returns a new `JsFunction` implemented by `f`.
+    ///
+    /// `f` may be a closure that captures Rust state. The closure is owned by the
+    /// function and dropped when the function is garbage collected, so it must be
+    /// `'static`; captured JavaScript values should be held in a
+    /// [`Root`](crate::handle::Root).
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn make_greeter(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    ///     let greeting = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///     JsFunction::new(&mut cx, move |mut cx| {
+    ///         let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    ///
+    ///         Ok(cx.string(format!("{greeting}, {name}!")))
+    ///     })
+    /// }
+    /// ```
     pub fn new<'a, C, F, V>(cx: &mut C, f: F) -> JsResult<'a, JsFunction>
     where
         C: Context<'a>,
@@ -1093,6 +2355,10 @@ impl JsFunction {
 
     #[cfg(not(feature = "napi-5"))]
     /// Returns a new `JsFunction` implemented by `f` with specified name
+    ///
+    /// The name is visible from JavaScript as the function's `name` property.
+    /// Functions created with [`JsFunction::new`] are named after the Rust type
+    /// of `f`.
     pub fn with_name<'a, C, U>(
         cx: &mut C,
         name: &str,
@@ -1107,6 +2373,10 @@ impl JsFunction {
 
     #[cfg(feature = "napi-5")]
     /// Returns a new `JsFunction` implemented by `f` with specified name
+    ///
+    /// The name is visible from JavaScript as the function's `name` property.
+    /// Functions created with [`JsFunction::new`] are named after the Rust type
+    /// of `f`.
     pub fn with_name<'a, C, F, V>(cx: &mut C, name: &str, f: F) -> JsResult<'a, JsFunction>
     where
         C: Context<'a>,
@@ -1155,7 +2425,27 @@ impl JsFunction {
 }
 
 impl JsFunction {
-    /// Calls this function.
+    /// Calls this function with an explicit `this` binding and a slice of arguments,
+    /// returning the result as a [`JsValue`].
+    ///
+    /// Unlike [`CallOptions::apply`](function::CallOptions::apply), the result is not
+    /// downcast, which avoids a redundant type check in hot paths where the caller
+    /// only needs a `JsValue` or performs its own downcast.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn call_each(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let f = cx.argument::<JsFunction>(0)?;
+    ///     let this = cx.undefined();
+    ///
+    ///     for i in 0..1000 {
+    ///         let arg = cx.number(i).upcast();
+    ///         f.call(&mut cx, this, [arg])?;
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
     ///
     /// **See also:** [`JsFunction::call_with`].
     pub fn call<'a, 'b, C: Context<'a>, T, AS>(
@@ -1209,6 +2499,51 @@ impl JsFunction {
             sys::fun::construct(out, env, self.to_local(), argc, argv)
         })
     }
+
+    /// Returns the name of the function, as reported by its
+    /// [`name`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/name)
+    /// property.
+    ///
+    /// The value is returned exactly as JavaScript reports it, e.g., `"bound f"` for a
+    /// bound function and `""` for an anonymous function. Throws a `TypeError` if the
+    /// property has been redefined to a value that is not a string.
+    pub fn name<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<String> {
+        let name = self.get::<JsString, _, _>(cx, "name")?;
+
+        Ok(name.value(cx))
+    }
+
+    /// Returns the number of parameters expected by the function, as reported by its
+    /// [`length`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/length)
+    /// property.
+    ///
+    /// As in JavaScript, rest parameters and parameters after the first one with a
+    /// default value are not counted. Throws a `TypeError` if the property has been
+    /// redefined to a value that is not a number, or a `RangeError` if it has been
+    /// redefined to a number that is not an integer between `0` and `u32::MAX`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn register_hook(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    ///     let hook = cx.argument::<JsFunction>(0)?;
+    ///
+    ///     if hook.length(&mut cx)? != 2 {
+    ///         let name = hook.name(&mut cx)?;
+    ///
+    ///         eprintln!("Warning: hook `{name}` should accept two arguments");
+    ///     }
+    ///
+    ///     Ok(cx.undefined())
+    /// }
+    /// ```
+    pub fn length<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<u32> {
+        let length = self.get::<JsNumber, _, _>(cx, "length")?;
+
+        match length.try_value_i64(cx).map(u32::try_from) {
+            Ok(Ok(length)) => Ok(length),
+            _ => cx.throw_range_error("Function length must be an integer between 0 and 2^32 - 1"),
+        }
+    }
 }
 
 impl JsFunction {
@@ -1225,6 +2560,20 @@ impl JsFunction {
         }
     }
 
+    /// Create a [`BindOptions`](function::BindOptions) for binding `this` and leading
+    /// arguments to this function.
+    pub fn bind<'a, C: Context<'a>>(&self, _cx: &C) -> BindOptions<'a> {
+        BindOptions {
+            // # Safety
+            // Only a single context may be used at a time because parent scopes
+            // are locked with `&mut self`. Therefore, the lifetime of `BindOptions`
+            // will always be the most narrow scope possible.
+            callee: Handle::new_internal(unsafe { self.clone() }),
+            this: None,
+            args: smallvec![],
+        }
+    }
+
     /// Create a [`ConstructOptions`](function::ConstructOptions) for calling this function
     /// as a constructor.
     pub fn construct_with<'a, C: Context<'a>>(&self, _cx: &C) -> ConstructOptions<'a> {
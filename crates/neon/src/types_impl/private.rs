@@ -30,3 +30,9 @@ pub trait ValueInternal: TransparentNoCopyWrapper + 'static {
     // JavaScript value must be of type `Self`
     unsafe fn from_local(env: Env, h: raw::Local) -> Self;
 }
+
+pub trait CoerceInternal: ValueInternal {
+    // # Safety
+    // `env` and `value` must be valid for the current scope
+    unsafe fn coerce(out: &mut raw::Local, env: raw::Env, value: raw::Local) -> bool;
+}
@@ -6,7 +6,7 @@ use crate::{
     object::Object,
     result::JsResult,
     sys::{self, no_panic::FailureBoundary, raw},
-    types::{private::ValueInternal, Value},
+    types::{private::ValueInternal, JsFunction, JsValue, Value},
 };
 
 #[cfg(feature = "napi-4")]
@@ -26,7 +26,6 @@ use {
     crate::context::internal::ContextInternal,
     crate::event::{JoinError, SendThrow},
     crate::result::NeonResult,
-    crate::types::{JsFunction, JsValue},
     std::future::Future,
     std::pin::Pin,
     std::sync::Mutex,
@@ -164,6 +163,70 @@ impl JsPromise {
         promise
     }
 
+    /// Attaches callbacks for the resolution and/or rejection of the `Promise`, equivalent
+    /// to the JavaScript expression `promise.then(onFulfilled, onRejected)`. Returns the
+    /// chained `Promise`, which settles with the result of whichever callback is called.
+    ///
+    /// As in JavaScript, if `on_rejected` is `None`, a rejection is forwarded unchanged
+    /// to the chained `Promise`.
+    ///
+    /// ```
+    /// # use neon::prelude::*;
+    /// fn log_result(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    ///     let promise = cx.argument::<JsPromise>(0)?;
+    ///     let on_fulfilled = JsFunction::new(&mut cx, |mut cx| {
+    ///         let value = cx.argument::<JsValue>(0)?;
+    ///         let value = value.to_string(&mut cx)?.value(&mut cx);
+    ///
+    ///         println!("Resolved: {value}");
+    ///
+    ///         Ok(cx.undefined())
+    ///     })?;
+    ///
+    ///     promise.then(&mut cx, on_fulfilled, None)
+    /// }
+    /// ```
+    pub fn then<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        on_fulfilled: Handle<JsFunction>,
+        on_rejected: Option<Handle<JsFunction>>,
+    ) -> JsResult<'a, JsPromise> {
+        let on_rejected = match on_rejected {
+            Some(f) => f.upcast(),
+            None => cx.undefined().upcast(),
+        };
+
+        self.chain(cx, on_fulfilled.upcast(), on_rejected)
+    }
+
+    /// Attaches a callback for the rejection of the `Promise`, equivalent to the
+    /// JavaScript expression `promise.catch(onRejected)`. Returns the chained `Promise`,
+    /// which resolves with the original value if the `Promise` is fulfilled or settles
+    /// with the result of `on_rejected` otherwise.
+    pub fn catch<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        on_rejected: Handle<JsFunction>,
+    ) -> JsResult<'a, JsPromise> {
+        let on_fulfilled = cx.undefined().upcast();
+
+        self.chain(cx, on_fulfilled, on_rejected.upcast())
+    }
+
+    fn chain<'a, 'b, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        on_fulfilled: Handle<'b, JsValue>,
+        on_rejected: Handle<'b, JsValue>,
+    ) -> JsResult<'a, JsPromise> {
+        let then = self.get::<JsFunction, _, _>(cx, "then")?;
+        let this = Handle::new_internal(Self(self.0));
+
+        then.call(cx, this, [on_fulfilled, on_rejected])?
+            .downcast_or_throw(cx)
+    }
+
     #[cfg(all(feature = "napi-5", feature = "futures"))]
     #[cfg_attr(docsrs, doc(cfg(all(feature = "napi-5", feature = "futures"))))]
     /// Creates a [`Future`](std::future::Future) that can be awaited to receive the result of a
@@ -275,13 +338,40 @@ impl Object for JsPromise {}
 /// It is recommended to settle a [`Deferred`] with [`Deferred::settle_with`] to ensure
 /// exceptions are caught.
 ///
+/// A [`Deferred`] is `Send` and may be moved to another thread to settle the
+/// promise later with [`Deferred::settle_with`]. The closure is executed on the
+/// JavaScript main thread; returning `Ok` resolves the promise and returning
+/// `Err` (e.g., with [`Context::throw_error`]) rejects it.
+///
 /// On Node-API versions less than 6, dropping a [`Deferred`] without settling will
 /// cause a panic. On Node-API 6+, the associated [`JsPromise`] will be automatically
-/// rejected.
+/// rejected with an error stating that the `Deferred` was dropped without being settled.
 ///
 /// # Examples
 ///
-/// See [`JsPromise`], [`JsFuture`].
+/// ```
+/// # use neon::prelude::*;
+/// # fn checksum(_: &str) -> Result<u32, String> { todo!() }
+/// # #[cfg(feature = "napi-4")]
+/// fn checksum_async(mut cx: FunctionContext) -> JsResult<JsPromise> {
+///     let path = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let channel = cx.channel();
+///     let (deferred, promise) = cx.promise();
+///
+///     std::thread::spawn(move || {
+///         let result = checksum(&path);
+///
+///         deferred.settle_with(&channel, move |mut cx| match result {
+///             Ok(sum) => Ok(cx.number(sum)),
+///             Err(msg) => cx.throw_error(msg),
+///         });
+///     });
+///
+///     Ok(promise)
+/// }
+/// ```
+///
+/// See also [`JsPromise`], [`JsFuture`].
 pub struct Deferred {
     internal: Option<NodeApiDeferred>,
     #[cfg(feature = "napi-6")]
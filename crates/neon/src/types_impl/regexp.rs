@@ -0,0 +1,154 @@
+use super::{builtin::Builtin, private::ValueInternal, Value};
+
+use crate::{
+    context::{internal::Env, Context},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::raw,
+    types::{JsArray, JsBoolean, JsString, JsValue},
+};
+
+static REGEXP: Builtin = Builtin::new("RegExp", &["test", "exec"]);
+
+/// The type of JavaScript
+/// [`RegExp`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp)
+/// objects.
+///
+/// Patterns are compiled and matched by the JavaScript engine, so they have exactly
+/// the syntax and semantics of JavaScript regular expressions, including flags such as
+/// `u` (Unicode) and `i` (case-insensitive).
+///
+/// Node-API does not provide direct access to `RegExp` objects. Operations call the
+/// methods of `RegExp.prototype`, which are looked up once per module instance on
+/// Node-API 6 or later. A value is considered a `JsRegExp` if it is an `instanceof`
+/// the global `RegExp` class.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsRegExp;
+///
+/// // Returns the strings that match a user supplied pattern
+/// fn filter(mut cx: FunctionContext) -> JsResult<JsArray> {
+///     let pattern = cx.argument::<JsString>(0)?.value(&mut cx);
+///     let strings = cx.argument::<JsArray>(1)?.to_vec(&mut cx)?;
+///     let re = JsRegExp::new(&mut cx, &pattern, "u")?;
+///     let matches = cx.empty_array();
+///
+///     for s in strings {
+///         let s = s.downcast_or_throw::<JsString, _>(&mut cx)?;
+///
+///         if re.test(&mut cx, s)? {
+///             let len = matches.len(&mut cx);
+///
+///             matches.set(&mut cx, len, s)?;
+///         }
+///     }
+///
+///     Ok(matches)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsRegExp(raw::Local);
+
+impl JsRegExp {
+    /// Compiles a regular expression, equivalent to the JavaScript expression
+    /// `new RegExp(source, flags)`.
+    ///
+    /// Throws a `SyntaxError` if `source` is not a valid pattern or `flags` contains
+    /// an invalid or repeated flag.
+    pub fn new<'a, C: Context<'a>>(
+        cx: &mut C,
+        source: &str,
+        flags: &str,
+    ) -> JsResult<'a, JsRegExp> {
+        let source = cx.string(source).upcast();
+        let flags = cx.string(flags).upcast();
+        let re = REGEXP.constructor(cx)?.construct(cx, [source, flags])?;
+
+        Ok(Handle::new_internal(JsRegExp(re.to_local())))
+    }
+
+    /// Checks whether the regular expression matches `input`, equivalent to the
+    /// JavaScript expression `re.test(input)`.
+    ///
+    /// Like [`JsRegExp::exec`], this advances `lastIndex` if the regular expression
+    /// has the global (`g`) or sticky (`y`) flag.
+    pub fn test<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        input: Handle<JsString>,
+    ) -> NeonResult<bool> {
+        let matched = self.call(cx, "test", input)?;
+
+        Ok(matched.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Searches `input` for a match, equivalent to the JavaScript expression
+    /// `re.exec(input)`.
+    ///
+    /// Returns the match array, containing the matched text followed by the text of
+    /// each capture group, or `None` if there is no match. The array also has the
+    /// `index`, `input` and `groups` properties of a JavaScript match result.
+    ///
+    /// If the regular expression has the global (`g`) or sticky (`y`) flag, the search
+    /// starts at `lastIndex`, which is updated after the search, allowing repeated
+    /// calls to iterate over all matches.
+    pub fn exec<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        input: Handle<JsString>,
+    ) -> NeonResult<Option<Handle<'a, JsArray>>> {
+        let result = self.call(cx, "exec", input)?;
+
+        if result.is_a::<JsArray, _>(cx) {
+            result.downcast_or_throw(cx).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn call<'a, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        method: &str,
+        input: Handle<JsString>,
+    ) -> JsResult<'a, JsValue> {
+        let this = JsValue::new_internal(self.0);
+
+        REGEXP.method(cx, method)?.call(cx, this, [input.upcast()])
+    }
+}
+
+impl Value for JsRegExp {}
+
+unsafe impl TransparentNoCopyWrapper for JsRegExp {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsRegExp {
+    fn name() -> &'static str {
+        "RegExp"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        REGEXP.is_instance(env, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsRegExp(h)
+    }
+}
+
+impl Object for JsRegExp {}
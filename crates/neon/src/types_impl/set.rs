@@ -0,0 +1,159 @@
+use super::{
+    builtin::{self, Builtin},
+    private::ValueInternal,
+    Value,
+};
+
+use crate::{
+    context::{internal::Env, Context},
+    handle::{internal::TransparentNoCopyWrapper, Handle},
+    object::Object,
+    result::{JsResult, NeonResult},
+    sys::raw,
+    types::{JsBoolean, JsNumber, JsObject, JsValue},
+};
+
+static SET: Builtin = Builtin::new("Set", &["add", "has", "delete", "values"]);
+
+/// The type of JavaScript
+/// [`Set`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+/// objects.
+///
+/// Values in a `Set` are unique according to
+/// [SameValueZero](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Equality_comparisons_and_sameness#same-value-zero_equality)
+/// equality: objects are compared by reference identity and `NaN` is equal to itself.
+/// Values are iterated in insertion order.
+///
+/// As with [`JsMap`](crate::types::JsMap), operations call the methods of
+/// `Set.prototype`, which are looked up once per module instance on Node-API 6 or
+/// later. A value is considered a `JsSet` if it is an `instanceof` the global `Set`
+/// class.
+///
+/// # Example
+///
+/// ```
+/// # use neon::prelude::*;
+/// use neon::types::JsSet;
+///
+/// // Removes duplicate values from an array, preserving order
+/// fn unique(mut cx: FunctionContext) -> JsResult<JsArray> {
+///     let values = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+///     let set = JsSet::new(&mut cx)?;
+///
+///     for value in values {
+///         set.add(&mut cx, value)?;
+///     }
+///
+///     let unique = set.to_vec(&mut cx)?;
+///
+///     JsArray::from_slice(&mut cx, &unique)
+/// }
+/// ```
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct JsSet(raw::Local);
+
+impl JsSet {
+    /// Constructs a new empty `Set`, equivalent to the JavaScript expression `new Set()`.
+    pub fn new<'a, C: Context<'a>>(cx: &mut C) -> JsResult<'a, JsSet> {
+        let set = SET.constructor(cx)?.construct(cx, [])?;
+
+        Ok(Handle::new_internal(JsSet(set.to_local())))
+    }
+
+    /// Returns the number of values in the `Set`.
+    pub fn size<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<usize> {
+        let size = Object::get::<JsNumber, _, _>(self, cx, "size")?;
+
+        Ok(size.value(cx) as usize)
+    }
+
+    /// Adds `value` to the `Set` if an equal value is not already present.
+    pub fn add<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<()> {
+        self.call(cx, "add", &[value.upcast()])?;
+
+        Ok(())
+    }
+
+    /// Checks whether the `Set` contains `value`.
+    pub fn has<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<bool> {
+        let has = self.call(cx, "has", &[value.upcast()])?;
+
+        Ok(has.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Removes `value` from the `Set`, returning `true` if the `Set` contained `value`.
+    pub fn delete<'a, C: Context<'a>, V: Value>(
+        &self,
+        cx: &mut C,
+        value: Handle<V>,
+    ) -> NeonResult<bool> {
+        let deleted = self.call(cx, "delete", &[value.upcast()])?;
+
+        Ok(deleted.downcast_or_throw::<JsBoolean, _>(cx)?.value(cx))
+    }
+
+    /// Copies the values of the `Set` into a new [`Vec`], in insertion order.
+    pub fn to_vec<'a, C: Context<'a>>(&self, cx: &mut C) -> NeonResult<Vec<Handle<'a, JsValue>>> {
+        let iter = self
+            .call(cx, "values", &[])?
+            .downcast_or_throw::<JsObject, _>(cx)?;
+
+        let mut values = Vec::new();
+
+        while let Some(value) = builtin::iter_next(cx, iter)? {
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+
+    fn call<'a, 'b, C: Context<'a>>(
+        &self,
+        cx: &mut C,
+        method: &str,
+        args: &[Handle<'b, JsValue>],
+    ) -> JsResult<'a, JsValue> {
+        let this = JsValue::new_internal(self.0);
+
+        SET.method(cx, method)?.call(cx, this, args)
+    }
+}
+
+impl Value for JsSet {}
+
+unsafe impl TransparentNoCopyWrapper for JsSet {
+    type Inner = raw::Local;
+
+    fn into_inner(self) -> Self::Inner {
+        self.0
+    }
+}
+
+impl ValueInternal for JsSet {
+    fn name() -> &'static str {
+        "Set"
+    }
+
+    fn is_typeof<Other: Value>(env: Env, other: &Other) -> bool {
+        SET.is_instance(env, other.to_local())
+    }
+
+    fn to_local(&self) -> raw::Local {
+        self.0
+    }
+
+    unsafe fn from_local(_env: Env, h: raw::Local) -> Self {
+        JsSet(h)
+    }
+}
+
+impl Object for JsSet {}
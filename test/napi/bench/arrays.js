@@ -0,0 +1,46 @@
+// Compares `JsArray::all_of` with downcasting each element of `JsArray::to_vec`
+//
+// Usage: npm run bench
+
+var addon = require("..");
+
+var ITERATIONS = 200;
+
+function bench(name, fn, array) {
+  // Warm up
+  for (var i = 0; i < 10; i++) {
+    fn(array);
+  }
+
+  var start = process.hrtime.bigint();
+
+  for (var i = 0; i < ITERATIONS; i++) {
+    fn(array);
+  }
+
+  var elapsed = Number(process.hrtime.bigint() - start) / 1e6 / ITERATIONS;
+
+  console.log(`  ${name.padEnd(12)} ${elapsed.toFixed(3)} ms/iter`);
+
+  return elapsed;
+}
+
+for (var len of [100, 10000, 1000000]) {
+  var numbers = Array.from({ length: len }, (_, i) => i);
+  // A mismatch halfway through shows the benefit of short-circuiting
+  var mixed = numbers.slice();
+
+  mixed[len >> 1] = "not a number";
+
+  for (var [label, array] of [
+    ["numbers", numbers],
+    ["mixed", mixed],
+  ]) {
+    console.log(`${label} (${len} elements)`);
+
+    var naive = bench("to_vec", addon.js_array_all_numbers_naive, array);
+    var allOf = bench("all_of", addon.js_array_all_numbers, array);
+
+    console.log(`  speedup      ${(naive / allOf).toFixed(2)}x`);
+  }
+}
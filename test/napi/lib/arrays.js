@@ -6,6 +6,13 @@ describe("JsArray", function () {
     assert.deepEqual([], addon.return_js_array());
   });
 
+  it("return a JsArray with empty slots", function () {
+    const array = addon.return_js_array_with_length(3);
+
+    assert.strictEqual(array.length, 3);
+    assert.strictEqual(0 in array, false);
+  });
+
   it("return a JsArray with a number at index 0", function () {
     assert.deepEqual([9000], addon.return_js_array_with_number());
   });
@@ -21,4 +28,161 @@ describe("JsArray", function () {
   it("returns undefined when accessing outside JsArray bounds", function () {
     assert.strictEqual(addon.read_js_array([]), undefined);
   });
+
+  it("can check that every element of a JsArray is a number", function () {
+    assert.strictEqual(addon.js_array_all_numbers([]), true);
+    assert.strictEqual(addon.js_array_all_numbers([1, 2, 3]), true);
+    assert.strictEqual(addon.js_array_all_numbers([1, "2", 3]), false);
+    assert.strictEqual(addon.js_array_all_numbers([1, , 3]), false);
+  });
+
+  it("propagates exceptions from element getters when checking types", function () {
+    const array = [1];
+
+    Object.defineProperty(array, 1, {
+      get() {
+        throw new Error("getter failed");
+      },
+    });
+
+    assert.throws(() => addon.js_array_all_numbers(array), /getter failed/);
+  });
+
+  it("can concatenate JsArrays", function () {
+    assert.deepEqual(addon.concat_js_arrays([1], [2, 3], [], [[4]]), [
+      1,
+      2,
+      3,
+      [4],
+    ]);
+    assert.deepEqual(addon.concat_js_arrays([1, 2]), [1, 2]);
+  });
+
+  it("can flatten a JsArray", function () {
+    const nested = [1, [2, [3, [4]]], , 5];
+
+    assert.deepEqual(addon.flat_js_array(nested, 0), [1, [2, [3, [4]]], 5]);
+    assert.deepEqual(addon.flat_js_array(nested, 1), [1, 2, [3, [4]], 5]);
+    assert.deepEqual(addon.flat_js_array(nested, 10), [1, 2, 3, 4, 5]);
+  });
+
+  it("can create a JsArray from a slice of handles", function () {
+    const obj = {};
+    const array = addon.js_array_from_arguments(1, "two", obj, undefined);
+
+    assert.ok(Array.isArray(array));
+    assert.deepEqual(array, [1, "two", obj, undefined]);
+    assert.strictEqual(array[2], obj);
+    assert.deepEqual(addon.js_array_from_arguments(), []);
+  });
+
+  it("can copy handles into a JsArray", function () {
+    const array = [1, 2, 3];
+
+    assert.strictEqual(addon.copy_into_js_array(array, 1, "a", "b"), array);
+    assert.deepEqual(array, [1, "a", "b"]);
+
+    addon.copy_into_js_array(array, 3, 4);
+    assert.deepEqual(array, [1, "a", "b", 4]);
+
+    assert.throws(
+      () => addon.copy_into_js_array([], 2 ** 32 - 1, 1),
+      RangeError,
+      /Invalid array length/
+    );
+  });
+
+  it("can copy a JsArray into a typed Vec", function () {
+    assert.strictEqual(addon.sum_js_array_numbers([1, 2, 3.5]), 6.5);
+    assert.strictEqual(addon.sum_js_array_numbers([]), 0);
+  });
+
+  it("reports the index of the first element with the wrong type", function () {
+    assert.throws(
+      () => addon.sum_js_array_numbers([1, 2, "three", {}]),
+      TypeError,
+      /element 2/
+    );
+  });
+
+  it("can lazily iterate over a JsArray", function () {
+    assert.deepEqual(addon.iter_js_array([]), []);
+    assert.deepEqual(addon.iter_js_array([1, "a", null]), [1, "a", null]);
+  });
+
+  it("re-checks the length while iterating over a JsArray", function () {
+    const array = [1, 2, 3];
+
+    Object.defineProperty(array, 0, {
+      get() {
+        array.length = 2;
+        return 1;
+      },
+    });
+
+    assert.deepEqual(addon.iter_js_array(array), [1, 2]);
+  });
+
+  it("propagates exceptions while iterating over a JsArray", function () {
+    const array = [1];
+
+    Object.defineProperty(array, 0, {
+      get() {
+        throw new RangeError("Oh, no!");
+      },
+    });
+
+    assert.throws(() => addon.iter_js_array(array), RangeError);
+  });
+
+  it("sorts a JsArray with a Rust comparator", function () {
+    const array = [{ n: 3 }, { n: 1, id: "a" }, { n: 2 }, { n: 1, id: "b" }];
+    const sorted = addon.sort_js_array_by_key(array, (x) => x.n);
+
+    assert.strictEqual(sorted, array);
+    assert.deepEqual(array, [
+      { n: 1, id: "a" },
+      { n: 1, id: "b" },
+      { n: 2 },
+      { n: 3 },
+    ]);
+    assert.deepEqual(addon.sort_js_array_by_key([], (x) => x), []);
+  });
+
+  it("sorts a snapshot of a JsArray modified by the comparator", function () {
+    const array = [3, 1, 2];
+    const sorted = addon.sort_js_array_by_key(array, (x) => {
+      array[0] = 100;
+      return x;
+    });
+
+    assert.deepEqual(sorted, [1, 2, 3]);
+  });
+
+  it("leaves a JsArray unmodified if the comparator throws", function () {
+    const array = [3, 1, 2];
+
+    assert.throws(
+      () =>
+        addon.sort_js_array_by_key(array, (x) => {
+          if (x === 2) {
+            throw new RangeError("Oh, no!");
+          }
+
+          return x;
+        }),
+      RangeError
+    );
+    assert.deepEqual(array, [3, 1, 2]);
+  });
+
+  it("does not panic with an inconsistent comparator", function () {
+    const array = Array.from({ length: 100 }, (_, i) => i);
+    const sorted = addon.sort_js_array_by_key(array, () => Math.random());
+
+    assert.deepEqual(
+      sorted.slice().sort((a, b) => a - b),
+      Array.from({ length: 100 }, (_, i) => i)
+    );
+  });
 });
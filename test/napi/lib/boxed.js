@@ -58,6 +58,35 @@ describe("boxed", function () {
     assert.throws(() => new RefPerson("World").fail(), /BorrowMutError/);
   });
 
+  it("should throw a catchable error on re-entrant borrows", function () {
+    const person = addon.ref_person_new("World");
+
+    addon.ref_person_set_name_with(person, () => {
+      assert.throws(() => addon.ref_person_try_greet(person), /borrowed/);
+
+      return "Universe";
+    });
+
+    assert.strictEqual(addon.ref_person_try_greet(person), "Hello, Universe!");
+    assert.throws(
+      () => addon.ref_person_set_name_with(person, () => {
+        addon.ref_person_set_name_with(person, () => "Nested");
+      }),
+      Error,
+      /borrowed/
+    );
+    assert.strictEqual(addon.ref_person_greet(person), "Hello, Universe!");
+  });
+
+  it("should report external memory to the garbage collector", function () {
+    const size = 500 * 1024 * 1024;
+    const before = addon.adjust_external_memory(0);
+    const after = addon.adjust_external_memory(size);
+
+    assert.strictEqual(after - before, size);
+    assert.strictEqual(addon.adjust_external_memory(-size), before);
+  });
+
   it("should type check externals", function () {
     // `any::type_name` does not guarantee exact format
     // failed downcast to neon::types::boxed::JsBox<napi::js::boxed::Person>
@@ -72,4 +101,63 @@ describe("boxed", function () {
 
     assert.throws(() => addon.person_greet(unit), /failed to downcast/);
   });
+
+  it("should distinguish boxes of different types", function () {
+    const registry = {
+      person: addon.person_new("World"),
+      unit: addon.external_unit(),
+      object: {},
+      number: 42,
+    };
+
+    assert.strictEqual(addon.box_kind(registry, "person"), "person");
+    assert.strictEqual(addon.box_kind(registry, "unit"), "unit");
+    assert.strictEqual(addon.box_kind(registry, "object"), "other");
+    assert.strictEqual(addon.box_kind(registry, "number"), "other");
+    assert.strictEqual(addon.box_kind(registry, "missing"), "other");
+  });
+
+  (global.gc ? it : it.skip)(
+    "should report finalizer errors and panics to the hook",
+    async function () {
+      (() => {
+        addon.box_failing_finalizer(false);
+        addon.box_failing_finalizer(true);
+      })();
+
+      let errors = [];
+
+      for (let i = 0; i < 100 && errors.length < 2; i++) {
+        global.gc();
+        await new Promise((resolve) => setTimeout(resolve, 10));
+        errors = errors.concat(addon.take_finalize_errors());
+      }
+
+      assert.sameMembers(errors, [
+        "Error while finalizing a `neon::types::JsBox`: finalizer failed",
+        "Panic while finalizing a `neon::types::JsBox`: finalizer panicked",
+      ]);
+    }
+  );
+
+  (global.gc ? it : it.skip)(
+    "should report errors from finalizers nested in wrappers",
+    async function () {
+      (() => {
+        addon.box_wrapped_failing_finalizer();
+      })();
+
+      let errors = [];
+
+      for (let i = 0; i < 100 && errors.length < 1; i++) {
+        global.gc();
+        await new Promise((resolve) => setTimeout(resolve, 10));
+        errors = errors.concat(addon.take_finalize_errors());
+      }
+
+      assert.deepEqual(errors, [
+        "Error while finalizing a `neon::types::JsBox`: finalizer failed",
+      ]);
+    }
+  );
 });
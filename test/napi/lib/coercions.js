@@ -7,4 +7,61 @@ describe("coercions", function () {
     assert.strictEqual(addon.to_string(new Map()), "[object Map]");
     assert.strictEqual(addon.to_string({ a: "b" }), "[object Object]");
   });
+
+  it("can coerce an argument to a number", function () {
+    assert.strictEqual(addon.argument_coerced_to_number("42"), 42);
+    assert.strictEqual(addon.argument_coerced_to_number(true), 1);
+    assert.strictEqual(
+      addon.argument_coerced_to_number({ valueOf: () => 7 }),
+      7
+    );
+    assert.isNaN(addon.argument_coerced_to_number("forty-two"));
+    assert.isNaN(addon.argument_coerced_to_number());
+    assert.throws(() => addon.argument_coerced_to_number(Symbol()), TypeError);
+  });
+
+  it("can coerce an argument to a string", function () {
+    assert.strictEqual(addon.argument_coerced_to_string(42), "42");
+    assert.strictEqual(addon.argument_coerced_to_string(null), "null");
+    assert.strictEqual(addon.argument_coerced_to_string(), "undefined");
+    assert.strictEqual(
+      addon.argument_coerced_to_string({ toString: () => "custom" }),
+      "custom"
+    );
+
+    const err = new Error("Oh, no!");
+
+    assert.throws(
+      () =>
+        addon.argument_coerced_to_string({
+          toString() {
+            throw err;
+          },
+        }),
+      err
+    );
+  });
+
+  it("can coerce an argument to a boolean", function () {
+    assert.strictEqual(addon.argument_coerced_to_boolean(1), true);
+    assert.strictEqual(addon.argument_coerced_to_boolean(""), false);
+    assert.strictEqual(addon.argument_coerced_to_boolean({}), true);
+    assert.strictEqual(addon.argument_coerced_to_boolean(), false);
+  });
+
+  it("can coerce values like the built-in conversion functions", function () {
+    const values = [0, 1.5, "", "12", "abc", null, undefined, [], [7], {}];
+
+    for (const value of values) {
+      assert.strictEqual(addon.coerce_to_string(value), String(value));
+      assert.deepEqual(addon.coerce_to_number(value), Number(value));
+      assert.strictEqual(addon.coerce_to_bool(value), Boolean(value));
+    }
+  });
+
+  it("throws when coercing a symbol to a string or number", function () {
+    assert.throws(() => addon.coerce_to_string(Symbol()), TypeError);
+    assert.throws(() => addon.coerce_to_number(Symbol()), TypeError);
+    assert.strictEqual(addon.coerce_to_bool(Symbol()), true);
+  });
 });
@@ -0,0 +1,173 @@
+const addon = require("..");
+const assert = require("chai").assert;
+
+describe("JsMap", function () {
+  it("should create a Map from entries", function () {
+    const key = {};
+    const map = addon.map_from_entries([
+      ["a", 1],
+      [key, 2],
+    ]);
+
+    assert.instanceOf(map, Map);
+    assert.deepEqual([...map], [
+      ["a", 1],
+      [key, 2],
+    ]);
+  });
+
+  it("should get values by key identity", function () {
+    const key = {};
+    const map = new Map([
+      [key, "object"],
+      [1, "number"],
+      [NaN, "nan"],
+    ]);
+
+    assert.strictEqual(addon.map_get(map, key), "object");
+    assert.strictEqual(addon.map_get(map, {}), undefined);
+    assert.strictEqual(addon.map_get(map, 1), "number");
+    assert.strictEqual(addon.map_get(map, "1"), undefined);
+    assert.strictEqual(addon.map_get(map, NaN), "nan");
+  });
+
+  it("should check for and delete keys", function () {
+    const key = {};
+    const map = new Map([[key, 1]]);
+
+    assert.isTrue(addon.map_has(map, key));
+    assert.isFalse(addon.map_has(map, {}));
+    assert.isFalse(addon.map_delete(map, {}));
+    assert.isTrue(addon.map_delete(map, key));
+    assert.isFalse(addon.map_has(map, key));
+  });
+
+  it("should get the size of a Map", function () {
+    assert.strictEqual(addon.map_size(new Map()), 0);
+    assert.strictEqual(addon.map_size(new Map([[1, 2]])), 1);
+  });
+
+  it("should read entries in insertion order", function () {
+    const key = {};
+    const map = new Map([
+      ["b", 1],
+      [key, 2],
+      ["a", 3],
+    ]);
+
+    assert.deepEqual(addon.map_entries(map), [
+      ["b", 1],
+      [key, 2],
+      ["a", 3],
+    ]);
+    assert.deepEqual(addon.map_entries(new Map()), []);
+  });
+
+  it("should check if a value is a Map", function () {
+    class SubMap extends Map {}
+
+    assert.isTrue(addon.is_map(new Map()));
+    assert.isTrue(addon.is_map(new SubMap()));
+    assert.isFalse(addon.is_map(new Set()));
+    assert.isFalse(addon.is_map({}));
+    assert.isFalse(addon.is_map(1));
+    assert.throws(() => addon.map_size({}), TypeError);
+  });
+
+  it("should not leave an exception pending when the check throws", function () {
+    const proxy = new Proxy(
+      {},
+      {
+        getPrototypeOf() {
+          throw new Error("getPrototypeOf");
+        },
+      }
+    );
+
+    assert.isFalse(addon.is_map(proxy));
+    assert.throws(() => addon.map_size(proxy), TypeError, /Map/);
+  });
+});
+
+describe("JsSet", function () {
+  it("should create a Set with unique values", function () {
+    const obj = {};
+    const set = addon.set_from_values([1, "1", obj, 1, obj, {}]);
+
+    assert.instanceOf(set, Set);
+    assert.strictEqual(set.size, 4);
+    assert.isTrue(set.has(obj));
+  });
+
+  it("should use SameValueZero equality", function () {
+    const set = addon.set_from_values([NaN, NaN, 0, -0]);
+
+    assert.strictEqual(addon.set_size(set), 2);
+    assert.isTrue(addon.set_has(set, NaN));
+    assert.isTrue(addon.set_has(set, -0));
+  });
+
+  it("should check for and delete values", function () {
+    const obj = {};
+    const set = new Set([obj, "a"]);
+
+    assert.isTrue(addon.set_has(set, obj));
+    assert.isFalse(addon.set_has(set, {}));
+    assert.isFalse(addon.set_delete(set, "b"));
+    assert.isTrue(addon.set_delete(set, obj));
+    assert.isFalse(set.has(obj));
+  });
+
+  it("should copy values in insertion order", function () {
+    const obj = {};
+
+    assert.deepEqual(addon.set_to_array(new Set(["b", obj, "a"])), [
+      "b",
+      obj,
+      "a",
+    ]);
+    assert.deepEqual(addon.set_to_array(new Set()), []);
+    assert.throws(() => addon.set_to_array(new Map()), TypeError);
+  });
+});
+
+describe("JsRegExp", function () {
+  it("should compile a RegExp with flags", function () {
+    const re = addon.regexp_new("^h(?<rest>.)", "iu");
+
+    assert.instanceOf(re, RegExp);
+    assert.strictEqual(re.source, "^h(?<rest>.)");
+    assert.strictEqual(re.flags, "iu");
+  });
+
+  it("should throw a SyntaxError for an invalid pattern or flags", function () {
+    assert.throws(() => addon.regexp_new("(", ""), SyntaxError);
+    assert.throws(() => addon.regexp_new("a", "gg"), SyntaxError);
+    assert.throws(() => addon.regexp_new("a", "x"), SyntaxError);
+  });
+
+  it("should test a string with JavaScript semantics", function () {
+    const re = addon.regexp_new("^\\p{Lu}", "u");
+
+    assert.isTrue(addon.regexp_test(re, "\u00c9cole"));
+    assert.isFalse(addon.regexp_test(re, "\u00e9cole"));
+    assert.throws(() => addon.regexp_test("a", "a"), TypeError);
+  });
+
+  it("should exec a RegExp and return the match or null", function () {
+    const m = addon.regexp_exec(/(?<word>\w+)-(\d+)/, "id: item-42");
+
+    assert.deepEqual([...m], ["item-42", "item", "42"]);
+    assert.strictEqual(m.index, 4);
+    assert.strictEqual(m.groups.word, "item");
+    assert.strictEqual(addon.regexp_exec(/\d/, "none"), null);
+  });
+
+  it("should advance lastIndex of a global RegExp", function () {
+    const re = /\d/g;
+
+    assert.strictEqual(addon.regexp_exec(re, "a1b2")[0], "1");
+    assert.strictEqual(addon.regexp_exec(re, "a1b2")[0], "2");
+    assert.strictEqual(addon.regexp_exec(re, "a1b2"), null);
+  });
+});
@@ -13,6 +13,24 @@ describe("JsDate", function () {
     assert.equal(date.toUTCString(), new Date(31415).toUTCString());
   });
 
+  it("should create date from system time", function () {
+    const date = addon.create_date_from_system_time(1700000000123);
+    assert.instanceOf(date, Date);
+    assert.equal(date.getTime(), 1700000000123);
+  });
+
+  it("should create date from system time before the epoch", function () {
+    assert.equal(addon.create_date_from_system_time(-31415).getTime(), -31415);
+    assert.equal(addon.create_date_from_system_time(0).getTime(), 0);
+  });
+
+  it("should throw when system time is out of range", function () {
+    assert.throws(
+      () => addon.create_date_from_system_time(8.64e15 + 1000),
+      RangeError
+    );
+  });
+
   it("should check if date is valid", function () {
     const dateIsValid = addon.check_date_is_valid(31415);
     assert.isTrue(dateIsValid);
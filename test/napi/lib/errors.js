@@ -1,3 +1,6 @@
+const childProcess = require("child_process");
+const path = require("path");
+
 const addon = require("..");
 const assert = require("chai").assert;
 
@@ -34,8 +37,118 @@ describe("errors", function () {
     assert.throws(() => addon.throw_error(msg), msg);
   });
 
+  it("should be able to throw an error with a cause", function () {
+    const cause = new Error("Underlying failure");
+
+    try {
+      addon.throw_error_with_cause("Out of Bounds", cause);
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, RangeError);
+      assert.strictEqual(err.message, "Out of Bounds");
+      assert.strictEqual(err.cause, cause);
+      assert.notInclude(Object.keys(err), "cause");
+    }
+  });
+
+  it("should be able to attach properties before throwing an error", function () {
+    assert.strictEqual(addon.throw_error_with_code("8080"), 8080);
+
+    try {
+      addon.throw_error_with_code("http");
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, "invalid port: http");
+      assert.strictEqual(err.code, "ERR_INVALID_PORT");
+    }
+  });
+
   it("should be able to stringify a downcast error", function () {
     let msg = addon.downcast_error();
     assert.strictEqual(msg, "failed to downcast string to number");
   });
+
+  it("should be able to read the name and message of an error", function () {
+    assert.strictEqual(
+      addon.error_name_and_message(new RangeError("Out of Bounds")),
+      "RangeError: Out of Bounds"
+    );
+
+    class CustomError extends Error {
+      get name() {
+        return "CustomError";
+      }
+    }
+
+    assert.strictEqual(
+      addon.error_name_and_message(new CustomError("Oh, no!")),
+      "CustomError: Oh, no!"
+    );
+  });
+
+  it("should throw when the error name is not a string", function () {
+    const err = new Error("Oh, no!");
+    err.name = 42;
+
+    assert.throws(() => addon.error_name_and_message(err), TypeError);
+  });
+
+  it("should read the stack of an error", function () {
+    const err = new Error("Oh, no!");
+
+    assert.strictEqual(addon.error_stack(err), err.stack);
+
+    err.stack = undefined;
+    assert.strictEqual(addon.error_stack(err), undefined);
+  });
+
+  it("should capture the stack of a new error", function capturingCaller() {
+    const err = addon.capture_error("Oh, no!");
+
+    assert.instanceOf(err, Error);
+    assert.strictEqual(err.message, "Oh, no!");
+    assert.match(err.stack, /^Error: Oh, no!/);
+    assert.match(err.stack, /capturingCaller/);
+  });
+
+  it("should throw a Rust error with its sources as causes", function () {
+    try {
+      addon.throw_rust_error("forty-two");
+      assert.fail("should throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.strictEqual(err.message, "invalid config");
+      assert.instanceOf(err.cause, Error);
+      assert.strictEqual(err.cause.message, "invalid digit found in string");
+      assert.strictEqual(err.cause.cause, undefined);
+    }
+
+    assert.strictEqual(addon.throw_rust_error("42"), undefined);
+  });
+
+  it("should not expose a native stack as an enumerable property", function () {
+    const err = addon.new_error("Oh, no!");
+
+    assert.notInclude(Object.keys(err), "nativeStack");
+  });
+
+  it("should attach a non-enumerable native stack with RUST_BACKTRACE", function () {
+    const script = `
+      const addon = require(${JSON.stringify(path.join(__dirname, ".."))});
+      const err = addon.new_error("Oh, no!");
+      const desc = Object.getOwnPropertyDescriptor(err, "nativeStack");
+
+      console.log(JSON.stringify({
+        type: typeof desc.value,
+        enumerable: desc.enumerable,
+      }));
+    `;
+
+    const output = childProcess.execFileSync(process.execPath, ["-e", script], {
+      env: { ...process.env, RUST_BACKTRACE: "1" },
+    });
+
+    assert.deepEqual(JSON.parse(output), { type: "string", enumerable: false });
+  });
 });
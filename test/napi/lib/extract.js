@@ -62,4 +62,43 @@ describe("Extractors", () => {
     assert.strictEqual(addon.extract_json_sum([1, 2, 3, 4]), 10);
     assert.strictEqual(addon.extract_json_sum([8, 16, 18]), 42);
   });
+
+  it("JSON honors toJSON", () => {
+    const date = new Date();
+
+    assert.strictEqual(addon.extract_json_string(date), date.toISOString());
+    assert.strictEqual(
+      addon.extract_json_string({ toJSON: () => "custom" }),
+      "custom"
+    );
+  });
+
+  it("Variadic", () => {
+    assert.strictEqual(addon.extract_variadic_sum(), 0);
+    assert.strictEqual(addon.extract_variadic_sum(8, 16, 18), 42);
+    assert.strictEqual(addon.extract_variadic_join("a", "b", "c"), "a b c");
+
+    assert.throws(
+      () => addon.extract_variadic_sum(1, 2, "3"),
+      TypeError,
+      /argument 2/
+    );
+  });
+
+  it("Variadic handles", () => {
+    assert.strictEqual(addon.variadic_max(), -Infinity);
+    assert.strictEqual(addon.variadic_max(3, 42, 7), 42);
+    assert.deepEqual(addon.variadic_type_names(1, "a", undefined, undefined), [
+      "1",
+      "a",
+      "undefined",
+      "undefined",
+    ]);
+
+    assert.throws(
+      () => addon.variadic_max(1, "2"),
+      TypeError,
+      /argument 1/
+    );
+  });
 });
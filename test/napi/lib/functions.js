@@ -10,6 +10,13 @@ describe("JsFunction", function () {
     assert.equal(addon.return_js_function()(41), 42);
   });
 
+  it("return a JsFunction built in Rust with a name", function () {
+    const fn = addon.return_named_js_function("parseConfig");
+
+    assert.strictEqual(fn.name, "parseConfig");
+    assert.strictEqual(String(fn), "function parseConfig() { [native code] }");
+  });
+
   it("call a JsFunction built in JS that implements x => x + 1", function () {
     assert.equal(
       addon.call_js_function(function (x) {
@@ -48,6 +55,54 @@ describe("JsFunction", function () {
     assert.equal(addon.call_js_function_with_four_args(), 4.0);
   });
 
+  it("call a JsFunction with a Vec of args", function () {
+    const args = addon.call_js_function_with_vec_args((...args) => args, 40);
+
+    assert.deepEqual(
+      args,
+      [...new Array(40)].map((_, i) => i)
+    );
+    assert.deepEqual(
+      addon.call_js_function_with_vec_args((...args) => args, 0),
+      []
+    );
+  });
+
+  it("call a JsFunction with a slice of args", function () {
+    assert.deepEqual(
+      addon.call_js_function_with_slice_args((...args) => args),
+      ["a", "b"]
+    );
+  });
+
+  it("bind this and leading arguments to a JsFunction", function () {
+    const obj = { secret: 42 };
+    const f = function (...args) {
+      return [this, ...args];
+    };
+    const bound = addon.bind_js_function(f, obj, "a", "b");
+
+    assert.deepEqual(bound("c"), [obj, "a", "b", "c"]);
+    assert.strictEqual(bound.call({}, "c")[0], obj);
+    assert.strictEqual(bound.name, "bound f");
+    assert.strictEqual(bound.length, 0);
+  });
+
+  it("construct a bound JsFunction ignoring the bound this", function () {
+    class Point {
+      constructor(x, y) {
+        this.x = x;
+        this.y = y;
+      }
+    }
+
+    const BoundPoint = addon.bind_js_function(Point, { ignored: true }, 1);
+    const point = new BoundPoint(2);
+
+    assert.instanceOf(point, Point);
+    assert.deepEqual({ ...point }, { x: 1, y: 2 });
+  });
+
   it("call a JsFunction with a custom this", function () {
     assert.equal(
       addon.call_js_function_with_custom_this(function () {
@@ -207,6 +262,43 @@ describe("JsFunction", function () {
     );
   });
 
+  it("rethrows a caught value unchanged with cx.throw", function () {
+    const error = new TypeError("Something bad happened");
+    const { stack } = error;
+
+    for (const value of [error, "a string", 42, undefined]) {
+      try {
+        addon.call_and_rethrow(() => {
+          throw value;
+        });
+        assert.fail("should have thrown");
+      } catch (err) {
+        assert.strictEqual(err, value);
+      }
+    }
+
+    assert.strictEqual(error.stack, stack);
+    assert.strictEqual(addon.call_and_rethrow(() => 42), 42);
+  });
+
+  it("clears the exception after cx.try_catch to allow retries", function () {
+    let calls = 0;
+    const flaky = () => {
+      calls += 1;
+
+      if (calls < 3) {
+        throw new Error(`attempt ${calls}`);
+      }
+
+      return calls;
+    };
+
+    assert.strictEqual(addon.call_with_retry(flaky, 5), 3);
+
+    calls = 0;
+    assert.throws(() => addon.call_with_retry(flaky, 2), /attempt 2/);
+  });
+
   it("gets a regular value with cx.try_catch", function () {
     assert.equal(
       addon.call_and_catch(() => {
@@ -268,4 +360,68 @@ describe("JsFunction", function () {
       global.gc();
     }
   );
+
+  it("can iterate over a Rust iterator", function () {
+    assert.deepEqual([...addon.iterate_range(4)], [0, 1, 2, 3]);
+  });
+
+  it("returns done repeatedly from an exhausted Rust iterator", function () {
+    const iter = addon.iterate_range(1);
+
+    assert.deepEqual(iter.next(), { value: 0, done: false });
+    assert.deepEqual(iter.next(), { value: undefined, done: true });
+    assert.deepEqual(iter.next(), { value: undefined, done: true });
+  });
+
+  it("can get the name of a function", function () {
+    function named() {}
+    const arrow = () => {};
+
+    assert.strictEqual(addon.get_function_name(named), "named");
+    assert.strictEqual(addon.get_function_name(arrow), "arrow");
+    assert.strictEqual(addon.get_function_name(named.bind(null)), "bound named");
+    assert.strictEqual(addon.get_function_name((() => () => {})()), "");
+  });
+
+  it("throws if the name of a function is not a string", function () {
+    class Thing {
+      static name() {}
+    }
+
+    assert.throws(() => addon.get_function_name(Thing), TypeError);
+  });
+
+  it("can get the length of a function", function () {
+    function f(a, b, c) {}
+
+    assert.strictEqual(addon.get_function_length(f), 3);
+    assert.strictEqual(addon.get_function_length(() => {}), 0);
+    assert.strictEqual(addon.get_function_length(f.bind(null, 1)), 2);
+    assert.strictEqual(addon.get_function_length((a, ...rest) => {}), 1);
+    assert.strictEqual(addon.get_function_length((a, b = 1, c) => {}), 1);
+  });
+
+  it("throws if the length of a function is redefined to an invalid value", function () {
+    function withLength(length) {
+      const f = function () {};
+
+      Object.defineProperty(f, "length", { value: length });
+
+      return f;
+    }
+
+    assert.strictEqual(
+      addon.get_function_length(withLength(2 ** 32 - 1)),
+      2 ** 32 - 1
+    );
+    assert.throws(() => addon.get_function_length(withLength("3")), TypeError);
+
+    for (const length of [-1, 1.5, NaN, Infinity, 2 ** 32]) {
+      assert.throws(
+        () => addon.get_function_length(withLength(length)),
+        RangeError,
+        /Function length/
+      );
+    }
+  });
 });
@@ -56,4 +56,62 @@ describe("JsNumber", function () {
       assert.equal(addon.accept_and_return_negative_js_number(-55), -55);
     });
   });
+
+  describe("integers", function () {
+    it("converts with ToInt32 and ToUint32", function () {
+      assert.deepEqual(addon.number_to_integers(42.9), [42, 42, "42"]);
+      assert.deepEqual(addon.number_to_integers(-1), [-1, 4294967295, "-1"]);
+      assert.deepEqual(addon.number_to_integers(2 ** 32 + 5), [
+        5,
+        5,
+        "4294967301",
+      ]);
+      assert.deepEqual(addon.number_to_integers(NaN), [0, 0, "0"]);
+      assert.deepEqual(addon.number_to_integers(Infinity), [0, 0, "0"]);
+    });
+
+    it("strictly converts safe integers to i64", function () {
+      assert.strictEqual(
+        addon.number_try_value_i64(Number.MAX_SAFE_INTEGER),
+        "9007199254740991"
+      );
+      assert.strictEqual(
+        addon.number_try_value_i64(Number.MIN_SAFE_INTEGER),
+        "-9007199254740991"
+      );
+      assert.strictEqual(addon.number_try_value_i64(-0), "0");
+
+      for (const n of [1.5, NaN, Infinity, Number.MAX_SAFE_INTEGER + 1]) {
+        assert.throws(
+          () => addon.number_try_value_i64(n),
+          RangeError,
+          /not a safe integer/
+        );
+      }
+    });
+
+    it("classifies numbers like the Number predicates", function () {
+      const values = [
+        0,
+        -0,
+        1.5,
+        2 ** 53,
+        Number.MAX_SAFE_INTEGER,
+        -Number.MAX_SAFE_INTEGER,
+        Number.MAX_VALUE,
+        NaN,
+        Infinity,
+        -Infinity,
+      ];
+
+      for (const n of values) {
+        assert.deepEqual(addon.number_predicates(n), [
+          Number.isInteger(n),
+          Number.isSafeInteger(n),
+          Number.isNaN(n),
+          Number.isFinite(n),
+        ]);
+      }
+    });
+  });
 });
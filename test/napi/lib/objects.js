@@ -6,6 +6,39 @@ describe("JsObject", function () {
     assert(global === addon.return_js_global_object());
   });
 
+  it("requires a global by name", function () {
+    assert.strictEqual(addon.require_global_function("Array"), Array);
+    assert.throws(
+      () => addon.require_global_function("__neon_missing_global__"),
+      TypeError,
+      "global `__neon_missing_global__` is not defined"
+    );
+    assert.throws(
+      () => addon.require_global_function("Math"),
+      TypeError,
+      /global `Math`/
+    );
+  });
+
+  it("parses and stringifies JSON", function () {
+    assert.deepEqual(addon.json_parse('{"a":[1,"b",null]}'), {
+      a: [1, "b", null],
+    });
+    assert.strictEqual(
+      addon.json_stringify({ a: [1, "b", null] }),
+      '{"a":[1,"b",null]}'
+    );
+    assert.throws(() => addon.json_parse("{"), SyntaxError);
+  });
+
+  it("propagates JSON.stringify exceptions", function () {
+    const circular = {};
+    circular.self = circular;
+
+    assert.throws(() => addon.json_stringify(circular), TypeError, /circular/);
+    assert.throws(() => addon.json_stringify(undefined), TypeError);
+  });
+
   it("return a JsObject built in Rust", function () {
     assert.deepEqual({}, addon.return_js_object());
   });
@@ -65,6 +98,33 @@ describe("JsObject", function () {
     });
   });
 
+  it("check if a JsObject is frozen or sealed", function () {
+    const obj = { x: 1 };
+
+    assert.isFalse(addon.is_frozen_js_object(obj));
+    assert.isFalse(addon.is_sealed_js_object(obj));
+
+    addon.seal_js_object(obj);
+    assert.isFalse(addon.is_frozen_js_object(obj));
+    assert.isTrue(addon.is_sealed_js_object(obj));
+
+    addon.freeze_js_object(obj);
+    assert.isTrue(addon.is_frozen_js_object(obj));
+    assert.isTrue(addon.is_sealed_js_object(obj));
+  });
+
+  it("throw when assigning to a frozen JsObject in strict mode", function () {
+    "use strict";
+
+    const obj = { x: 1 };
+
+    addon.freeze_js_object(obj);
+    assert.throws(() => {
+      obj.x = 2;
+    }, TypeError);
+    assert.strictEqual(obj.x, 1);
+  });
+
   it("returns only own properties from get_own_property_names", function () {
     var superObject = {
       a: 1,
@@ -91,6 +151,97 @@ describe("JsObject", function () {
     assert.equal(addon.get_own_property_names(object).length, 1);
   });
 
+  it("returns non-enumerable properties from get_own_property_names", function () {
+    var object = { a: 1 };
+    Object.defineProperty(object, "hidden", { value: 2, enumerable: false });
+
+    assert.deepEqual(addon.get_own_property_names(object), ["a", "hidden"]);
+  });
+
+  it("returns only own enumerable string keys from keys", function () {
+    var superObject = { a: 1 };
+    var object = Object.create(superObject);
+    object.b = 2;
+    object[3] = 3;
+    object[Symbol("c")] = 4;
+    Object.defineProperty(object, "hidden", { value: 5, enumerable: false });
+
+    assert.deepEqual(addon.keys(object), Object.keys(object));
+    assert.deepEqual(addon.keys(object), ["3", "b"]);
+    assert.deepEqual(addon.keys([1, 2]), ["0", "1"]);
+  });
+
+  describe("property_names_with", function () {
+    const sym = Symbol("sym");
+    const inherited = Symbol("inherited");
+    const superObject = { a: 1, [inherited]: 2 };
+    const object = Object.create(superObject);
+
+    object.b = 3;
+    object[4] = 5;
+    object[sym] = 6;
+    Object.defineProperty(object, "hidden", { value: 7, enumerable: false });
+    Object.defineProperty(object, "fixed", {
+      value: 8,
+      enumerable: true,
+      writable: false,
+      configurable: false,
+    });
+
+    it("returns own string and symbol keys by default", function () {
+      assert.deepEqual(
+        addon.property_names_with(object, {}),
+        Reflect.ownKeys(object)
+      );
+    });
+
+    it("includes inherited keys", function () {
+      const keys = addon.property_names_with(object, {
+        includePrototypes: true,
+      });
+
+      for (const key of [...Reflect.ownKeys(object), "a", inherited]) {
+        assert.ok(keys.includes(key), `missing ${String(key)}`);
+      }
+    });
+
+    it("filters by property attributes", function () {
+      assert.deepEqual(
+        addon.property_names_with(object, { enumerable: true }),
+        ["4", "b", "fixed", sym]
+      );
+      assert.deepEqual(
+        addon.property_names_with(object, { writable: true }),
+        ["4", "b", sym]
+      );
+      assert.deepEqual(
+        addon.property_names_with(object, { configurable: true }),
+        ["4", "b", sym]
+      );
+    });
+
+    it("skips strings or symbols", function () {
+      assert.deepEqual(addon.property_names_with(object, { skipStrings: true }), [
+        sym,
+      ]);
+      assert.deepEqual(
+        addon.property_names_with(object, { skipSymbols: true }),
+        Object.getOwnPropertyNames(object)
+      );
+    });
+
+    it("keeps integer keys as numbers", function () {
+      assert.deepEqual(
+        addon.property_names_with(object, {
+          keepNumbers: true,
+          skipSymbols: true,
+          enumerable: true,
+        }),
+        [4, "b", "fixed"]
+      );
+    });
+  });
+
   it("data borrowed on the heap can be held longer than the handle", function () {
     const msg = "Hello, World!";
     const buf = Buffer.from(msg);
@@ -124,4 +275,317 @@ describe("JsObject", function () {
 
     assert.strictEqual(addon.call_symbol_method(obj, sym), "hello");
   });
+
+  it("can call a method with arguments", function () {
+    const sym = Symbol("sym");
+    const obj = {
+      value: 42,
+      add(x, y) {
+        return this.value + x + y;
+      },
+      [sym]() {
+        return this;
+      },
+      notMethod: 1,
+    };
+
+    assert.strictEqual(addon.call_method(obj, "add", [1, 2]), 45);
+    assert.strictEqual(addon.call_method(obj, sym, []), obj);
+    assert.throws(
+      () => addon.call_method(obj, "notMethod", []),
+      TypeError,
+      /^'notMethod' is not a function$/
+    );
+    assert.throws(
+      () => addon.call_method(obj, "missing", []),
+      TypeError,
+      /^'missing' is not a function$/
+    );
+    assert.throws(
+      () => addon.call_method(obj, Symbol("missing"), []),
+      TypeError,
+      /^Symbol\(missing\) is not a function$/
+    );
+  });
+
+  it("can define a data property with attributes", function () {
+    const obj = {};
+
+    addon.define_data_property(obj, "hidden", 42, {
+      writable: false,
+      enumerable: false,
+      configurable: false,
+    });
+
+    assert.deepEqual(Object.getOwnPropertyDescriptor(obj, "hidden"), {
+      value: 42,
+      writable: false,
+      enumerable: false,
+      configurable: false,
+    });
+
+    assert.throws(
+      () =>
+        addon.define_data_property(obj, "hidden", 0, {
+          writable: true,
+          enumerable: true,
+          configurable: true,
+        }),
+      TypeError
+    );
+  });
+
+  it("can define accessor properties", function () {
+    const obj = addon.define_counter();
+
+    assert.strictEqual(obj.count, 1);
+    assert.strictEqual(obj.count, 2);
+
+    obj.count = 10;
+    assert.strictEqual(obj.count, 11);
+
+    assert.strictEqual(obj.readOnly, "read-only");
+    assert.throws(() => {
+      "use strict";
+      obj.readOnly = "changed";
+    }, TypeError);
+
+    assert.deepEqual(Object.keys(obj), ["count", "readOnly"]);
+  });
+
+  it("can create an object with a prototype", function () {
+    class Foo {
+      greet() {
+        return "hello";
+      }
+    }
+
+    const obj = addon.create_with_prototype(Foo.prototype);
+
+    assert.instanceOf(obj, Foo);
+    assert.strictEqual(obj.greet(), "hello");
+    assert.throws(() => addon.create_with_prototype(42), TypeError);
+  });
+
+  it("can create an object with a null prototype", function () {
+    const obj = addon.create_null_prototype_object();
+
+    assert.strictEqual(Object.getPrototypeOf(obj), null);
+    assert.strictEqual(obj.toString, undefined);
+  });
+
+  it("can get and set the prototype of an object", function () {
+    class Base {
+      greet() {
+        return "hello";
+      }
+    }
+
+    const obj = { name: "obj" };
+
+    assert.strictEqual(addon.get_prototype(obj), Object.prototype);
+    assert.strictEqual(addon.set_prototype(obj, Base.prototype), obj);
+    assert.strictEqual(addon.get_prototype(obj), Base.prototype);
+    assert.instanceOf(obj, Base);
+    assert.strictEqual(obj.greet(), "hello");
+
+    addon.set_prototype(obj, null);
+    assert.strictEqual(addon.get_prototype(obj), null);
+    assert.strictEqual(obj.toString, undefined);
+    assert.strictEqual(obj.name, "obj");
+
+    assert.throws(() => addon.set_prototype(obj, 42), TypeError);
+    assert.throws(
+      () => addon.set_prototype(Object.preventExtensions({}), {}),
+      TypeError
+    );
+  });
+
+  it("can assign the properties of one object to another", function () {
+    const sym = Symbol("sym");
+    const target = { a: 1, b: 2 };
+    const source = {
+      b: 3,
+      get c() {
+        return this.b * 2;
+      },
+      [sym]: 4,
+    };
+
+    Object.defineProperty(source, "hidden", { value: 5, enumerable: false });
+    Object.setPrototypeOf(source, { inherited: 6 });
+
+    assert.strictEqual(addon.assign_object(target, source), target);
+    assert.deepEqual(target, { a: 1, b: 3, c: 6 });
+    assert.strictEqual(target[sym], undefined);
+    assert.ok(!("hidden" in target));
+    assert.ok(!("inherited" in target));
+
+    assert.throws(
+      () =>
+        addon.assign_object({}, {
+          get x() {
+            throw new RangeError("getter");
+          },
+        }),
+      RangeError
+    );
+  });
+
+  it("can access integer keys of a plain object", function () {
+    const obj = addon.copy_index({ 7: "seven" }, 7, 4000000000);
+
+    assert.deepEqual(obj, { 7: "seven", 4000000000: "seven" });
+    assert.ok(!("length" in obj));
+    assert.strictEqual(addon.copy_index({}, 1, 2)[2], undefined);
+  });
+
+  it("can create an object from entries", function () {
+    assert.deepEqual(addon.object_from_entries(), { a: 3, b: 2 });
+  });
+
+  it("propagates exceptions from a getPrototypeOf trap", function () {
+    const err = new Error("trap");
+    const proxy = new Proxy(
+      {},
+      {
+        getPrototypeOf() {
+          throw err;
+        },
+      }
+    );
+
+    assert.throws(() => addon.get_prototype(proxy), err);
+  });
+
+  it("can check for own and inherited properties", function () {
+    const sym = Symbol("sym");
+    const proto = { inherited: 1 };
+    const obj = Object.create(proto);
+    obj.own = 2;
+    obj[sym] = 3;
+    obj[7] = 4;
+
+    assert.deepEqual(addon.has_property(obj, "own"), [true, true]);
+    assert.deepEqual(addon.has_property(obj, "inherited"), [true, false]);
+    assert.deepEqual(addon.has_property(obj, "missing"), [false, false]);
+    assert.deepEqual(addon.has_property(obj, sym), [true, true]);
+    assert.deepEqual(addon.has_property(obj, 7), [true, true]);
+    assert.deepEqual(addon.has_property(obj, "7"), [true, true]);
+  });
+
+  it("can get own properties, distinguishing missing from undefined", function () {
+    assert.strictEqual(addon.get_own_timeout({ timeout: 5 }), 5);
+    assert.strictEqual(
+      addon.get_own_timeout({ timeout: undefined }),
+      undefined
+    );
+    assert.strictEqual(addon.get_own_timeout({}), "default");
+    assert.strictEqual(
+      addon.get_own_timeout(Object.create({ timeout: 5 })),
+      "default"
+    );
+  });
+
+  it("can check for properties by index and name", function () {
+    assert.deepEqual(
+      addon.has_property_by_key_types(["x"]),
+      [true, true, false, false]
+    );
+    assert.deepEqual(
+      addon.has_property_by_key_types(Object.create({ 0: 1, a: 2 })),
+      [true, false, true, false]
+    );
+  });
+
+  it("can delete properties", function () {
+    const obj = { a: 1, b: 2 };
+    const array = [1, 2, 3];
+
+    assert.strictEqual(addon.delete_property(obj, "a"), true);
+    assert.strictEqual(addon.delete_named_property(obj, "b"), true);
+    assert.strictEqual(addon.delete_property(obj, "missing"), true);
+    assert.deepEqual(obj, {});
+
+    assert.strictEqual(addon.delete_property(array, 1), true);
+    assert.strictEqual(1 in array, false);
+    assert.strictEqual(array.length, 3);
+  });
+
+  it("returns false when a property cannot be deleted", function () {
+    const obj = Object.defineProperty({}, "fixed", {
+      value: 1,
+      configurable: false,
+    });
+
+    assert.strictEqual(addon.delete_property(obj, "fixed"), false);
+    assert.strictEqual(addon.delete_named_property(obj, "fixed"), false);
+    assert.strictEqual(obj.fixed, 1);
+  });
+
+  it("propagates exceptions from proxy traps", function () {
+    const obj = new Proxy(
+      {},
+      {
+        has() {
+          throw new Error("has trap");
+        },
+        deleteProperty() {
+          throw new Error("delete trap");
+        },
+      }
+    );
+
+    assert.throws(() => addon.has_property(obj, "a"), /has trap/);
+    assert.throws(() => addon.delete_property(obj, "a"), /delete trap/);
+  });
+
+  it("can manually count references to an object", function () {
+    const obj = {};
+    const reference = addon.create_reference(obj, 1);
+
+    assert.strictEqual(addon.reference_ref(reference), 2);
+    assert.strictEqual(addon.reference_unref(reference), 1);
+    assert.strictEqual(addon.reference_unref(reference), 0);
+    assert.strictEqual(addon.reference_get(reference), obj);
+  });
+
+  (global.gc ? it : it.skip)(
+    "should not return an object from a weak reference after collection",
+    async function () {
+      let reference = (() => addon.create_reference({}, 0))();
+
+      // Weak references are cleared asynchronously after collection
+      for (let i = 0; i < 10 && addon.reference_get(reference); i++) {
+        await new Promise((resolve) => setImmediate(resolve));
+        global.gc();
+      }
+
+      assert.strictEqual(addon.reference_get(reference), undefined);
+    }
+  );
+
+  it("can downgrade a root to a weak reference", function () {
+    const obj = {};
+    const reference = addon.downgrade_root(obj);
+
+    assert.strictEqual(addon.reference_get(reference), obj);
+    assert.strictEqual(addon.reference_ref(reference), 1);
+    assert.strictEqual(addon.reference_unref(reference), 0);
+  });
+
+  (global.gc ? it : it.skip)(
+    "should not return an object from a downgraded root after collection",
+    async function () {
+      let reference = (() => addon.downgrade_root({}))();
+
+      // Weak references are cleared asynchronously after collection
+      for (let i = 0; i < 10 && addon.reference_get(reference); i++) {
+        await new Promise((resolve) => setImmediate(resolve));
+        global.gc();
+      }
+
+      assert.strictEqual(addon.reference_get(reference), undefined);
+    }
+  );
 });
@@ -17,6 +17,101 @@ describe("JsString", function () {
       assert.equal(addon.return_length_utf16("hello 🥹"), 8);
     });
   });
+  describe("from_utf16", function () {
+    it("should round-trip a string through UTF-16", function () {
+      assert.equal(addon.string_utf16_round_trip("hello 🥹"), "hello 🥹");
+    });
+    it("should preserve unpaired surrogates", function () {
+      assert.equal(addon.string_utf16_round_trip("\uD800"), "\uD800");
+      assert.equal(addon.string_utf16_round_trip("a\uDC00b"), "a\uDC00b");
+    });
+    it("should create a string with a lone surrogate", function () {
+      assert.equal(addon.return_lone_surrogate(), "a\uD800b");
+    });
+  });
+  describe("latin1", function () {
+    it("should map each byte to a single character", function () {
+      const bytes = Buffer.from([...new Array(256)].map((_, i) => i));
+      const s = addon.string_from_latin1(bytes);
+
+      assert.strictEqual(s.length, 256);
+
+      for (let i = 0; i < 256; i++) {
+        assert.strictEqual(s.charCodeAt(i), i);
+      }
+
+      assert.deepEqual(addon.string_to_latin1(s), bytes);
+    });
+    it("should create an empty string", function () {
+      assert.strictEqual(addon.string_from_latin1(Buffer.alloc(0)), "");
+    });
+    it("should encode a string as Latin-1", function () {
+      assert.deepEqual(
+        addon.string_to_latin1("café"),
+        Buffer.from([0x63, 0x61, 0x66, 0xe9])
+      );
+    });
+    it("should truncate characters outside of Latin-1", function () {
+      assert.strictEqual(addon.string_to_latin1("a\u0100🥹").length, 4);
+    });
+  });
+  describe("concat", function () {
+    it("should concatenate two strings", function () {
+      assert.equal(addon.string_concat("hello ", "🥹"), "hello 🥹");
+      assert.equal(addon.string_concat("", ""), "");
+    });
+    it("should preserve unpaired surrogates", function () {
+      assert.equal(
+        addon.string_concat("a\uD800", "\uDC00b"),
+        "a\uD800\uDC00b"
+      );
+    });
+  });
+  describe("repeat", function () {
+    it("should repeat a string", function () {
+      assert.equal(addon.string_repeat("ab", 3), "ababab");
+      assert.equal(addon.string_repeat("ab", 0), "");
+      assert.equal(addon.string_repeat("", 1000), "");
+    });
+    it("should throw a RangeError if the result is too large", function () {
+      expect(() => addon.string_repeat("ab", 2 ** 31)).to.throw(
+        RangeError,
+        /string size out of range/
+      );
+      // Longer than V8 allows, but small enough to allocate
+      expect(() => addon.string_repeat("ab", 2 ** 29)).to.throw(
+        RangeError,
+        /string size out of range/
+      );
+    });
+  });
+  describe("reader", function () {
+    it("should read a string in chunks", function () {
+      const s = "hello, world! ".repeat(100);
+      const chunks = addon.string_read_chunks(s, 64);
+
+      assert.equal(chunks.length, Math.ceil(s.length / 63));
+      assert.equal(chunks.join(""), s);
+    });
+    it("should not split multi-byte characters across chunks", function () {
+      const s = "a🥹é中🥹\uD800z".repeat(50);
+      const chunks = addon.string_read_chunks(s, 5);
+
+      for (const chunk of chunks) {
+        assert.ok(Buffer.byteLength(chunk) <= 4);
+      }
+
+      assert.equal(chunks.join(""), s.replace(/\uD800/g, "\uFFFD"));
+    });
+    it("should read an empty string", function () {
+      assert.deepEqual(addon.string_read_chunks("", 5), []);
+    });
+    it("should reject buffers that are too small", function () {
+      expect(() => addon.string_read_chunks("abc", 4)).to.throw(
+        /at least 5 bytes/
+      );
+    });
+  });
   describe("run_as_script", function () {
     it("should return the evaluated value", function () {
       assert.equal(addon.run_string_as_script("6 * 7"), 42);
@@ -1,3 +1,6 @@
+const childProcess = require("child_process");
+const path = require("path");
+
 const addon = require("..");
 const assert = require("chai").assert;
 
@@ -88,6 +91,17 @@ const assert = require("chai").assert;
     setTimeout(() => global.gc(), 10);
   });
 
+  it("should drop a Root while the global queue is kept alive", function (cb) {
+    // If the queue is not unreferenced again, the test runner will not cleanly exit
+    addon.keep_drop_queue_alive(true);
+    addon.drop_global_queue(() => {
+      addon.keep_drop_queue_alive(false);
+      cb();
+    });
+
+    setTimeout(() => global.gc(), 10);
+  });
+
   it("should be able to join on the result of a channel", function (cb) {
     // `msg` is closed over by multiple functions. A function that returns the
     // current value is passed to the Neon function `addon.channel_join`. Additionally,
@@ -113,6 +127,159 @@ const assert = require("chai").assert;
     }, 10);
   });
 
+  it("should report a live channel while the event loop is running", function (cb) {
+    addon.channel_is_alive((isAlive) => {
+      assert.strictEqual(isAlive, true);
+      cb();
+    });
+  });
+
+  it("should exit while an unreferenced channel is held", function () {
+    const script = `
+      const addon = require(${JSON.stringify(path.join(__dirname, ".."))});
+
+      // Keep the process alive until the channel has sent its closure
+      const timer = setTimeout(() => {}, 60000);
+
+      addon.unref_channel(() => {
+        console.log("sent");
+        clearTimeout(timer);
+      });
+    `;
+
+    const output = childProcess.execFileSync(process.execPath, ["-e", script], {
+      timeout: 4000,
+    });
+
+    assert.strictEqual(output.toString().trim(), "sent");
+  });
+
+  it("should fail to try_send on a full bounded channel", function () {
+    assert.deepEqual(addon.bounded_channel_try_send(), [true, false]);
+  });
+
+  it("should not block the main thread on a full bounded channel", function () {
+    assert.deepEqual(
+      addon.bounded_channel_send_blocking_on_main_thread(),
+      [true, false]
+    );
+  });
+
+  it("should recover the closure from a failed try_send_or_return", function (cb) {
+    const calls = [];
+
+    addon.bounded_channel_try_send_or_return((name) => {
+      calls.push(name);
+
+      if (calls.length === 2) {
+        assert.deepEqual(calls, ["first", "second"]);
+        cb();
+      }
+    });
+  });
+
+  it("should apply backpressure with a bounded channel", function (cb) {
+    const count = 100;
+    const capacity = 2;
+    let expected = 0;
+
+    addon.bounded_channel_send_blocking(count, capacity, (i, pending) => {
+      assert.strictEqual(i, expected++);
+      // At most `capacity` closures are queued, plus one waiting to be sent
+      assert.ok(pending <= capacity + 1, `${pending} closures pending`);
+
+      if (expected === count) {
+        cb();
+      }
+    });
+  });
+
+  it("should call a threadsafe function with mapped payloads", function (cb) {
+    const count = 10;
+    let expected = 0;
+
+    addon.threadsafe_function_call(count, (i, name) => {
+      assert.strictEqual(i, expected);
+      assert.strictEqual(name, `call-${expected}`);
+
+      if (++expected === count) {
+        cb();
+      }
+    });
+  });
+
+  it("should drop pending calls of an aborted threadsafe function", function (cb) {
+    let called = false;
+
+    assert.deepEqual(
+      addon.threadsafe_function_abort(() => {
+        called = true;
+      }),
+      [true, true, true, true]
+    );
+
+    setTimeout(() => {
+      assert.strictEqual(called, false);
+      cb();
+    }, 10);
+  });
+
+  it("should reject a cancelled task with an AbortError", async function () {
+    const { promise, cancel } = addon.cancellable_task();
+
+    setTimeout(cancel, 10);
+
+    try {
+      await promise;
+      throw new Error("Did not reject");
+    } catch (err) {
+      assert.strictEqual(err.name, "AbortError");
+      assert.strictEqual(err.message, "This operation was aborted");
+    }
+  });
+
+  it("should cancel a synchronous loop from JavaScript", function () {
+    let calls = 0;
+
+    const steps = addon.cancellable_loop((cancel) => {
+      if (++calls === 3) {
+        cancel();
+        cancel();
+      }
+    });
+
+    assert.strictEqual(steps, 3);
+    assert.throws(() => addon.cancelled_token_throws(), /aborted/);
+  });
+
+  it("should run a deferred closure after returning to JavaScript", function (cb) {
+    let returned = false;
+
+    addon.defer_callback(() => {
+      assert.strictEqual(returned, true);
+      cb();
+    });
+
+    returned = true;
+  });
+
+  it("should run deferred closures in order after microtasks", function (cb) {
+    const events = [];
+
+    addon.defer_callback(() => events.push("first"));
+    Promise.resolve().then(() => events.push("microtask"));
+    addon.defer_callback(() => {
+      events.push("second");
+
+      try {
+        assert.deepEqual(events, ["microtask", "first", "second"]);
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+  });
+
   it("should be able to sum numbers on the libuv pool", async function () {
     const nums = new Float64Array(
       [...new Array(10000)].map(() => Math.random())
@@ -133,6 +300,132 @@ const assert = require("chai").assert;
     assert.strictEqual(expected, actual);
   });
 
+  it("should chain a promise with then", async function () {
+    const double = (n) => n * 2;
+    const recover = (err) => err.message;
+
+    assert.strictEqual(
+      await addon.promise_then(Promise.resolve(21), double),
+      42
+    );
+    const rejected = Promise.reject(new Error("oops"));
+
+    assert.strictEqual(
+      await addon.promise_then(rejected, double, recover),
+      "oops"
+    );
+  });
+
+  it("should forward rejections through then without a handler", async function () {
+    const err = new Error("oops");
+
+    try {
+      await addon.promise_then(Promise.reject(err), () => "unreachable");
+      assert.fail("should throw");
+    } catch (actual) {
+      assert.strictEqual(actual, err);
+    }
+  });
+
+  it("should chain a promise with catch", async function () {
+    const recover = (err) => err.message;
+
+    assert.strictEqual(
+      await addon.promise_catch(Promise.resolve(42), recover),
+      42
+    );
+    assert.strictEqual(
+      await addon.promise_catch(Promise.reject(new Error("oops")), recover),
+      "oops"
+    );
+  });
+
+  it("should run a queued microtask before the next task", function (cb) {
+    const order = [];
+
+    setTimeout(() => {
+      assert.deepEqual(order, ["sync", "promise", "microtask"]);
+      cb();
+    }, 0);
+
+    Promise.resolve().then(() => order.push("promise"));
+    addon.queue_microtask(() => order.push("microtask"));
+    order.push("sync");
+  });
+
+  it("should limit the concurrency of a task pool", async function () {
+    const running = await Promise.all(addon.task_pool_concurrency(2, 8));
+
+    assert.strictEqual(running.length, 8);
+    assert.ok(running.every((n) => n >= 1 && n <= 2));
+  });
+
+  it("should start queued pool tasks after a task panics", async function () {
+    const [panicked, queued] = addon.task_pool_panic();
+
+    try {
+      await panicked;
+
+      throw new Error("Did not throw");
+    } catch (err) {
+      assert.instanceOf(err, Error);
+      assert.instanceOf(err.panic, Error);
+      assert.strictEqual(err.panic.message, "Pooled task panicked");
+    }
+
+    assert.strictEqual(await queued, 42);
+  });
+
+  it("should cancel a task that has not started", function (cb) {
+    const [cancelled, cancelledAgain] = addon.task_cancel((completed) => {
+      try {
+        assert.strictEqual(completed, false);
+        cb();
+      } catch (err) {
+        cb(err);
+      }
+    });
+
+    assert.strictEqual(cancelled, true);
+    assert.strictEqual(cancelledAgain, false);
+  });
+
+  it("should deliver task progress before completing", async function () {
+    const events = [];
+    const count = await addon.task_progress(100, (i) => events.push(i));
+
+    assert.strictEqual(count, 100);
+    assert.deepEqual(
+      events,
+      [...new Array(100)].map((_, i) => i)
+    );
+  });
+
+  it("should report exceptions thrown by a task progress callback", async function () {
+    const err = new Error("progress");
+    const events = [];
+    const rejection = new Promise((resolve) => {
+      process.removeAllListeners("unhandledRejection");
+      process.once("unhandledRejection", resolve);
+    });
+
+    const count = await addon.task_progress(3, (i) => {
+      events.push(i);
+
+      if (i === 1) {
+        throw err;
+      }
+    });
+
+    const uncaught = await rejection;
+
+    assert.strictEqual(count, 3);
+    assert.deepEqual(events, [0, 1, 2]);
+    assert.instanceOf(uncaught, Error);
+    assert.ok(/exception/i.test(uncaught.message));
+    assert.strictEqual(uncaught.cause, err);
+  });
+
   it("should be able to resolve a promise from a rust thread", async function () {
     const nums = new Float64Array(
       [...new Array(10000)].map(() => Math.random())
@@ -160,6 +160,24 @@ describe("Typed arrays", function () {
     }
   });
 
+  it("gets an empty ArrayBuffer from an empty slice", function () {
+    var b = addon.return_array_buffer_from_slice(0);
+    assert.instanceOf(b, ArrayBuffer);
+    assert.strictEqual(b.byteLength, 0);
+  });
+
+  it("gets a Buffer copied from a slice", function () {
+    var b = addon.return_buffer_from_slice(16);
+    assert.instanceOf(b, Buffer);
+    assert.ok(b.equals(Buffer.from([...Array(16).keys()])));
+  });
+
+  it("gets an empty Buffer from an empty slice", function () {
+    var b = addon.return_buffer_from_slice(0);
+    assert.instanceOf(b, Buffer);
+    assert.strictEqual(b.length, 0);
+  });
+
   it("gets an external Buffer", function () {
     var expected = "String to copy";
     var buf = addon.return_external_buffer(expected);
@@ -425,6 +443,108 @@ describe("Typed arrays", function () {
     assert.strictEqual(addon.get_arraybuffer_byte_length(buf), 0);
   });
 
+  it("detaches an ArrayBuffer", function () {
+    var buf = new ArrayBuffer(16);
+    var arr = new Uint8Array(buf);
+
+    assert.strictEqual(addon.is_array_buffer_detached(buf), false);
+    assert.strictEqual(addon.detach_array_buffer(buf), 0);
+    assert.strictEqual(addon.is_array_buffer_detached(buf), true);
+    assert.strictEqual(buf.byteLength, 0);
+    assert.strictEqual(arr.length, 0);
+  });
+
+  it("borrows an empty slice from a typed array after it is detached", function () {
+    var arr = new Uint32Array(4);
+
+    assert.deepEqual(addon.detach_typed_array(arr), [0, 0]);
+    assert.strictEqual(arr.length, 0);
+  });
+
+  it("fails a dynamically checked borrow of a detached ArrayBuffer", function () {
+    var buf = new ArrayBuffer(16);
+
+    assert.strictEqual(addon.borrow_detached_array_buffer(buf), 16);
+
+    detach(buf);
+
+    assert.throws(
+      () => addon.borrow_detached_array_buffer(buf),
+      TypeError,
+      /detached/
+    );
+  });
+
+  it("checks if an ArrayBuffer was detached by a transfer", function () {
+    var buf = new ArrayBuffer(16);
+
+    detach(buf);
+
+    assert.strictEqual(addon.is_array_buffer_detached(buf), true);
+  });
+
+  it("throws when detaching an ArrayBuffer that cannot be detached", function () {
+    var memory = new WebAssembly.Memory({ initial: 1 });
+
+    assert.throws(() => addon.detach_array_buffer(memory.buffer), TypeError);
+    assert.strictEqual(addon.is_array_buffer_detached(memory.buffer), false);
+  });
+
+  it("creates a SharedArrayBuffer from a slice", function () {
+    var buf = addon.shared_array_buffer_from_slice(new Uint8Array([1, 2, 3]));
+
+    assert.instanceOf(buf, SharedArrayBuffer);
+    assert.deepEqual(Array.from(new Uint8Array(buf)), [1, 2, 3]);
+  });
+
+  it("atomically updates a SharedArrayBuffer", function () {
+    var buf = new SharedArrayBuffer(4);
+    var arr = new Uint8Array(buf);
+
+    Atomics.store(arr, 3, 255);
+
+    assert.strictEqual(addon.increment_shared_array_buffer(buf), 4);
+    assert.deepEqual(Array.from(arr), [1, 1, 1, 0]);
+    assert.strictEqual(
+      addon.increment_shared_array_buffer(new SharedArrayBuffer(0)),
+      0
+    );
+  });
+
+  it("does not treat an ArrayBuffer as a SharedArrayBuffer", function () {
+    assert.throws(
+      () => addon.increment_shared_array_buffer(new ArrayBuffer(4)),
+      TypeError
+    );
+  });
+
+  it("does not treat a forged SharedArrayBuffer as a SharedArrayBuffer", function () {
+    var buf = new ArrayBuffer(4);
+
+    Object.setPrototypeOf(buf, SharedArrayBuffer.prototype);
+
+    assert.instanceOf(buf, SharedArrayBuffer);
+    assert.throws(() => addon.increment_shared_array_buffer(buf), TypeError);
+    assert.throws(
+      () =>
+        addon.increment_shared_array_buffer(
+          Object.create(SharedArrayBuffer.prototype)
+        ),
+      TypeError
+    );
+  });
+
+  it("preserves a pending exception when checking for a SharedArrayBuffer", function () {
+    assert.throws(
+      () =>
+        addon.is_shared_array_buffer_while_throwing(() => {
+          throw new Error("original");
+        }, new SharedArrayBuffer(4)),
+      Error,
+      /original/
+    );
+  });
+
   function testDetach(
     arr,
     addonFn,
@@ -551,4 +671,72 @@ describe("Typed arrays", function () {
       assert.fail("region overrun should be validated when instantiating");
     } catch (expected) {}
   });
+
+  it("creates a DataView over a region of an ArrayBuffer", function () {
+    var buf = new ArrayBuffer(16);
+    var view = addon.return_data_view(buf, 4, 8);
+
+    assert.instanceOf(view, DataView);
+    assert.strictEqual(view.buffer, buf);
+    assert.strictEqual(view.byteOffset, 4);
+    assert.strictEqual(view.byteLength, 8);
+    assert.deepEqual(addon.get_data_view_info(view), {
+      buffer: buf,
+      byteOffset: 4,
+      byteLength: 8,
+    });
+  });
+
+  it("throws a RangeError when a DataView does not fit in its buffer", function () {
+    var buf = new ArrayBuffer(16);
+
+    assert.throws(() => addon.return_data_view(buf, 12, 8), RangeError);
+  });
+
+  it("only accepts a DataView as a JsDataView", function () {
+    var buf = new ArrayBuffer(16);
+
+    assert.throws(() => addon.get_data_view_info(buf), TypeError);
+    assert.throws(() => addon.get_data_view_info(new Uint8Array(buf)), TypeError);
+  });
+
+  it("reads mixed values from a DataView", function () {
+    for (var littleEndian of [true, false]) {
+      var view = new DataView(new ArrayBuffer(20), 2, 16);
+
+      view.setUint8(0, 200);
+      view.setInt8(1, -3);
+      view.setUint16(2, 0xabcd, littleEndian);
+      view.setUint32(4, 0x01020304, littleEndian);
+      view.setFloat64(8, -2.25, littleEndian);
+
+      assert.deepEqual(addon.read_data_view_header(view, littleEndian), [
+        200, -3, 0xabcd, 0x01020304, -2.25,
+      ]);
+    }
+  });
+
+  it("writes mixed values to a DataView", function () {
+    for (var littleEndian of [true, false]) {
+      var view = new DataView(new ArrayBuffer(20), 2, 16);
+
+      addon.write_data_view_header(view, littleEndian);
+
+      assert.strictEqual(view.getUint8(0), 0xff);
+      assert.strictEqual(view.getInt8(1), -1);
+      assert.strictEqual(view.getUint16(2, littleEndian), 0x1234);
+      assert.strictEqual(view.getUint32(4, littleEndian), 0xdeadbeef);
+      assert.strictEqual(view.getFloat64(8, littleEndian), 1.5);
+    }
+  });
+
+  it("throws a RangeError when accessing a DataView out of bounds", function () {
+    var view = new DataView(new ArrayBuffer(16), 4, 8);
+
+    assert.strictEqual(addon.read_data_view_uint32(view, 4), 0);
+    assert.throws(() => addon.read_data_view_uint32(view, 5), RangeError);
+    assert.throws(() => addon.read_data_view_uint32(view, 8), RangeError);
+    assert.throws(() => addon.write_data_view_float64(view, 1, 0), RangeError);
+    assert.throws(() => view.getUint32(5, true), RangeError);
+  });
 });
@@ -89,5 +89,235 @@ describe("type checks", function () {
     assert(addon.strict_equals(o1, o1));
     assert(!addon.strict_equals(o1, o2));
     assert(!addon.strict_equals(o1, 17));
+    assert(!addon.strict_equals(NaN, NaN));
+    assert(addon.strict_equals(0, -0));
+    assert(!addon.strict_equals(0, "0"));
+    assert(!addon.strict_equals(null, undefined));
+  });
+
+  it("ptr_equals", function () {
+    const o1 = {};
+    const o2 = {};
+
+    assert.deepEqual(addon.ptr_equals(o1, o2), [true, false]);
+  });
+
+  it("loose_equals", function () {
+    const o1 = {};
+    const o2 = {};
+    const values = [0, -0, 1, NaN, "", "0", "1", true, false, null, undefined];
+
+    assert(addon.loose_equals(o1, o1));
+    assert(!addon.loose_equals(o1, o2));
+    assert(addon.loose_equals(1n, "1"));
+    assert(addon.loose_equals([1], 1));
+    assert(addon.loose_equals({ valueOf: () => 42 }, 42));
+
+    for (const a of values) {
+      for (const b of values) {
+        assert.strictEqual(addon.loose_equals(a, b), a == b, `${a} == ${b}`);
+      }
+    }
+  });
+
+  it("loose_equals propagates exceptions from conversions", function () {
+    const err = new Error("valueOf");
+    const o = {
+      valueOf() {
+        throw err;
+      },
+    };
+
+    assert.throws(() => addon.loose_equals(o, 1), err);
+  });
+
+  it("constructor_name", function () {
+    class Foo {}
+
+    assert.strictEqual(addon.constructor_name(new Foo()), "Foo");
+    assert.strictEqual(addon.constructor_name(Buffer.alloc(1)), "Buffer");
+    assert.strictEqual(addon.constructor_name([]), "Array");
+    assert.strictEqual(addon.constructor_name(() => {}), "Function");
+    assert.strictEqual(addon.constructor_name(Object.create(null)), undefined);
+    assert.strictEqual(addon.constructor_name(17), undefined);
+    assert.strictEqual(addon.constructor_name("hello"), undefined);
+    assert.strictEqual(addon.constructor_name(null), undefined);
+  });
+
+  // `util.inspect` is only available to addons with `process.getBuiltinModule`
+  const canInspect = typeof process.getBuiltinModule === "function";
+
+  it("to_debug_string", function () {
+    const { inspect } = require("util");
+    const calls = [];
+    const hostile = {
+      [inspect.custom]() {
+        calls.push("inspect");
+        throw new Error("inspect");
+      },
+      toString() {
+        calls.push("toString");
+        throw new Error("toString");
+      },
+      get getter() {
+        calls.push("getter");
+        throw new Error("getter");
+      },
+    };
+    const proxy = new Proxy(
+      {},
+      {
+        get() {
+          calls.push("get");
+        },
+        ownKeys() {
+          calls.push("ownKeys");
+          return [];
+        },
+      }
+    );
+
+    assert.strictEqual(addon.to_debug_string("hi"), "'hi'");
+    assert.strictEqual(addon.to_debug_string(17), "17");
+    assert.strictEqual(addon.to_debug_string(null), "null");
+    assert.strictEqual(
+      addon.to_debug_string({ a: { b: { c: 1 } }, d: [1, 2] }),
+      canInspect ? "{ a: { b: [Object] }, d: [ 1, 2 ] }" : "[Object]"
+    );
+    assert.strictEqual(typeof addon.to_debug_string(hostile), "string");
+    assert.strictEqual(typeof addon.to_debug_string(proxy), "string");
+    assert.deepEqual(calls, []);
+  });
+
+  it("includes the value in a downcast error", function () {
+    assert.throws(
+      () => addon.downcast_to_number("17"),
+      TypeError,
+      "failed to downcast any to number: '17'"
+    );
+    assert.throws(
+      () => addon.downcast_to_number({ n: 17 }),
+      TypeError,
+      canInspect
+        ? "failed to downcast any to number: { n: 17 }"
+        : "failed to downcast any to number: [Object]"
+    );
+  });
+
+  it("instance_of", function () {
+    class Base {}
+    class Derived extends Base {}
+    class Even {
+      static [Symbol.hasInstance](n) {
+        return n % 2 === 0;
+      }
+    }
+
+    assert(addon.instance_of(new Derived(), Derived));
+    assert(addon.instance_of(new Derived(), Base));
+    assert(addon.instance_of(new Derived(), Object));
+    assert(!addon.instance_of(new Base(), Derived));
+    assert(!addon.instance_of(17, Number));
+    assert(addon.instance_of(2, Even));
+    assert(!addon.instance_of(3, Even));
+    assert.throws(() => addon.instance_of({}, {}), TypeError);
+    assert.throws(() => addon.instance_of({}, 17), TypeError);
+  });
+
+  it("is_symbol", function () {
+    assert(addon.is_symbol(Symbol()));
+    assert(addon.is_symbol(Symbol.iterator));
+    assert(!addon.is_symbol("symbol"));
+    assert(!addon.is_symbol(Object(Symbol())));
+  });
+
+  it("create_symbol", function () {
+    const a = addon.create_symbol("neon");
+    const b = addon.create_symbol("neon");
+
+    assert.strictEqual(typeof a, "symbol");
+    assert.strictEqual(a.description, "neon");
+    assert.notStrictEqual(a, b);
+    assert.strictEqual(addon.create_symbol().description, undefined);
+  });
+
+  it("symbol_for", function () {
+    assert.strictEqual(addon.symbol_for("neon"), Symbol.for("neon"));
+    assert.strictEqual(addon.symbol_for("neon"), addon.symbol_for("neon"));
+  });
+
+  it("symbol_description", function () {
+    assert.strictEqual(addon.symbol_description(Symbol("neon")), "neon");
+    assert.strictEqual(addon.symbol_description(Symbol("")), "");
+    assert.strictEqual(addon.symbol_description(Symbol()), undefined);
+    assert.strictEqual(
+      addon.symbol_description(Symbol.asyncIterator),
+      "Symbol.asyncIterator"
+    );
+  });
+
+  it("get_well_known_symbol_property", function () {
+    const array = [];
+    const iterable = { async *[Symbol.asyncIterator]() {} };
+
+    assert.strictEqual(
+      addon.get_well_known_symbol_property(array, "iterator"),
+      array[Symbol.iterator]
+    );
+    assert.strictEqual(
+      addon.get_well_known_symbol_property(iterable, "asyncIterator"),
+      iterable[Symbol.asyncIterator]
+    );
+    assert.throws(
+      () => addon.get_well_known_symbol_property(array, "notASymbol"),
+      TypeError
+    );
+  });
+
+  it("downcast_or", function () {
+    assert.strictEqual(addon.downcast_or_default("hello"), "hello");
+    assert.strictEqual(addon.downcast_or_default(42), "default");
+    assert.strictEqual(addon.downcast_or_default(undefined), "default");
+  });
+
+  it("downcast_or_else", function () {
+    assert.strictEqual(addon.downcast_or_else_length({ length: 5 }), 5);
+    assert.strictEqual(addon.downcast_or_else_length({ length: "5" }), 0);
+    assert.strictEqual(addon.downcast_or_else_length({}), 0);
+  });
+
+  it("run_script", function () {
+    assert.strictEqual(addon.run_script("1 + 2"), 3);
+    assert.strictEqual(addon.run_script("globalThis"), globalThis);
+    assert.strictEqual(addon.run_script("typeof require"), "undefined");
+
+    addon.run_script("var runScriptGlobal = 42;");
+    assert.strictEqual(globalThis.runScriptGlobal, 42);
+    delete globalThis.runScriptGlobal;
+
+    assert.throws(() => addon.run_script("1 +"), SyntaxError);
+    assert.throws(() => addon.run_script("throw new RangeError()"), RangeError);
+  });
+
+  it("type_of", function () {
+    const cases = [
+      [undefined, "Undefined"],
+      [null, "Null"],
+      [true, "Boolean"],
+      [42, "Number"],
+      ["hello", "String"],
+      [Symbol(), "Symbol"],
+      [{}, "Object"],
+      [[], "Object"],
+      [new Date(), "Object"],
+      [() => {}, "Function"],
+      [42n, "BigInt"],
+    ];
+
+    for (const [value, expected] of cases) {
+      assert.strictEqual(addon.type_of(value), expected);
+    }
+
+    assert.strictEqual(addon.boxed_type_of(), "External");
   });
 });
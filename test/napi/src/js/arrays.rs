@@ -4,6 +4,12 @@ pub fn return_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
     Ok(cx.empty_array())
 }
 
+pub fn return_js_array_with_length(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+
+    Ok(cx.array(len))
+}
+
 pub fn return_js_array_with_number(mut cx: FunctionContext) -> JsResult<JsArray> {
     let array: Handle<JsArray> = JsArray::new(&mut cx, 1);
     let n = cx.number(9000.0);
@@ -24,3 +30,97 @@ pub fn read_js_array(mut cx: FunctionContext) -> JsResult<JsValue> {
 
     Ok(first_element)
 }
+
+pub fn js_array_all_numbers(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let all_numbers = array.all_of::<JsNumber, _>(&mut cx)?;
+
+    Ok(cx.boolean(all_numbers))
+}
+
+pub fn concat_js_arrays(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let others = (1..cx.len())
+        .map(|i| cx.argument::<JsArray>(i))
+        .collect::<NeonResult<Vec<_>>>()?;
+
+    array.concat(&mut cx, &others)
+}
+
+pub fn flat_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let depth = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+
+    array.flat(&mut cx, depth)
+}
+
+pub fn js_array_from_arguments(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let args = (0..cx.len())
+        .map(|i| cx.argument::<JsValue>(i))
+        .collect::<NeonResult<Vec<_>>>()?;
+
+    JsArray::from_slice(&mut cx, &args)
+}
+
+pub fn copy_into_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let start = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let values = (2..cx.len())
+        .map(|i| cx.argument::<JsValue>(i))
+        .collect::<NeonResult<Vec<_>>>()?;
+
+    array.copy_from(&mut cx, start, &values)?;
+
+    Ok(array)
+}
+
+pub fn sum_js_array_numbers(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+    let sum = array
+        .to_typed_vec::<JsNumber, _>(&mut cx)?
+        .into_iter()
+        .fold(0.0, |sum, n| sum + n.value(&mut cx));
+
+    Ok(cx.number(sum))
+}
+
+pub fn iter_js_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array = cx.argument::<JsArray>(0)?;
+    let copy = cx.empty_array();
+    let mut iter = array.iter(&cx);
+    let mut i = 0;
+
+    while let Some(v) = iter.try_next(&mut cx)? {
+        copy.set(&mut cx, i, v)?;
+        i += 1;
+    }
+
+    Ok(copy)
+}
+
+pub fn sort_js_array_by_key(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let array = cx.argument::<JsArray>(0)?;
+    let key = cx.argument::<JsFunction>(1)?;
+
+    array.sort_by(&mut cx, |cx, a, b| {
+        let a = key.call_with(cx).arg(a).apply::<JsNumber, _>(cx)?.value(cx);
+        let b = key.call_with(cx).arg(b).apply::<JsNumber, _>(cx)?.value(cx);
+
+        Ok(a.total_cmp(&b))
+    })?;
+
+    Ok(array)
+}
+
+// Baseline for `bench/arrays.js`: downcasts each element of `to_vec`
+pub fn js_array_all_numbers_naive(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let array: Handle<JsArray> = cx.argument(0)?;
+
+    for v in array.to_vec(&mut cx)? {
+        if v.downcast::<JsNumber, _>(&mut cx).is_err() {
+            return Ok(cx.boolean(false));
+        }
+    }
+
+    Ok(cx.boolean(true))
+}
@@ -1,6 +1,6 @@
-use std::cell::RefCell;
+use std::{cell::RefCell, error::Error, sync::Mutex};
 
-use neon::prelude::*;
+use neon::{prelude::*, types::FinalizeError};
 
 pub struct Person {
     name: String,
@@ -69,6 +69,99 @@ pub fn ref_person_fail(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+pub fn ref_person_set_name_with(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let person = cx.argument::<JsBox<RefCell<Person>>>(0)?;
+    let f = cx.argument::<JsFunction>(1)?;
+    let mut person = person.borrow_mut_or_throw(&mut cx)?;
+    let name = f.call_with(&cx).apply::<JsString, _>(&mut cx)?;
+
+    person.set_name(name.value(&mut cx));
+
+    Ok(cx.undefined())
+}
+
+pub fn ref_person_try_greet(mut cx: FunctionContext) -> JsResult<JsString> {
+    let person = cx.argument::<JsBox<RefCell<Person>>>(0)?;
+    let greeting = person.borrow_or_throw(&mut cx)?.greet();
+
+    Ok(cx.string(greeting))
+}
+
 pub fn external_unit(mut cx: FunctionContext) -> JsResult<JsBox<()>> {
     Ok(cx.boxed(()))
 }
+
+pub fn box_kind(mut cx: FunctionContext) -> JsResult<JsString> {
+    let registry = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsString>(1)?;
+    let value = registry.get_value(&mut cx, key)?;
+
+    let kind = if value.is_a::<JsBox<Person>, _>(&mut cx) {
+        "person"
+    } else if value.downcast::<JsBox<()>, _>(&mut cx).is_ok() {
+        "unit"
+    } else {
+        "other"
+    };
+
+    Ok(cx.string(kind))
+}
+
+static FINALIZE_ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub struct FailingFinalizer {
+    panic: bool,
+}
+
+impl Finalize for FailingFinalizer {
+    fn try_finalize<'a, C: Context<'a>>(
+        self,
+        _: &mut C,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if self.panic {
+            panic!("finalizer panicked");
+        }
+
+        Err("finalizer failed".into())
+    }
+}
+
+pub fn box_failing_finalizer(mut cx: FunctionContext) -> JsResult<JsBox<FailingFinalizer>> {
+    let panic = cx.argument::<JsBoolean>(0)?.value(&mut cx);
+
+    FinalizeError::set_hook(|err| FINALIZE_ERRORS.lock().unwrap().push(err.to_string()));
+
+    Ok(cx.boxed(FailingFinalizer { panic }))
+}
+
+// Wrappers must forward `try_finalize` to the failing value
+pub fn box_wrapped_failing_finalizer(
+    mut cx: FunctionContext,
+) -> JsResult<JsBox<Vec<Option<Box<FailingFinalizer>>>>> {
+    FinalizeError::set_hook(|err| FINALIZE_ERRORS.lock().unwrap().push(err.to_string()));
+
+    Ok(cx.boxed(vec![
+        None,
+        Some(Box::new(FailingFinalizer { panic: false })),
+    ]))
+}
+
+pub fn take_finalize_errors(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let errors = std::mem::take(&mut *FINALIZE_ERRORS.lock().unwrap());
+
+    let arr = cx.empty_array();
+
+    for (i, err) in errors.iter().enumerate() {
+        let err = cx.string(err);
+        arr.set(&mut cx, i as u32, err)?;
+    }
+
+    Ok(arr)
+}
+
+pub fn adjust_external_memory(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let change = cx.argument::<JsNumber>(0)?.value(&mut cx) as i64;
+    let total = cx.adjust_external_memory(change);
+
+    Ok(cx.number(total as f64))
+}
@@ -4,3 +4,30 @@ pub fn to_string(mut cx: FunctionContext) -> JsResult<JsString> {
     let arg: Handle<JsValue> = cx.argument(0)?;
     arg.to_string(&mut cx)
 }
+
+pub fn argument_coerced_to_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    cx.argument_coerced::<JsNumber>(0)
+}
+
+pub fn argument_coerced_to_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    cx.argument_coerced::<JsString>(0)
+}
+
+pub fn argument_coerced_to_boolean(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    cx.argument_coerced::<JsBoolean>(0)
+}
+
+pub fn coerce_to_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    arg.coerce_to_string(&mut cx)
+}
+
+pub fn coerce_to_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    arg.coerce_to_number(&mut cx)
+}
+
+pub fn coerce_to_bool(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let arg: Handle<JsValue> = cx.argument(0)?;
+    arg.coerce_to_bool(&mut cx)
+}
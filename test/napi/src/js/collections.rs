@@ -0,0 +1,137 @@
+use neon::{
+    prelude::*,
+    types::{JsMap, JsRegExp, JsSet},
+};
+
+pub fn map_from_entries(mut cx: FunctionContext) -> JsResult<JsMap> {
+    let entries = cx
+        .argument::<JsArray>(0)?
+        .to_typed_vec::<JsArray, _>(&mut cx)?;
+    let map = JsMap::new(&mut cx)?;
+
+    for entry in entries {
+        let key = entry.get_value(&mut cx, 0)?;
+        let value = entry.get_value(&mut cx, 1)?;
+
+        map.set(&mut cx, key, value)?;
+    }
+
+    Ok(map)
+}
+
+pub fn map_get(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let map = cx.argument::<JsMap>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+
+    map.get(&mut cx, key)
+}
+
+pub fn map_has(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let map = cx.argument::<JsMap>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let has = map.has(&mut cx, key)?;
+
+    Ok(cx.boolean(has))
+}
+
+pub fn map_delete(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let map = cx.argument::<JsMap>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let deleted = map.delete(&mut cx, key)?;
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn map_size(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let map = cx.argument::<JsMap>(0)?;
+    let size = map.size(&mut cx)?;
+
+    Ok(cx.number(size as f64))
+}
+
+pub fn map_entries(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let map = cx.argument::<JsMap>(0)?;
+    let entries = map.entries(&mut cx)?;
+    let result = cx.empty_array();
+
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        let entry = JsArray::from_slice(&mut cx, &[key, value])?;
+
+        result.set(&mut cx, i as u32, entry)?;
+    }
+
+    Ok(result)
+}
+
+pub fn is_map(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let value = cx.argument::<JsValue>(0)?;
+    let is_map = value.is_a::<JsMap, _>(&mut cx);
+
+    Ok(cx.boolean(is_map))
+}
+
+pub fn set_from_values(mut cx: FunctionContext) -> JsResult<JsSet> {
+    let values = cx.argument::<JsArray>(0)?.to_vec(&mut cx)?;
+    let set = JsSet::new(&mut cx)?;
+
+    for value in values {
+        set.add(&mut cx, value)?;
+    }
+
+    Ok(set)
+}
+
+pub fn set_has(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let set = cx.argument::<JsSet>(0)?;
+    let value = cx.argument::<JsValue>(1)?;
+    let has = set.has(&mut cx, value)?;
+
+    Ok(cx.boolean(has))
+}
+
+pub fn set_delete(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let set = cx.argument::<JsSet>(0)?;
+    let value = cx.argument::<JsValue>(1)?;
+    let deleted = set.delete(&mut cx, value)?;
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn set_size(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let set = cx.argument::<JsSet>(0)?;
+    let size = set.size(&mut cx)?;
+
+    Ok(cx.number(size as f64))
+}
+
+pub fn set_to_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let set = cx.argument::<JsSet>(0)?;
+    let values = set.to_vec(&mut cx)?;
+
+    JsArray::from_slice(&mut cx, &values)
+}
+
+pub fn regexp_new(mut cx: FunctionContext) -> JsResult<JsRegExp> {
+    let source = cx.argument::<JsString>(0)?.value(&mut cx);
+    let flags = cx.argument::<JsString>(1)?.value(&mut cx);
+
+    JsRegExp::new(&mut cx, &source, &flags)
+}
+
+pub fn regexp_test(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let input = cx.argument::<JsString>(1)?;
+    let matched = re.test(&mut cx, input)?;
+
+    Ok(cx.boolean(matched))
+}
+
+pub fn regexp_exec(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let re = cx.argument::<JsRegExp>(0)?;
+    let input = cx.argument::<JsString>(1)?;
+
+    match re.exec(&mut cx, input)? {
+        Some(m) => Ok(m.upcast()),
+        None => Ok(cx.null().upcast()),
+    }
+}
@@ -1,4 +1,5 @@
 use neon::{prelude::*, types::JsDate};
+use std::time::{Duration, UNIX_EPOCH};
 
 pub fn create_date(mut cx: FunctionContext) -> JsResult<JsDate> {
     let date = JsDate::new_lossy(&mut cx, 31415);
@@ -91,3 +92,15 @@ pub fn get_date_value(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let value = date.value(&mut cx);
     Ok(cx.number(value))
 }
+
+pub fn create_date_from_system_time(mut cx: FunctionContext) -> JsResult<JsDate> {
+    let millis = cx.argument::<JsNumber>(0)?.value(&mut cx);
+    let offset = Duration::from_millis(millis.abs() as u64);
+    let time = if millis < 0.0 {
+        UNIX_EPOCH - offset
+    } else {
+        UNIX_EPOCH + offset
+    };
+
+    JsDate::from_system_time(&mut cx, time).or_throw(&mut cx)
+}
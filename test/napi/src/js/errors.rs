@@ -24,6 +24,27 @@ pub fn throw_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     cx.throw_error(msg)
 }
 
+pub fn throw_error_with_cause(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+    let cause = cx.argument::<JsValue>(1)?;
+    let err = JsError::range_error(&mut cx, msg)?.with_cause(&mut cx, cause)?;
+
+    cx.throw(err)
+}
+
+pub fn throw_error_with_code(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let port = cx.argument::<JsString>(0)?.value(&mut cx);
+    let port = port.parse::<u16>().or_else(|_| {
+        let err = cx.error(format!("invalid port: {port}"))?;
+        let code = cx.string("ERR_INVALID_PORT");
+
+        err.set(&mut cx, "code", code)?;
+        cx.throw(err)
+    })?;
+
+    Ok(cx.number(port))
+}
+
 pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
     let s = cx.string("hi");
     if let Err(e) = s.downcast::<JsNumber, _>(&mut cx) {
@@ -32,3 +53,50 @@ pub fn downcast_error(mut cx: FunctionContext) -> JsResult<JsString> {
         panic!()
     }
 }
+
+pub fn error_name_and_message(mut cx: FunctionContext) -> JsResult<JsString> {
+    let err = cx.argument::<JsError>(0)?;
+    let name = err.name(&mut cx)?;
+    let message = err.message(&mut cx)?;
+
+    Ok(cx.string(format!("{name}: {message}")))
+}
+
+pub fn error_stack(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let err = cx.argument::<JsError>(0)?;
+
+    match err.stack(&mut cx)? {
+        Some(stack) => Ok(cx.string(stack).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn capture_error(mut cx: FunctionContext) -> JsResult<JsError> {
+    let msg = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsError::capture(&mut cx, msg)
+}
+
+#[derive(Debug)]
+struct ConfigError(std::num::ParseIntError);
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("invalid config")
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+pub fn throw_rust_error(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let input = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    match input.parse::<u32>() {
+        Ok(_) => Ok(cx.undefined()),
+        Err(err) => cx.throw_rust_error(ConfigError(err)),
+    }
+}
@@ -131,8 +131,48 @@ pub fn extract_json_sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(nums.into_iter().sum::<f64>()))
 }
 
+pub fn extract_json_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let Json::<String>(s) = cx.args()?;
+
+    Ok(cx.string(s))
+}
+
 pub fn extract_single_add_one(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let n: f64 = cx.args()?;
 
     Ok(cx.number(n + 1.0))
 }
+
+pub fn extract_variadic_sum(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let nums = cx.args_as::<f64>()?;
+
+    Ok(cx.number(nums.into_iter().fold(0.0, |sum, n| sum + n)))
+}
+
+pub fn extract_variadic_join(mut cx: FunctionContext) -> JsResult<JsString> {
+    let strings = cx.args_as::<String>()?;
+
+    Ok(cx.string(strings.join(" ")))
+}
+
+pub fn variadic_max(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let max = cx
+        .args_vec::<JsNumber>()?
+        .into_iter()
+        .map(|n| n.value(&mut cx))
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    Ok(cx.number(max))
+}
+
+pub fn variadic_type_names(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let names = cx.empty_array();
+
+    for (i, arg) in cx.args_iter().enumerate() {
+        let name = arg.to_string(&mut cx)?;
+
+        names.set(&mut cx, i as u32, name)?;
+    }
+
+    Ok(names)
+}
@@ -9,6 +9,12 @@ pub fn return_js_function(mut cx: FunctionContext) -> JsResult<JsFunction> {
     JsFunction::new(&mut cx, add1)
 }
 
+pub fn return_named_js_function(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsFunction::with_name(&mut cx, &name, |mut cx| Ok(cx.undefined()))
+}
+
 pub fn call_js_function(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let f = cx.argument::<JsFunction>(0)?;
     let args = [cx.number(16.0).upcast()];
@@ -70,6 +76,29 @@ pub fn call_js_function_with_four_args(mut cx: FunctionContext) -> JsResult<JsNu
         .apply(&mut cx)
 }
 
+pub fn call_js_function_with_vec_args(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let len = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let args = (0..len).map(|n| cx.number(n as f64)).collect::<Vec<_>>();
+
+    f.call_with(&cx).args(args).apply(&mut cx)
+}
+
+pub fn call_js_function_with_slice_args(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let args = [cx.string("a"), cx.string("b")];
+
+    f.call_with(&cx).args(&args[..]).apply(&mut cx)
+}
+
+pub fn bind_js_function(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let this = cx.argument::<JsValue>(1)?;
+    let args = cx.args_vec::<JsValue>()?;
+
+    f.bind(&cx).this(this).args(&args[2..]).build(&mut cx)
+}
+
 pub fn call_js_function_with_custom_this(mut cx: FunctionContext) -> JsResult<JsObject> {
     let custom_this = cx.empty_object();
     let secret = cx.number(42.0);
@@ -227,6 +256,28 @@ pub fn call_and_catch(mut cx: FunctionContext) -> JsResult<JsValue> {
         .unwrap_or_else(|err| err))
 }
 
+pub fn call_and_rethrow(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+
+    match cx.try_catch(|cx| f.call_with(cx).apply(cx)) {
+        Ok(v) => Ok(v),
+        Err(err) => cx.throw(err),
+    }
+}
+
+pub fn call_with_retry(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f: Handle<JsFunction> = cx.argument(0)?;
+    let attempts = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+
+    for _ in 1..attempts {
+        if let Ok(v) = cx.try_catch(|cx| f.call_with(cx).apply(cx)) {
+            return Ok(v);
+        }
+    }
+
+    f.call_with(&cx).apply(&mut cx)
+}
+
 pub fn get_number_or_default(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let n = cx
         .try_catch(|cx| Ok(cx.argument::<JsNumber>(0)?.value(cx)))
@@ -314,3 +365,23 @@ pub fn caller_with_drop_callback(mut cx: FunctionContext) -> JsResult<JsFunction
         callback.f.to_inner(&mut cx).call(&mut cx, this, args)
     })
 }
+
+pub fn iterate_range(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let end = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+
+    cx.iterator_from((0..end).map(f64::from))
+}
+
+pub fn get_function_name(mut cx: FunctionContext) -> JsResult<JsString> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let name = f.name(&mut cx)?;
+
+    Ok(cx.string(name))
+}
+
+pub fn get_function_length(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let length = f.length(&mut cx)?;
+
+    Ok(cx.number(length))
+}
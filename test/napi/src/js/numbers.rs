@@ -39,3 +39,47 @@ pub fn accept_and_return_negative_js_number(mut cx: FunctionContext) -> JsResult
     let number: Handle<JsNumber> = cx.argument(0)?;
     Ok(number)
 }
+
+pub fn number_to_integers(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let n = cx.argument::<JsNumber>(0)?;
+    let i32 = n.value_i32(&mut cx);
+    let u32 = n.value_u32(&mut cx);
+    let i64 = n.value_i64(&mut cx);
+
+    let result = cx.empty_array();
+    let i32 = cx.number(i32);
+    let u32 = cx.number(u32);
+    let i64 = cx.string(i64.to_string());
+
+    result.set(&mut cx, 0, i32)?;
+    result.set(&mut cx, 1, u32)?;
+    result.set(&mut cx, 2, i64)?;
+
+    Ok(result)
+}
+
+pub fn number_try_value_i64(mut cx: FunctionContext) -> JsResult<JsString> {
+    let n = cx.argument::<JsNumber>(0)?;
+    let n = n.try_value_i64(&mut cx).or_throw(&mut cx)?;
+
+    Ok(cx.string(n.to_string()))
+}
+
+pub fn number_predicates(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let n = cx.argument::<JsNumber>(0)?;
+    let predicates = [
+        n.is_integer(&mut cx),
+        n.is_safe_integer(&mut cx),
+        n.is_nan(&mut cx),
+        n.is_finite(&mut cx),
+    ];
+    let result = cx.empty_array();
+
+    for (i, predicate) in predicates.into_iter().enumerate() {
+        let predicate = cx.boolean(predicate);
+
+        result.set(&mut cx, i as u32, predicate)?;
+    }
+
+    Ok(result)
+}
@@ -1,11 +1,29 @@
 use std::borrow::Cow;
 
-use neon::{prelude::*, types::buffer::TypedArray};
+use neon::{handle::Reference, object::PropertyAttributes, prelude::*, types::buffer::TypedArray};
 
 pub fn return_js_global_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.global_object())
 }
 
+pub fn require_global_function(mut cx: FunctionContext) -> JsResult<JsFunction> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.require_global(&name)
+}
+
+pub fn json_parse(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let s = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.json_parse(&s)
+}
+
+pub fn json_stringify(mut cx: FunctionContext) -> JsResult<JsString> {
+    let v = cx.argument::<JsValue>(0)?;
+
+    cx.json_stringify(v)
+}
+
 pub fn return_js_object(mut cx: FunctionContext) -> JsResult<JsObject> {
     Ok(cx.empty_object())
 }
@@ -49,6 +67,20 @@ pub fn seal_js_object(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     }
 }
 
+pub fn is_frozen_js_object(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let is_frozen = obj.is_frozen(&mut cx)?;
+
+    Ok(cx.boolean(is_frozen))
+}
+
+pub fn is_sealed_js_object(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let is_sealed = obj.is_sealed(&mut cx)?;
+
+    Ok(cx.boolean(is_sealed))
+}
+
 // Accepts either a `JsString` or `JsBuffer` and returns the contents as
 // as bytes; avoids copying.
 fn get_bytes<'cx, 'a, C>(cx: &'a mut C, v: Handle<JsValue>) -> NeonResult<Cow<'a, [u8]>>
@@ -95,3 +127,211 @@ pub fn call_symbol_method(mut cx: FunctionContext) -> JsResult<JsString> {
     let sym: Handle<JsValue> = cx.argument::<JsValue>(1)?;
     obj.call_method_with(&mut cx, sym)?.apply(&mut cx)
 }
+
+pub fn call_method(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let method = cx.argument::<JsValue>(1)?;
+    let args = cx.argument::<JsArray>(2)?.to_vec(&mut cx)?;
+
+    obj.call_method(&mut cx, method, args)
+}
+
+type BoxedReference = JsBox<Reference<JsObject>>;
+
+pub fn create_reference(mut cx: FunctionContext) -> JsResult<BoxedReference> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let count = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let reference = Reference::new(&mut cx, &*obj, count);
+
+    Ok(cx.boxed(reference))
+}
+
+pub fn downgrade_root(mut cx: FunctionContext) -> JsResult<BoxedReference> {
+    let root = cx.argument::<JsObject>(0)?.root(&mut cx);
+    let reference = root.downgrade(&mut cx);
+
+    root.drop(&mut cx);
+
+    Ok(cx.boxed(reference))
+}
+
+pub fn reference_ref(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let reference = cx.argument::<BoxedReference>(0)?;
+    let count = reference.reference(&mut cx);
+
+    Ok(cx.number(count))
+}
+
+pub fn reference_unref(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let reference = cx.argument::<BoxedReference>(0)?;
+    let count = reference.unref(&mut cx);
+
+    Ok(cx.number(count))
+}
+
+pub fn reference_get(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let reference = cx.argument::<BoxedReference>(0)?;
+
+    match reference.to_inner(&mut cx) {
+        Some(obj) => Ok(obj.upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn define_data_property(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsString>(1)?.value(&mut cx);
+    let value = cx.argument::<JsValue>(2)?;
+    let flags = cx.argument::<JsObject>(3)?;
+    let attributes = PropertyAttributes {
+        writable: flags
+            .get::<JsBoolean, _, _>(&mut cx, "writable")?
+            .value(&mut cx),
+        enumerable: flags
+            .get::<JsBoolean, _, _>(&mut cx, "enumerable")?
+            .value(&mut cx),
+        configurable: flags
+            .get::<JsBoolean, _, _>(&mut cx, "configurable")?
+            .value(&mut cx),
+    };
+
+    obj.define_data_property(&mut cx, &key, value, attributes)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn define_counter(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.empty_object();
+    let count = std::rc::Rc::new(std::cell::Cell::new(0.0));
+
+    let getter = JsFunction::new(&mut cx, {
+        let count = count.clone();
+
+        move |mut cx| {
+            count.set(count.get() + 1.0);
+            Ok(cx.number(count.get()))
+        }
+    })?;
+
+    let setter = JsFunction::new(&mut cx, move |mut cx| {
+        count.set(cx.argument::<JsNumber>(0)?.value(&mut cx));
+        Ok(cx.undefined())
+    })?;
+
+    obj.define_accessor(&mut cx, "count", getter, Some(setter))?;
+
+    let getter = JsFunction::new(&mut cx, |mut cx| Ok(cx.string("read-only")))?;
+
+    obj.define_accessor(&mut cx, "readOnly", getter, None)?;
+
+    Ok(obj)
+}
+
+pub fn create_with_prototype(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let proto = cx.argument::<JsValue>(0)?;
+
+    cx.object_with_prototype(proto)
+}
+
+pub fn create_null_prototype_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+    cx.null_prototype_object()
+}
+
+pub fn get_prototype(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+
+    obj.get_prototype(&mut cx)
+}
+
+pub fn set_prototype(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let proto = cx.argument::<JsValue>(1)?;
+
+    obj.set_prototype(&mut cx, proto)?;
+
+    Ok(obj)
+}
+
+pub fn assign_object(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let source = cx.argument::<JsObject>(1)?;
+
+    obj.assign(&mut cx, source)?;
+
+    Ok(obj)
+}
+
+pub fn object_from_entries(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let one = cx.number(1);
+    let two = cx.number(2);
+    let three = cx.number(3);
+
+    JsObject::from_entries(&mut cx, &[("a", one), ("b", two), ("a", three)])
+}
+
+// Copies the value at `from` to `to` using integer keys
+pub fn copy_index(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let from = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let to = cx.argument::<JsNumber>(2)?.value(&mut cx) as u32;
+    let value = obj.get_value(&mut cx, from)?;
+
+    obj.set(&mut cx, to, value)?;
+
+    Ok(obj)
+}
+
+pub fn has_property(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let has = obj.has(&mut cx, key)?;
+    let has_own = obj.has_own_property(&mut cx, key)?;
+    let has: Handle<JsValue> = cx.boolean(has).upcast();
+    let has_own = cx.boolean(has_own).upcast();
+
+    JsArray::from_slice(&mut cx, &[has, has_own])
+}
+
+pub fn get_own_timeout(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let options = cx.argument::<JsObject>(0)?;
+
+    match options.get_own::<JsValue, _, _>(&mut cx, "timeout")? {
+        Some(timeout) => Ok(timeout),
+        None => Ok(cx.string("default").upcast()),
+    }
+}
+
+pub fn has_property_by_key_types(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let has_index = obj.has(&mut cx, 0)?;
+    let has_own_index = obj.has_own_property(&mut cx, 0)?;
+    let has_str = obj.has(&mut cx, "a")?;
+    let has_own_str = obj.has_own_property(&mut cx, "a")?;
+    let results = [has_index, has_own_index, has_str, has_own_str]
+        .into_iter()
+        .map(|b| cx.boolean(b).upcast())
+        .collect::<Vec<Handle<JsValue>>>();
+
+    JsArray::from_slice(&mut cx, &results)
+}
+
+pub fn delete_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsValue>(1)?;
+    let deleted = if let Ok(index) = key.downcast::<JsNumber, _>(&mut cx) {
+        let index = index.value(&mut cx) as u32;
+        obj.delete(&mut cx, index)?
+    } else {
+        obj.delete(&mut cx, key)?
+    };
+
+    Ok(cx.boolean(deleted))
+}
+
+pub fn delete_named_property(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let key = cx.argument::<JsString>(1)?.value(&mut cx);
+    let deleted = obj.delete(&mut cx, key.as_str())?;
+
+    Ok(cx.boolean(deleted))
+}
@@ -1,4 +1,4 @@
-use neon::{prelude::*, reflect::eval};
+use neon::{prelude::*, reflect::eval, types::buffer::TypedArray};
 
 pub fn return_js_string(mut cx: FunctionContext) -> JsResult<JsString> {
     Ok(cx.string("hello node"))
@@ -19,6 +19,68 @@ pub fn return_length_utf16(mut cx: FunctionContext) -> JsResult<JsNumber> {
     Ok(cx.number(value.len() as f64))
 }
 
+pub fn string_utf16_round_trip(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsString>(0)?.to_utf16(&mut cx);
+    Ok(JsString::from_utf16(&mut cx, &value))
+}
+
+pub fn return_lone_surrogate(mut cx: FunctionContext) -> JsResult<JsString> {
+    Ok(JsString::from_utf16(&mut cx, &[0x61, 0xD800, 0x62]))
+}
+
+pub fn string_from_latin1(mut cx: FunctionContext) -> JsResult<JsString> {
+    let buf = cx.argument::<JsBuffer>(0)?;
+    let bytes = buf.as_slice(&cx).to_vec();
+
+    Ok(JsString::from_latin1(&mut cx, &bytes))
+}
+
+pub fn string_to_latin1(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let bytes = cx.argument::<JsString>(0)?.to_latin1(&mut cx);
+
+    JsBuffer::from_slice(&mut cx, &bytes)
+}
+
+pub fn string_concat(mut cx: FunctionContext) -> JsResult<JsString> {
+    let a = cx.argument::<JsString>(0)?;
+    let b = cx.argument::<JsString>(1)?;
+
+    a.concat(&mut cx, b).or_throw(&mut cx)
+}
+
+pub fn string_repeat(mut cx: FunctionContext) -> JsResult<JsString> {
+    let s = cx.argument::<JsString>(0)?;
+    let n = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+
+    s.repeat(&mut cx, n).or_throw(&mut cx)
+}
+
+pub fn string_read_chunks(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let s = cx.argument::<JsString>(0)?;
+    let len = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let mut reader = s.reader(&cx);
+    let mut buf = vec![0; len];
+    let chunks = cx.empty_array();
+    let mut i = 0;
+
+    loop {
+        let n = reader.read(&mut cx, &mut buf)?;
+
+        if n == 0 {
+            break;
+        }
+
+        let chunk =
+            std::str::from_utf8(&buf[..n]).or_else(|err| cx.throw_error(err.to_string()))?;
+        let chunk = cx.string(chunk);
+
+        chunks.set(&mut cx, i, chunk)?;
+        i += 1;
+    }
+
+    Ok(chunks)
+}
+
 pub fn run_string_as_script(mut cx: FunctionContext) -> JsResult<JsValue> {
     let string_script = cx.argument::<JsString>(0)?;
     eval(&mut cx, string_script)
@@ -1,6 +1,18 @@
-use std::{cell::RefCell, sync::Arc, time::Duration};
-
-use neon::{prelude::*, types::buffer::TypedArray};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    time::Duration,
+};
+
+use neon::{
+    event::{CallMode, CancellationToken, TaskPool, ThreadsafeFunction},
+    prelude::*,
+    types::buffer::TypedArray,
+};
 
 pub fn useless_root(mut cx: FunctionContext) -> JsResult<JsObject> {
     let object = cx.argument::<JsObject>(0)?;
@@ -168,6 +180,14 @@ pub fn drop_global_queue(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+pub fn keep_drop_queue_alive(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let keep = cx.argument::<JsBoolean>(0)?.value(&mut cx);
+
+    cx.keep_drop_queue_alive(keep);
+
+    Ok(cx.undefined())
+}
+
 pub fn channel_join(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     // Function to fetch a message for processing
     let get_message = cx.argument::<JsFunction>(0)?.root(&mut cx);
@@ -211,6 +231,236 @@ pub fn channel_join(mut cx: FunctionContext) -> JsResult<JsUndefined> {
     Ok(cx.undefined())
 }
 
+pub fn channel_is_alive(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let channel = cx.channel();
+
+    std::thread::spawn(move || {
+        let is_alive = channel.is_alive();
+
+        channel.send(move |mut cx| {
+            let this = cx.undefined();
+            let args = [cx.boolean(is_alive).upcast()];
+
+            callback.into_inner(&mut cx).call(&mut cx, this, args)?;
+
+            Ok(())
+        });
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn unref_channel(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+    let mut channel = cx.channel();
+
+    channel.unref(&mut cx);
+
+    std::thread::spawn(move || {
+        channel
+            .send(move |mut cx| callback.into_inner(&mut cx).call_with(&cx).exec(&mut cx))
+            .join()
+            .unwrap();
+
+        // Hold the channel for the remaining lifetime of the process
+        loop {
+            std::thread::park();
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn bounded_channel_try_send(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let channel = Channel::bounded(&mut cx, 1);
+    let first = channel.try_send(|_| Ok(())).is_ok();
+    let second = channel.try_send(|_| Ok(())).is_ok();
+    let first = cx.boolean(first).upcast::<JsValue>();
+    let second = cx.boolean(second).upcast();
+
+    JsArray::from_slice(&mut cx, &[first, second])
+}
+
+pub fn bounded_channel_send_blocking_on_main_thread(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let channel = Channel::bounded(&mut cx, 1);
+    let first = channel.send_blocking(|_| Ok(())).is_ok();
+    // Would deadlock if it waited for the main thread to drain the channel
+    let second = channel.send_blocking(|_| Ok(())).is_ok();
+    let first = cx.boolean(first).upcast::<JsValue>();
+    let second = cx.boolean(second).upcast();
+
+    JsArray::from_slice(&mut cx, &[first, second])
+}
+
+pub fn bounded_channel_try_send_or_return(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = Arc::new(cx.argument::<JsFunction>(0)?.root(&mut cx));
+    let channel = Channel::bounded(&mut cx, 1);
+    let send = |name: &'static str| {
+        let callback = callback.clone();
+
+        move |mut cx: TaskContext| {
+            let name = cx.string(name);
+
+            callback
+                .to_inner(&mut cx)
+                .call_with(&cx)
+                .arg(name)
+                .exec(&mut cx)
+        }
+    };
+
+    channel.try_send_or_return(send("first")).unwrap();
+
+    let err = match channel.try_send_or_return(send("second")) {
+        Ok(_) => return cx.throw_error("Expected bounded channel to be full"),
+        Err(err) => err,
+    };
+
+    assert!(err.is_full());
+    assert!(!err.is_closed());
+
+    // Retry the recovered closure after the first one has executed
+    let second = err.into_inner();
+
+    std::thread::spawn(move || channel.send_blocking(second).unwrap());
+
+    Ok(cx.undefined())
+}
+
+pub fn threadsafe_function_call(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let count = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let callback = cx.argument::<JsFunction>(1)?;
+    let tsfn = ThreadsafeFunction::with_capacity(&mut cx, callback, 2, |cx, (i, name)| {
+        let i = cx.number(i).upcast();
+        let name = cx.string(name).upcast();
+
+        Ok(vec![i, name])
+    });
+
+    std::thread::spawn(move || {
+        for i in 0..count {
+            tsfn.call((i, format!("call-{i}")), CallMode::Blocking)
+                .unwrap();
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn threadsafe_function_abort(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let callback = cx.argument::<JsFunction>(0)?;
+    let tsfn = ThreadsafeFunction::with_capacity(&mut cx, callback, 1, |_, ()| Ok(vec![]));
+    let first = tsfn.call((), CallMode::NonBlocking).is_ok();
+    let full = tsfn.call((), CallMode::NonBlocking).unwrap_err().is_full();
+    // Would deadlock if it waited for the main thread to drain the queue
+    let blocked = tsfn.call((), CallMode::Blocking).unwrap_err().is_full();
+
+    // The first call is still pending and is dropped without calling `callback`
+    tsfn.abort();
+
+    let closed = tsfn.call((), CallMode::Blocking).unwrap_err().is_closed();
+    let first = cx.boolean(first).upcast::<JsValue>();
+    let full = cx.boolean(full).upcast();
+    let blocked = cx.boolean(blocked).upcast();
+    let closed = cx.boolean(closed).upcast();
+
+    JsArray::from_slice(&mut cx, &[first, full, blocked, closed])
+}
+
+pub fn cancellable_task(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let (token, cancel) = cx.cancellation_token()?;
+    let on_complete = token.clone();
+    let promise = cx
+        .task(move || {
+            let mut iterations = 0;
+
+            while !token.is_cancelled() {
+                iterations += 1;
+                std::thread::sleep(Duration::from_millis(1));
+            }
+
+            iterations
+        })
+        .promise(move |mut cx, iterations: u32| {
+            on_complete.throw_if_cancelled(&mut cx)?;
+
+            Ok(cx.number(iterations))
+        });
+    let result = cx.empty_object();
+
+    result.set(&mut cx, "promise", promise)?;
+    result.set(&mut cx, "cancel", cancel)?;
+
+    Ok(result)
+}
+
+// Calls `step(cancel)` until it cancels the token
+pub fn cancellable_loop(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let step = cx.argument::<JsFunction>(0)?;
+    let (token, cancel) = cx.cancellation_token()?;
+    let mut steps = 0;
+
+    while !token.is_cancelled() {
+        steps += 1;
+        step.call_with(&cx).arg(cancel).exec(&mut cx)?;
+    }
+
+    Ok(cx.number(steps))
+}
+
+pub fn cancelled_token_throws(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let token = CancellationToken::new();
+
+    token.throw_if_cancelled(&mut cx)?;
+    token.clone().cancel();
+    token.throw_if_cancelled(&mut cx)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn bounded_channel_send_blocking(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let count = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let capacity = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let callback = Arc::new(cx.argument::<JsFunction>(2)?.root(&mut cx));
+    let channel = Channel::bounded(&mut cx, capacity);
+    let pending = Arc::new(AtomicUsize::new(0));
+
+    std::thread::spawn(move || {
+        for i in 0..count {
+            let callback = callback.clone();
+            let pending = pending.clone();
+
+            pending.fetch_add(1, Ordering::SeqCst);
+
+            channel
+                .send_blocking(move |mut cx| {
+                    let pending = pending.fetch_sub(1, Ordering::SeqCst);
+                    let i = cx.number(i);
+                    let pending = cx.number(pending as f64);
+
+                    callback
+                        .to_inner(&mut cx)
+                        .call_with(&cx)
+                        .arg(i)
+                        .arg(pending)
+                        .exec(&mut cx)
+                })
+                .unwrap();
+        }
+    });
+
+    Ok(cx.undefined())
+}
+
+pub fn defer_callback(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let callback = cx.argument::<JsFunction>(0)?.root(&mut cx);
+
+    cx.defer(move |mut cx| callback.into_inner(&mut cx).call_with(&cx).exec(&mut cx))?;
+
+    Ok(cx.undefined())
+}
+
 pub fn sum(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let nums = cx.argument::<JsTypedArray<f64>>(0)?.as_slice(&cx).to_vec();
 
@@ -236,6 +486,33 @@ pub fn sum_manual_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+pub fn promise_then(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx.argument::<JsPromise>(0)?;
+    let on_fulfilled = cx.argument::<JsFunction>(1)?;
+    let on_rejected = cx.argument_opt(2);
+    let on_rejected = match on_rejected {
+        Some(f) => Some(f.downcast_or_throw::<JsFunction, _>(&mut cx)?),
+        None => None,
+    };
+
+    promise.then(&mut cx, on_fulfilled, on_rejected)
+}
+
+pub fn promise_catch(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let promise = cx.argument::<JsPromise>(0)?;
+    let on_rejected = cx.argument::<JsFunction>(1)?;
+
+    promise.catch(&mut cx, on_rejected)
+}
+
+pub fn queue_microtask(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let f = cx.argument::<JsFunction>(0)?;
+
+    cx.queue_microtask(f)?;
+
+    Ok(cx.undefined())
+}
+
 pub fn sum_rust_thread(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let nums = cx.argument::<JsTypedArray<f64>>(0)?.as_slice(&cx).to_vec();
 
@@ -251,6 +528,144 @@ pub fn sum_rust_thread(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+pub fn task_cancel(mut cx: FunctionContext) -> JsResult<JsArray> {
+    // Larger than the maximum size of the libuv thread pool
+    const BLOCKERS: usize = 1024;
+
+    let callback = Rc::new(cx.argument::<JsFunction>(0)?.root(&mut cx));
+    let gate = Arc::new((Mutex::new(false), Condvar::new()));
+    let remaining = Rc::new(Cell::new(BLOCKERS));
+    let completed = Rc::new(Cell::new(false));
+
+    // Occupy every worker thread so that the cancellable task cannot start
+    for _ in 0..BLOCKERS {
+        let gate = gate.clone();
+        let callback = callback.clone();
+        let remaining = remaining.clone();
+        let completed = completed.clone();
+
+        cx.task(move || {
+            let (open, cvar) = &*gate;
+            let _open = cvar.wait_while(open.lock().unwrap(), |open| !*open);
+        })
+        .and_then(move |mut cx, _| {
+            remaining.set(remaining.get() - 1);
+
+            if remaining.get() > 0 {
+                return Ok(());
+            }
+
+            let completed = cx.boolean(completed.get());
+
+            callback
+                .to_inner(&mut cx)
+                .call_with(&cx)
+                .arg(completed)
+                .exec(&mut cx)
+        });
+    }
+
+    let task = cx.task(|| {}).and_then_cancellable({
+        let completed = completed.clone();
+
+        move |_, _| {
+            completed.set(true);
+            Ok(())
+        }
+    });
+
+    let cancelled = task.cancel(&mut cx);
+    let cancelled_again = task.cancel(&mut cx);
+
+    let (open, cvar) = &*gate;
+
+    *open.lock().unwrap() = true;
+    cvar.notify_all();
+
+    let result = cx.empty_array();
+    let cancelled = cx.boolean(cancelled);
+    let cancelled_again = cx.boolean(cancelled_again);
+
+    result.set(&mut cx, 0, cancelled)?;
+    result.set(&mut cx, 1, cancelled_again)?;
+
+    Ok(result)
+}
+
+pub fn task_progress(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let count = cx.argument::<JsNumber>(0)?.value(&mut cx) as u32;
+    let callback = cx.argument::<JsFunction>(1)?.root(&mut cx);
+
+    let promise = cx
+        .task_with_progress(
+            move |progress| {
+                for i in 0..count {
+                    progress.send(i);
+                }
+
+                count
+            },
+            move |mut cx, i| {
+                let i = cx.number(i);
+
+                callback
+                    .to_inner(&mut cx)
+                    .call_with(&cx)
+                    .arg(i)
+                    .exec(&mut cx)
+            },
+        )
+        .promise(|mut cx, count| Ok(cx.number(count)));
+
+    Ok(promise)
+}
+
+pub fn task_pool_concurrency(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let limit = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let count = cx.argument::<JsNumber>(1)?.value(&mut cx) as u32;
+    let pool = TaskPool::new(&mut cx, limit);
+    let active = Arc::new(AtomicUsize::new(0));
+    let promises = cx.empty_array();
+
+    // Each promise resolves with the number of tasks running concurrently
+    for i in 0..count {
+        let active = active.clone();
+        let promise = pool.promise(
+            &mut cx,
+            move || {
+                let running = active.fetch_add(1, Ordering::SeqCst) + 1;
+
+                std::thread::sleep(Duration::from_millis(10));
+                active.fetch_sub(1, Ordering::SeqCst);
+                running as f64
+            },
+            |mut cx, running| Ok(cx.number(running)),
+        );
+
+        promises.set(&mut cx, i, promise)?;
+    }
+
+    Ok(promises)
+}
+
+pub fn task_pool_panic(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let pool = TaskPool::new(&mut cx, 1);
+    let panicked = pool.promise(
+        &mut cx,
+        || -> f64 { panic!("Pooled task panicked") },
+        |mut cx, n| Ok(cx.number(n)),
+    );
+
+    // Only starts after the panicking task frees the pool's only slot
+    let queued = pool.promise(&mut cx, || 42.0, |mut cx, n| Ok(cx.number(n)));
+    let promises = cx.empty_array();
+
+    promises.set(&mut cx, 0, panicked)?;
+    promises.set(&mut cx, 1, queued)?;
+
+    Ok(promises)
+}
+
 pub fn leak_promise(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let (_, promise) = cx.promise();
 
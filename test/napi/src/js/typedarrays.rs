@@ -1,6 +1,9 @@
 use neon::{
     prelude::*,
-    types::buffer::{Binary, BorrowError, TypedArray},
+    types::{
+        buffer::{Binary, BorrowError, TypedArray},
+        JsDataView, JsSharedArrayBuffer,
+    },
 };
 
 pub fn return_array_buffer(mut cx: FunctionContext) -> JsResult<JsArrayBuffer> {
@@ -15,6 +18,13 @@ pub fn return_array_buffer_from_slice(mut cx: FunctionContext) -> JsResult<JsArr
     JsArrayBuffer::from_slice(&mut cx, &v)
 }
 
+pub fn return_buffer_from_slice(mut cx: FunctionContext) -> JsResult<JsBuffer> {
+    let len = cx.argument::<JsNumber>(0)?.value(&mut cx) as usize;
+    let v = (0..len).map(|i| i as u8).collect::<Vec<_>>();
+
+    JsBuffer::from_slice(&mut cx, &v)
+}
+
 pub fn read_array_buffer_with_lock(mut cx: FunctionContext) -> JsResult<JsNumber> {
     let buf = cx.argument::<JsTypedArray<u32>>(0)?;
     let i = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
@@ -271,6 +281,62 @@ pub fn detach_and_unroot(cx: FunctionContext) -> JsResult<JsObject> {
     })
 }
 
+pub fn detach_array_buffer(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+
+    buf.detach(&mut cx)?;
+
+    let len = buf.as_slice(&cx).len();
+
+    Ok(cx.number(len as f64))
+}
+
+pub fn detach_typed_array(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let mut arr = cx.argument::<JsTypedArray<u32>>(0)?;
+
+    arr.buffer(&mut cx).detach(&mut cx)?;
+
+    let lock = cx.lock();
+    let borrowed = arr.try_borrow(&lock).map(|data| data.len());
+    let borrowed_mut = arr.try_borrow_mut(&lock).map(|data| data.len());
+
+    drop(lock);
+
+    let errors = [borrowed.unwrap_err(), borrowed_mut.unwrap_err()];
+
+    assert!(errors.iter().all(|err| err.is_detached()));
+
+    let lens = [arr.as_slice(&cx).len(), arr.as_mut_slice(&mut cx).len()];
+    let result = cx.empty_array();
+
+    for (i, len) in lens.into_iter().enumerate() {
+        let len = cx.number(len as f64);
+
+        result.set(&mut cx, i as u32, len)?;
+    }
+
+    Ok(result)
+}
+
+pub fn borrow_detached_array_buffer(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let lock = cx.lock();
+    let len = buf.try_borrow(&lock).map(|data| data.len());
+
+    drop(lock);
+
+    let len = len.or_throw(&mut cx)?;
+
+    Ok(cx.number(len as f64))
+}
+
+pub fn is_array_buffer_detached(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let detached = buf.is_detached(&mut cx);
+
+    Ok(cx.boolean(detached))
+}
+
 pub fn get_typed_array_info(mut cx: FunctionContext) -> JsResult<JsObject> {
     let x = cx.argument::<JsValue>(0)?;
 
@@ -379,3 +445,106 @@ pub fn write_buffer_with_borrow_mut(mut cx: FunctionContext) -> JsResult<JsUndef
 
     Ok(cx.undefined())
 }
+
+pub fn return_data_view(mut cx: FunctionContext) -> JsResult<JsDataView> {
+    let buf = cx.argument::<JsArrayBuffer>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let len = cx.argument::<JsNumber>(2)?.value(&mut cx) as usize;
+
+    JsDataView::new(&mut cx, buf, offset, len)
+}
+
+pub fn get_data_view_info(mut cx: FunctionContext) -> JsResult<JsObject> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let buffer = view.buffer(&mut cx);
+    let offset = view.byte_offset(&mut cx);
+    let len = view.byte_length(&mut cx);
+    let obj = cx.empty_object();
+
+    obj.set(&mut cx, "buffer", buffer)?;
+    let offset = cx.number(offset as f64);
+    obj.set(&mut cx, "byteOffset", offset)?;
+    let len = cx.number(len as f64);
+    obj.set(&mut cx, "byteLength", len)?;
+
+    Ok(obj)
+}
+
+pub fn read_data_view_header(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let little_endian = cx.argument::<JsBoolean>(1)?.value(&mut cx);
+    let kind = view.get_uint8(&mut cx, 0)?;
+    let flags = view.get_int8(&mut cx, 1)?;
+    let id = view.get_uint16(&mut cx, 2, little_endian)?;
+    let len = view.get_uint32(&mut cx, 4, little_endian)?;
+    let value = view.get_float64(&mut cx, 8, little_endian)?;
+    let values = [kind as f64, flags as f64, id as f64, len as f64, value];
+    let arr = cx.empty_array();
+
+    for (i, n) in values.into_iter().enumerate() {
+        let n = cx.number(n);
+        arr.set(&mut cx, i as u32, n)?;
+    }
+
+    Ok(arr)
+}
+
+pub fn write_data_view_header(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let little_endian = cx.argument::<JsBoolean>(1)?.value(&mut cx);
+
+    view.set_uint8(&mut cx, 0, 0xff)?;
+    view.set_int8(&mut cx, 1, -1)?;
+    view.set_uint16(&mut cx, 2, 0x1234, little_endian)?;
+    view.set_uint32(&mut cx, 4, 0xdeadbeef, little_endian)?;
+    view.set_float64(&mut cx, 8, 1.5, little_endian)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn read_data_view_uint32(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let n = view.get_uint32(&mut cx, offset, true)?;
+
+    Ok(cx.number(n))
+}
+
+pub fn write_data_view_float64(mut cx: FunctionContext) -> JsResult<JsUndefined> {
+    let view = cx.argument::<JsDataView>(0)?;
+    let offset = cx.argument::<JsNumber>(1)?.value(&mut cx) as usize;
+    let n = cx.argument::<JsNumber>(2)?.value(&mut cx);
+
+    view.set_float64(&mut cx, offset, n, true)?;
+
+    Ok(cx.undefined())
+}
+
+pub fn shared_array_buffer_from_slice(mut cx: FunctionContext) -> JsResult<JsSharedArrayBuffer> {
+    let buf = cx.argument::<JsTypedArray<u8>>(0)?.as_slice(&cx).to_vec();
+
+    JsSharedArrayBuffer::from_slice(&mut cx, &buf)
+}
+
+pub fn increment_shared_array_buffer(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let buf = cx.argument::<JsSharedArrayBuffer>(0)?;
+
+    for byte in buf.as_atomic_slice(&mut cx)? {
+        byte.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    let size = buf.size(&mut cx)?;
+
+    Ok(cx.number(size as f64))
+}
+
+// Checks the type of a `SharedArrayBuffer` while the exception thrown by `f` is pending
+pub fn is_shared_array_buffer_while_throwing(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let f = cx.argument::<JsFunction>(0)?;
+    let buf = cx.argument::<JsValue>(1)?;
+    let result = f.call_with(&cx).apply::<JsValue, _>(&mut cx);
+
+    assert!(!buf.is_a::<JsSharedArrayBuffer, _>(&mut cx));
+
+    result
+}
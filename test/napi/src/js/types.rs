@@ -1,4 +1,4 @@
-use neon::prelude::*;
+use neon::{prelude::*, types::JsSymbol};
 
 pub fn is_string(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let val: Handle<JsValue> = cx.argument(0)?;
@@ -72,3 +72,125 @@ pub fn strict_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
     let eq = v1.strict_equals(&mut cx, v2);
     Ok(cx.boolean(eq))
 }
+
+pub fn ptr_equals(mut cx: FunctionContext) -> JsResult<JsArray> {
+    let v1: Handle<JsValue> = cx.argument(0)?;
+    let v2: Handle<JsValue> = cx.argument(1)?;
+    let copy = v1;
+    let result = cx.empty_array();
+    let same = cx.boolean(v1.ptr_eq(&copy));
+    let different = cx.boolean(v1.ptr_eq(&v2));
+
+    result.set(&mut cx, 0, same)?;
+    result.set(&mut cx, 1, different)?;
+
+    Ok(result)
+}
+
+pub fn loose_equals(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let v1: Handle<JsValue> = cx.argument(0)?;
+    let v2: Handle<JsValue> = cx.argument(1)?;
+    let eq = v1.loose_equals(&mut cx, v2)?;
+    Ok(cx.boolean(eq))
+}
+
+pub fn constructor_name(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+
+    match v.constructor_name(&mut cx)? {
+        Some(name) => Ok(cx.string(name).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn to_debug_string(mut cx: FunctionContext) -> JsResult<JsString> {
+    let v: Handle<JsValue> = cx.argument(0)?;
+    let s = v.to_debug_string(&mut cx);
+
+    Ok(cx.string(s))
+}
+
+pub fn downcast_to_number(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    cx.argument::<JsNumber>(0)
+}
+
+pub fn is_symbol(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let result = val.is_a::<JsSymbol, _>(&mut cx);
+    Ok(cx.boolean(result))
+}
+
+pub fn downcast_or_default(mut cx: FunctionContext) -> JsResult<JsString> {
+    let default = cx.string("default");
+
+    Ok(cx.argument::<JsValue>(0)?.downcast_or(&mut cx, default))
+}
+
+pub fn downcast_or_else_length(mut cx: FunctionContext) -> JsResult<JsNumber> {
+    let options = cx.argument::<JsObject>(0)?;
+    let length = options
+        .get_value(&mut cx, "length")?
+        .downcast_or_else(&mut cx, |cx| cx.number(0));
+
+    Ok(length)
+}
+
+pub fn create_symbol(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let description = cx.argument_opt(0);
+    let description = match description {
+        Some(v) => Some(v.downcast_or_throw::<JsString, _>(&mut cx)?),
+        None => None,
+    };
+
+    Ok(JsSymbol::new(&mut cx, description))
+}
+
+pub fn symbol_for(mut cx: FunctionContext) -> JsResult<JsSymbol> {
+    let key = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    JsSymbol::for_key(&mut cx, &key)
+}
+
+pub fn symbol_description(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let symbol = cx.argument::<JsSymbol>(0)?;
+
+    match symbol.description(&mut cx)? {
+        Some(description) => Ok(cx.string(description).upcast()),
+        None => Ok(cx.undefined().upcast()),
+    }
+}
+
+pub fn get_well_known_symbol_property(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let obj = cx.argument::<JsObject>(0)?;
+    let name = cx.argument::<JsString>(1)?.value(&mut cx);
+    let symbol = JsSymbol::well_known(&mut cx, &name)?;
+
+    obj.get_value(&mut cx, symbol)
+}
+
+pub fn instance_of(mut cx: FunctionContext) -> JsResult<JsBoolean> {
+    let val: Handle<JsValue> = cx.argument(0)?;
+    let constructor: Handle<JsValue> = cx.argument(1)?;
+    let result = val.instance_of(&mut cx, constructor)?;
+    Ok(cx.boolean(result))
+}
+
+pub fn run_script(mut cx: FunctionContext) -> JsResult<JsValue> {
+    let src = cx.argument::<JsString>(0)?.value(&mut cx);
+
+    cx.run_script(src)
+}
+
+pub fn type_of(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.argument::<JsValue>(0)?;
+    let ty = value.type_of(&mut cx);
+
+    Ok(cx.string(format!("{ty:?}")))
+}
+
+pub fn boxed_type_of(mut cx: FunctionContext) -> JsResult<JsString> {
+    let value = cx.boxed(());
+    let ty = value.type_of(&mut cx);
+
+    Ok(cx.string(format!("{ty:?}")))
+}
@@ -1,8 +1,8 @@
 use neon::prelude::*;
 
 use crate::js::{
-    arrays::*, boxed::*, coercions::*, date::*, errors::*, functions::*, numbers::*, objects::*,
-    strings::*, threads::*, typedarrays::*, types::*,
+    arrays::*, boxed::*, coercions::*, collections::*, date::*, errors::*, functions::*,
+    numbers::*, objects::*, strings::*, threads::*, typedarrays::*, types::*,
 };
 
 mod js {
@@ -10,6 +10,7 @@ mod js {
     pub mod bigint;
     pub mod boxed;
     pub mod coercions;
+    pub mod collections;
     pub mod date;
     pub mod errors;
     pub mod export;
@@ -118,6 +119,13 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("return_js_string_utf16", return_js_string_utf16)?;
     cx.export_function("return_length_utf8", return_length_utf8)?;
     cx.export_function("return_length_utf16", return_length_utf16)?;
+    cx.export_function("string_utf16_round_trip", string_utf16_round_trip)?;
+    cx.export_function("return_lone_surrogate", return_lone_surrogate)?;
+    cx.export_function("string_from_latin1", string_from_latin1)?;
+    cx.export_function("string_to_latin1", string_to_latin1)?;
+    cx.export_function("string_concat", string_concat)?;
+    cx.export_function("string_repeat", string_repeat)?;
+    cx.export_function("string_read_chunks", string_read_chunks)?;
     cx.export_function("run_string_as_script", run_string_as_script)?;
 
     cx.export_function("return_js_number", return_js_number)?;
@@ -141,8 +149,12 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "accept_and_return_negative_js_number",
         accept_and_return_negative_js_number,
     )?;
+    cx.export_function("number_to_integers", number_to_integers)?;
+    cx.export_function("number_try_value_i64", number_try_value_i64)?;
+    cx.export_function("number_predicates", number_predicates)?;
 
     cx.export_function("return_js_function", return_js_function)?;
+    cx.export_function("return_named_js_function", return_named_js_function)?;
     cx.export_function("call_js_function", call_js_function)?;
     cx.export_function(
         "call_js_function_idiomatically",
@@ -168,6 +180,15 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
         "call_js_function_with_four_args",
         call_js_function_with_four_args,
     )?;
+    cx.export_function(
+        "call_js_function_with_vec_args",
+        call_js_function_with_vec_args,
+    )?;
+    cx.export_function(
+        "call_js_function_with_slice_args",
+        call_js_function_with_slice_args,
+    )?;
+    cx.export_function("bind_js_function", bind_js_function)?;
     cx.export_function(
         "call_js_function_with_custom_this",
         call_js_function_with_custom_this,
@@ -204,13 +225,48 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("recompute_scoped", recompute_scoped)?;
 
     cx.export_function("return_js_array", return_js_array)?;
+    cx.export_function("return_js_array_with_length", return_js_array_with_length)?;
+    cx.export_function("iter_js_array", iter_js_array)?;
+    cx.export_function("sort_js_array_by_key", sort_js_array_by_key)?;
     cx.export_function("return_js_array_with_number", return_js_array_with_number)?;
     cx.export_function("return_js_array_with_string", return_js_array_with_string)?;
     cx.export_function("read_js_array", read_js_array)?;
+    cx.export_function("js_array_all_numbers", js_array_all_numbers)?;
+    cx.export_function("js_array_all_numbers_naive", js_array_all_numbers_naive)?;
+    cx.export_function("concat_js_arrays", concat_js_arrays)?;
+    cx.export_function("flat_js_array", flat_js_array)?;
+    cx.export_function("js_array_from_arguments", js_array_from_arguments)?;
+    cx.export_function("copy_into_js_array", copy_into_js_array)?;
+    cx.export_function("sum_js_array_numbers", sum_js_array_numbers)?;
+
+    cx.export_function("map_from_entries", map_from_entries)?;
+    cx.export_function("map_get", map_get)?;
+    cx.export_function("map_has", map_has)?;
+    cx.export_function("map_delete", map_delete)?;
+    cx.export_function("map_size", map_size)?;
+    cx.export_function("map_entries", map_entries)?;
+    cx.export_function("is_map", is_map)?;
+    cx.export_function("set_from_values", set_from_values)?;
+    cx.export_function("set_has", set_has)?;
+    cx.export_function("set_delete", set_delete)?;
+    cx.export_function("set_size", set_size)?;
+    cx.export_function("set_to_array", set_to_array)?;
+    cx.export_function("regexp_new", regexp_new)?;
+    cx.export_function("regexp_test", regexp_test)?;
+    cx.export_function("regexp_exec", regexp_exec)?;
 
     cx.export_function("to_string", to_string)?;
+    cx.export_function("argument_coerced_to_number", argument_coerced_to_number)?;
+    cx.export_function("argument_coerced_to_string", argument_coerced_to_string)?;
+    cx.export_function("argument_coerced_to_boolean", argument_coerced_to_boolean)?;
+    cx.export_function("coerce_to_string", coerce_to_string)?;
+    cx.export_function("coerce_to_number", coerce_to_number)?;
+    cx.export_function("coerce_to_bool", coerce_to_bool)?;
 
     cx.export_function("return_js_global_object", return_js_global_object)?;
+    cx.export_function("require_global_function", require_global_function)?;
+    cx.export_function("json_parse", json_parse)?;
+    cx.export_function("json_stringify", json_stringify)?;
     cx.export_function("return_js_object", return_js_object)?;
     cx.export_function("return_js_object_with_number", return_js_object_with_number)?;
     cx.export_function("return_js_object_with_string", return_js_object_with_string)?;
@@ -220,12 +276,34 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     )?;
     cx.export_function("freeze_js_object", freeze_js_object)?;
     cx.export_function("seal_js_object", seal_js_object)?;
+    cx.export_function("is_frozen_js_object", is_frozen_js_object)?;
+    cx.export_function("is_sealed_js_object", is_sealed_js_object)?;
+    cx.export_function("define_data_property", define_data_property)?;
+    cx.export_function("define_counter", define_counter)?;
+    cx.export_function("create_with_prototype", create_with_prototype)?;
+    cx.export_function("get_prototype", get_prototype)?;
+    cx.export_function("set_prototype", set_prototype)?;
+    cx.export_function("assign_object", assign_object)?;
+    cx.export_function("object_from_entries", object_from_entries)?;
+    cx.export_function("copy_index", copy_index)?;
+    cx.export_function("create_null_prototype_object", create_null_prototype_object)?;
+    cx.export_function("has_property", has_property)?;
+    cx.export_function("get_own_timeout", get_own_timeout)?;
+    cx.export_function("has_property_by_key_types", has_property_by_key_types)?;
+    cx.export_function("delete_property", delete_property)?;
+    cx.export_function("delete_named_property", delete_named_property)?;
+    cx.export_function("create_reference", create_reference)?;
+    cx.export_function("reference_ref", reference_ref)?;
+    cx.export_function("reference_unref", reference_unref)?;
+    cx.export_function("reference_get", reference_get)?;
+    cx.export_function("downgrade_root", downgrade_root)?;
 
     cx.export_function("return_array_buffer", return_array_buffer)?;
     cx.export_function(
         "return_array_buffer_from_slice",
         return_array_buffer_from_slice,
     )?;
+    cx.export_function("return_buffer_from_slice", return_buffer_from_slice)?;
     cx.export_function("read_array_buffer_with_lock", read_array_buffer_with_lock)?;
     cx.export_function(
         "read_array_buffer_with_borrow",
@@ -278,9 +356,31 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("detach_and_escape", detach_and_escape)?;
     cx.export_function("detach_and_cast", detach_and_cast)?;
     cx.export_function("detach_and_unroot", detach_and_unroot)?;
+    cx.export_function("detach_array_buffer", detach_array_buffer)?;
+    cx.export_function("detach_typed_array", detach_typed_array)?;
+    cx.export_function("borrow_detached_array_buffer", borrow_detached_array_buffer)?;
+    cx.export_function("is_array_buffer_detached", is_array_buffer_detached)?;
+    cx.export_function(
+        "shared_array_buffer_from_slice",
+        shared_array_buffer_from_slice,
+    )?;
+    cx.export_function(
+        "increment_shared_array_buffer",
+        increment_shared_array_buffer,
+    )?;
+    cx.export_function(
+        "is_shared_array_buffer_while_throwing",
+        is_shared_array_buffer_while_throwing,
+    )?;
     cx.export_function("get_typed_array_info", get_typed_array_info)?;
     cx.export_function("build_f32_region", build_f32_region)?;
     cx.export_function("build_f64_region", build_f64_region)?;
+    cx.export_function("return_data_view", return_data_view)?;
+    cx.export_function("get_data_view_info", get_data_view_info)?;
+    cx.export_function("read_data_view_header", read_data_view_header)?;
+    cx.export_function("write_data_view_header", write_data_view_header)?;
+    cx.export_function("read_data_view_uint32", read_data_view_uint32)?;
+    cx.export_function("write_data_view_float64", write_data_view_float64)?;
     cx.export_function("read_buffer_with_lock", read_buffer_with_lock)?;
     cx.export_function("read_buffer_with_borrow", read_buffer_with_borrow)?;
     cx.export_function("write_buffer_with_lock", write_buffer_with_lock)?;
@@ -289,6 +389,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("call_nullary_method", call_nullary_method)?;
     cx.export_function("call_unary_method", call_unary_method)?;
     cx.export_function("call_symbol_method", call_symbol_method)?;
+    cx.export_function("call_method", call_method)?;
 
     cx.export_function("create_date", create_date)?;
     cx.export_function("get_date_value", get_date_value)?;
@@ -298,6 +399,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("try_new_lossy_date", try_new_lossy_date)?;
     cx.export_function("nan_dates", nan_dates)?;
     cx.export_function("create_date_from_value", create_date_from_value)?;
+    cx.export_function("create_date_from_system_time", create_date_from_system_time)?;
     cx.export_function("create_and_get_invalid_date", create_and_get_invalid_date)?;
 
     cx.export_function("is_array", is_array)?;
@@ -312,22 +414,52 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("is_string", is_string)?;
     cx.export_function("is_undefined", is_undefined)?;
     cx.export_function("strict_equals", strict_equals)?;
+    cx.export_function("ptr_equals", ptr_equals)?;
+    cx.export_function("loose_equals", loose_equals)?;
+    cx.export_function("constructor_name", constructor_name)?;
+    cx.export_function("to_debug_string", to_debug_string)?;
+    cx.export_function("downcast_to_number", downcast_to_number)?;
+    cx.export_function("instance_of", instance_of)?;
+    cx.export_function("run_script", run_script)?;
+    cx.export_function("type_of", type_of)?;
+    cx.export_function("boxed_type_of", boxed_type_of)?;
+    cx.export_function("is_symbol", is_symbol)?;
+    cx.export_function("downcast_or_default", downcast_or_default)?;
+    cx.export_function("downcast_or_else_length", downcast_or_else_length)?;
+    cx.export_function("create_symbol", create_symbol)?;
+    cx.export_function("symbol_for", symbol_for)?;
+    cx.export_function("symbol_description", symbol_description)?;
+    cx.export_function(
+        "get_well_known_symbol_property",
+        get_well_known_symbol_property,
+    )?;
 
     cx.export_function("new_error", new_error)?;
     cx.export_function("new_type_error", new_type_error)?;
     cx.export_function("new_range_error", new_range_error)?;
     cx.export_function("throw_error", throw_error)?;
+    cx.export_function("throw_error_with_cause", throw_error_with_cause)?;
+    cx.export_function("throw_error_with_code", throw_error_with_code)?;
     cx.export_function("downcast_error", downcast_error)?;
+    cx.export_function("error_name_and_message", error_name_and_message)?;
+    cx.export_function("error_stack", error_stack)?;
+    cx.export_function("capture_error", capture_error)?;
+    cx.export_function("throw_rust_error", throw_rust_error)?;
 
     cx.export_function("panic", panic)?;
     cx.export_function("panic_after_throw", panic_after_throw)?;
 
     cx.export_function("throw_and_catch", throw_and_catch)?;
     cx.export_function("call_and_catch", call_and_catch)?;
+    cx.export_function("call_and_rethrow", call_and_rethrow)?;
+    cx.export_function("call_with_retry", call_with_retry)?;
     cx.export_function("get_number_or_default", get_number_or_default)?;
     cx.export_function("assume_this_is_an_object", assume_this_is_an_object)?;
     cx.export_function("is_construct", is_construct)?;
     cx.export_function("caller_with_drop_callback", caller_with_drop_callback)?;
+    cx.export_function("iterate_range", iterate_range)?;
+    cx.export_function("get_function_name", get_function_name)?;
+    cx.export_function("get_function_length", get_function_length)?;
 
     cx.export_function("count_called", {
         let n = std::cell::RefCell::new(0);
@@ -346,13 +478,69 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     cx.export_function("get_own_property_names", call_get_own_property_names)?;
 
+    fn call_keys(mut cx: FunctionContext) -> JsResult<JsArray> {
+        let object = cx.argument::<JsObject>(0)?;
+        object.keys(&mut cx)
+    }
+
+    cx.export_function("keys", call_keys)?;
+
+    fn call_property_names_with(mut cx: FunctionContext) -> JsResult<JsArray> {
+        let object = cx.argument::<JsObject>(0)?;
+        let flags = cx.argument::<JsObject>(1)?;
+        let mut options = object.property_names_with(&cx);
+
+        let is_set = |cx: &mut FunctionContext, name: &str| -> NeonResult<bool> {
+            Ok(flags
+                .get_opt::<JsBoolean, _, _>(cx, name)?
+                .map(|b| b.value(cx))
+                .unwrap_or(false))
+        };
+
+        if is_set(&mut cx, "includePrototypes")? {
+            options.include_prototypes();
+        }
+        if is_set(&mut cx, "enumerable")? {
+            options.enumerable();
+        }
+        if is_set(&mut cx, "writable")? {
+            options.writable();
+        }
+        if is_set(&mut cx, "configurable")? {
+            options.configurable();
+        }
+        if is_set(&mut cx, "skipStrings")? {
+            options.skip_strings();
+        }
+        if is_set(&mut cx, "skipSymbols")? {
+            options.skip_symbols();
+        }
+        if is_set(&mut cx, "keepNumbers")? {
+            options.keep_numbers();
+        }
+
+        options.get(&mut cx)
+    }
+
+    cx.export_function("property_names_with", call_property_names_with)?;
+
     cx.export_function("person_new", person_new)?;
     cx.export_function("person_greet", person_greet)?;
     cx.export_function("ref_person_new", ref_person_new)?;
     cx.export_function("ref_person_greet", ref_person_greet)?;
     cx.export_function("ref_person_set_name", ref_person_set_name)?;
     cx.export_function("ref_person_fail", ref_person_fail)?;
+    cx.export_function("ref_person_set_name_with", ref_person_set_name_with)?;
+    cx.export_function("ref_person_try_greet", ref_person_try_greet)?;
+    cx.export_function("adjust_external_memory", adjust_external_memory)?;
     cx.export_function("external_unit", external_unit)?;
+    cx.export_function("box_kind", box_kind)?;
+    cx.export_function("box_failing_finalizer", box_failing_finalizer)?;
+    cx.export_function(
+        "box_wrapped_failing_finalizer",
+        box_wrapped_failing_finalizer,
+    )?;
+    cx.export_function("take_finalize_errors", take_finalize_errors)?;
 
     cx.export_function("useless_root", useless_root)?;
     cx.export_function("thread_callback", thread_callback)?;
@@ -361,9 +549,38 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("greeter_greet", greeter_greet)?;
     cx.export_function("leak_channel", leak_channel)?;
     cx.export_function("drop_global_queue", drop_global_queue)?;
+    cx.export_function("keep_drop_queue_alive", keep_drop_queue_alive)?;
     cx.export_function("channel_join", channel_join)?;
+    cx.export_function("channel_is_alive", channel_is_alive)?;
+    cx.export_function("unref_channel", unref_channel)?;
+    cx.export_function("bounded_channel_try_send", bounded_channel_try_send)?;
+    cx.export_function(
+        "bounded_channel_send_blocking_on_main_thread",
+        bounded_channel_send_blocking_on_main_thread,
+    )?;
+    cx.export_function(
+        "bounded_channel_try_send_or_return",
+        bounded_channel_try_send_or_return,
+    )?;
+    cx.export_function(
+        "bounded_channel_send_blocking",
+        bounded_channel_send_blocking,
+    )?;
+    cx.export_function("threadsafe_function_call", threadsafe_function_call)?;
+    cx.export_function("threadsafe_function_abort", threadsafe_function_abort)?;
+    cx.export_function("cancellable_task", cancellable_task)?;
+    cx.export_function("cancellable_loop", cancellable_loop)?;
+    cx.export_function("cancelled_token_throws", cancelled_token_throws)?;
+    cx.export_function("defer_callback", defer_callback)?;
     cx.export_function("sum", sum)?;
     cx.export_function("sum_manual_promise", sum_manual_promise)?;
+    cx.export_function("promise_then", promise_then)?;
+    cx.export_function("promise_catch", promise_catch)?;
+    cx.export_function("queue_microtask", queue_microtask)?;
+    cx.export_function("task_pool_concurrency", task_pool_concurrency)?;
+    cx.export_function("task_pool_panic", task_pool_panic)?;
+    cx.export_function("task_cancel", task_cancel)?;
+    cx.export_function("task_progress", task_progress)?;
     cx.export_function("sum_rust_thread", sum_rust_thread)?;
     cx.export_function("leak_promise", leak_promise)?;
     cx.export_function("channel_panic", channel_panic)?;
@@ -408,10 +625,15 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
     cx.export_function("extract_values", js::extract::extract_values)?;
     cx.export_function("extract_buffer_sum", js::extract::extract_buffer_sum)?;
     cx.export_function("extract_json_sum", js::extract::extract_json_sum)?;
+    cx.export_function("extract_json_string", js::extract::extract_json_string)?;
     cx.export_function(
         "extract_single_add_one",
         js::extract::extract_single_add_one,
     )?;
+    cx.export_function("extract_variadic_sum", js::extract::extract_variadic_sum)?;
+    cx.export_function("extract_variadic_join", js::extract::extract_variadic_join)?;
+    cx.export_function("variadic_max", js::extract::variadic_max)?;
+    cx.export_function("variadic_type_names", js::extract::variadic_type_names)?;
 
     Ok(())
 }